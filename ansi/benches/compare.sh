@@ -0,0 +1,40 @@
+#!/bin/sh
+# Runs benches/parse.rs against an older revision and the working tree, alternating
+# between the two so that drift on the machine affects both sides alike.
+#
+#   ansi/benches/compare.sh REV [ROUNDS] [CORPUS]
+#
+# REV is any revision git understands, such as `main` or `HEAD~1`. It may predate the
+# bench, so the bench and its Cargo.toml entry are copied into the worktree of REV.
+set -eu
+
+if [ $# -lt 1 ]; then
+    echo "usage: $0 REV [ROUNDS] [CORPUS]" >&2
+    exit 2
+fi
+rev=$1
+rounds=${2:-3}
+corpus=${3:-}
+root=$(git rev-parse --show-toplevel)
+baseline=$(mktemp -d)
+
+cleanup() {
+    git -C "$root" worktree remove --force "$baseline"
+}
+trap cleanup EXIT
+
+git -C "$root" worktree add --quiet --detach "$baseline" "$rev"
+mkdir -p "$baseline/ansi/benches"
+cp "$root/ansi/benches/parse.rs" "$baseline/ansi/benches/parse.rs"
+if ! grep -q '^\[\[bench\]\]' "$baseline/ansi/Cargo.toml"; then
+    printf '\n[[bench]]\nname = "parse"\nharness = false\n' >>"$baseline/ansi/Cargo.toml"
+fi
+
+round=1
+while [ "$round" -le "$rounds" ]; do
+    echo "== $rev (round $round)"
+    (cd "$baseline" && cargo bench --quiet -p ansi --bench parse -- $corpus)
+    echo "== working tree (round $round)"
+    (cd "$root" && cargo bench --quiet -p ansi --bench parse -- $corpus)
+    round=$((round + 1))
+done
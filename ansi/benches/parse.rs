@@ -0,0 +1,211 @@
+//! Parser throughput over generated terminal output, run with `cargo bench -p ansi`.
+//!
+//! The corpora come from a fixed seed so numbers are comparable across commits,
+//! pass a corpus name such as `htop` to only run that one.
+//!
+//! `benches/compare.sh REV [ROUNDS] [CORPUS]` runs this bench at revision REV, such as
+//! `main`, and on the working tree in alternating rounds.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use ansi::{Feed, SizedAnsiParser};
+
+const CORPUS_LEN: usize = 8 << 20;
+const MIN_TIME: Duration = Duration::from_secs(2);
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.below(items.len())]
+    }
+}
+
+type Generator = fn(&mut XorShift) -> Vec<u8>;
+
+/// Colored diagnostics in the style of rustc and cargo
+fn compiler_output(rng: &mut XorShift) -> Vec<u8> {
+    const MESSAGES: &[&str] = &[
+        "mismatched types",
+        "cannot borrow `*self` as mutable more than once at a time",
+        "unused variable: `config`",
+        "the trait bound `T: Clone` is not satisfied",
+        "value used here after move",
+    ];
+    const FILES: &[&str] = &[
+        "src/main.rs",
+        "src/parser/mod.rs",
+        "src/lib.rs",
+        "tests/it.rs",
+    ];
+    let mut out = String::new();
+    while out.len() < CORPUS_LEN {
+        let (level, color) = match rng.below(3) {
+            0 => ("error", 9),
+            _ => ("warning", 11),
+        };
+        let line = rng.below(900) + 1;
+        let column = rng.below(80) + 1;
+        let width = rng.below(20) + 1;
+        out += &format!(
+            "\x1b[0m\x1b[1m\x1b[38;5;{color}m{level}[E0{code}]\x1b[0m\x1b[0m\x1b[1m: {message}\x1b[0m\n\
+             \x1b[0m   \x1b[0m\x1b[0m\x1b[1m\x1b[38;5;12m--> \x1b[0m\x1b[0m{file}:{line}:{column}\x1b[0m\n\
+             \x1b[0m    \x1b[0m\x1b[0m\x1b[1m\x1b[38;5;12m|\x1b[0m\n\
+             \x1b[0m\x1b[1m\x1b[38;5;12m{line:<4}\x1b[0m\x1b[0m\x1b[1m\x1b[38;5;12m|\x1b[0m\x1b[0m        let value = parser.next(input).unwrap_or_default();\x1b[0m\n\
+             \x1b[0m    \x1b[0m\x1b[0m\x1b[1m\x1b[38;5;12m|\x1b[0m\x1b[0m        \x1b[0m\x1b[0m\x1b[1m\x1b[38;5;{color}m{carets}\x1b[0m\n\n",
+            code = 100 + rng.below(700),
+            message = rng.pick(MESSAGES),
+            file = rng.pick(FILES),
+            carets = "^".repeat(width),
+        );
+        if rng.below(8) == 0 {
+            out += "\x1b[0m\x1b[1m\x1b[32m   Compiling\x1b[0m ansi v0.1.0 (/home/user/ansi/ansi)\n";
+        }
+    }
+    out.into_bytes()
+}
+
+/// Full screen redraws of a process monitor, positioned with CUP and drawn with SGR
+fn htop_frames(rng: &mut XorShift) -> Vec<u8> {
+    const COMMANDS: &[&str] = &[
+        "/usr/lib/firefox/firefox -contentproc -childID 12",
+        "cargo build --release",
+        "/usr/bin/pipewire",
+        "htop",
+        "rust-analyzer",
+        "/usr/bin/gnome-shell",
+    ];
+    let mut out = String::new();
+    while out.len() < CORPUS_LEN {
+        out += "\x1b[?25l\x1b[H";
+        for cpu in 0..8 {
+            let used = rng.below(40);
+            out += &format!(
+                "\x1b[{};{}H\x1b[1m\x1b[36m{cpu:>3}\x1b[0m\x1b[1m[\x1b[32m{}\x1b[31m{}\x1b[30m{}\x1b[0m\x1b[1m{:>5.1}%]\x1b[0m",
+                cpu / 2 + 1,
+                cpu % 2 * 60 + 1,
+                "|".repeat(used / 2),
+                "|".repeat(used - used / 2),
+                " ".repeat(40 - used),
+                used as f32 * 2.5,
+            );
+        }
+        out += "\x1b[6;1H\x1b[30;42m    PID USER      PRI  NI  VIRT   RES   SHR S  CPU% MEM%   TIME+  Command\x1b[K\x1b[m";
+        for row in 0..40 {
+            let selected = if row == 3 { "\x1b[30;46m" } else { "" };
+            out += &format!(
+                "\x1b[{};1H{selected}\x1b[m{:>7} \x1b[33muser\x1b[m      20   0 \x1b[36m{:>4}M\x1b[m {:>5} {:>5} S {:>5.1} {:>4.1} {:>2}:{:02}.{:02} \x1b[1m{}\x1b[m\x1b[K",
+                row + 7,
+                rng.below(99999),
+                rng.below(9999),
+                rng.below(99999),
+                rng.below(9999),
+                rng.below(1000) as f32 / 10.0,
+                rng.below(100) as f32 / 10.0,
+                rng.below(60),
+                rng.below(60),
+                rng.below(100),
+                rng.pick(COMMANDS),
+            );
+        }
+        out += "\x1b[47;1H\x1b[30;46mF1\x1b[0mHelp  \x1b[30;46mF2\x1b[0mSetup \x1b[30;46mF10\x1b[0mQuit\x1b[?25h";
+    }
+    out.into_bytes()
+}
+
+/// Plain text in several scripts, as printed by `cat`
+fn utf8_text(rng: &mut XorShift) -> Vec<u8> {
+    const SENTENCES: &[&str] = &[
+        "The quick brown fox jumps over the lazy dog. ",
+        "Zwölf Boxkämpfer jagen Viktor quer über den großen Sylter Deich. ",
+        "Съешь же ещё этих мягких французских булок, да выпей чаю. ",
+        "いろはにほへと ちりぬるを わかよたれそ つねならむ ",
+        "敏捷的棕色狐狸跳过了懒狗。",
+        "Ταχίστη αλώπηξ βαφής ψημένη γη, δρασκελίζει υπέρ νωθρού κυνός. ",
+        "Emoji 🎉🚀 and flags 🇩🇪 mixed into ASCII text. ",
+        "    fn main() { println!(\"hello, world\"); }",
+    ];
+    let mut out = String::new();
+    while out.len() < CORPUS_LEN {
+        out += rng.pick(SENTENCES);
+        if rng.below(4) == 0 {
+            out += "\n";
+        }
+    }
+    out.into_bytes()
+}
+
+fn run_next(corpus: &[u8]) -> usize {
+    let mut parser = SizedAnsiParser::<256>::new();
+    let mut events = 0;
+    for byte in corpus {
+        events += !matches!(black_box(parser.next(*byte)), ansi::Out::None) as usize;
+    }
+    events
+}
+
+fn run_feed(corpus: &[u8], text_runs: bool) -> usize {
+    let mut parser = SizedAnsiParser::<256>::new();
+    parser.cfg.text_runs = text_runs;
+    let mut input = corpus;
+    let mut events = 0;
+    while !input.is_empty() {
+        let Feed { consumed, out } = parser.feed(input);
+        black_box(out);
+        input = &input[consumed..];
+        events += 1;
+    }
+    events
+}
+
+/// The best of repeated runs in MiB/s
+fn measure(corpus: &[u8], run: impl Fn(&[u8]) -> usize) -> f64 {
+    let start = Instant::now();
+    let mut best = Duration::MAX;
+    while start.elapsed() < MIN_TIME {
+        let run_start = Instant::now();
+        black_box(run(black_box(corpus)));
+        best = best.min(run_start.elapsed());
+    }
+    corpus.len() as f64 / (1 << 20) as f64 / best.as_secs_f64()
+}
+
+fn main() {
+    let filter = std::env::args().skip(1).find(|arg| !arg.starts_with('-'));
+    let corpora: [(&str, Generator); 3] = [
+        ("compiler", compiler_output),
+        ("htop", htop_frames),
+        ("utf8", utf8_text),
+    ];
+    println!(
+        "{:<10} {:>12} {:>12} {:>16}",
+        "corpus", "next", "feed", "feed text_runs"
+    );
+    for (name, generate) in corpora {
+        if filter
+            .as_ref()
+            .is_some_and(|filter| !name.contains(filter.as_str()))
+        {
+            continue;
+        }
+        let corpus = generate(&mut XorShift(0x9E37_79B9_7F4A_7C15));
+        println!(
+            "{name:<10} {:>7.1} MiB/s {:>7.1} MiB/s {:>11.1} MiB/s",
+            measure(&corpus, run_next),
+            measure(&corpus, |corpus| run_feed(corpus, false)),
+            measure(&corpus, |corpus| run_feed(corpus, true)),
+        );
+    }
+}
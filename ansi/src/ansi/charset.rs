@@ -0,0 +1,432 @@
+use crate::ansi::*;
+
+/// One of the four ISO 2022 graphic set registers
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum CharsetSlot {
+    #[default]
+    G0 = 0,
+    G1 = 1,
+    G2 = 2,
+    G3 = 3,
+}
+
+/// A graphic character set which can be designated into a [`CharsetSlot`]
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum Charset {
+    /// US ASCII `B`
+    #[default]
+    Ascii,
+    /// United Kingdom `A`
+    Uk,
+    /// DEC Special Graphics `0`, the line drawing set
+    DecSpecialGraphics,
+    /// DEC Supplemental `<` or `%5`
+    DecSupplemental,
+    /// ISO Latin-1 Supplemental, the 96 character set `A`
+    Latin1Supplemental,
+
+    /// Dutch NRCS `4`
+    Dutch,
+    /// Finnish NRCS `C` or `5`
+    Finnish,
+    /// French NRCS `R` or `f`
+    French,
+    /// French Canadian NRCS `Q` or `9`
+    FrenchCanadian,
+    /// German NRCS `K`
+    German,
+    /// Italian NRCS `Y`
+    Italian,
+    /// Norwegian/Danish NRCS `E`, `6` or `` ` ``
+    NorwegianDanish,
+    /// Portuguese NRCS `%6`
+    Portuguese,
+    /// Spanish NRCS `Z`
+    Spanish,
+    /// Swedish NRCS `H` or `7`
+    Swedish,
+    /// Swiss NRCS `=`
+    Swiss,
+}
+
+const DEC_SPECIAL_GRAPHICS: [char; 32] = [
+    '\u{00A0}', '◆', '▒', '␉', '␌', '␍', '␊', '°', '±', '␤', '␋', '┘', '┐', '┌', '└', '┼', '⎺',
+    '⎻', '─', '⎼', '⎽', '├', '┤', '┴', '┬', '│', '≤', '≥', 'π', '≠', '£', '·',
+];
+
+const DEC_SUPPLEMENTAL: [(u8, char); 5] = [
+    (b'(', '¤'),
+    (b'W', 'Œ'),
+    (b']', 'Ÿ'),
+    (b'w', 'œ'),
+    (b'}', 'ÿ'),
+];
+
+const DUTCH: [(u8, char); 9] = [
+    (b'#', '£'),
+    (b'@', '¾'),
+    (b'[', 'ĳ'),
+    (b'\\', '½'),
+    (b']', '|'),
+    (b'{', '¨'),
+    (b'|', 'ƒ'),
+    (b'}', '¼'),
+    (b'~', '´'),
+];
+
+const FINNISH: [(u8, char); 9] = [
+    (b'[', 'Ä'),
+    (b'\\', 'Ö'),
+    (b']', 'Å'),
+    (b'^', 'Ü'),
+    (b'`', 'é'),
+    (b'{', 'ä'),
+    (b'|', 'ö'),
+    (b'}', 'å'),
+    (b'~', 'ü'),
+];
+
+const FRENCH: [(u8, char); 9] = [
+    (b'#', '£'),
+    (b'@', 'à'),
+    (b'[', '°'),
+    (b'\\', 'ç'),
+    (b']', '§'),
+    (b'{', 'é'),
+    (b'|', 'ù'),
+    (b'}', 'è'),
+    (b'~', '¨'),
+];
+
+const FRENCH_CANADIAN: [(u8, char); 10] = [
+    (b'@', 'à'),
+    (b'[', 'â'),
+    (b'\\', 'ç'),
+    (b']', 'ê'),
+    (b'^', 'î'),
+    (b'`', 'ô'),
+    (b'{', 'é'),
+    (b'|', 'ù'),
+    (b'}', 'è'),
+    (b'~', 'û'),
+];
+
+const GERMAN: [(u8, char); 8] = [
+    (b'@', '§'),
+    (b'[', 'Ä'),
+    (b'\\', 'Ö'),
+    (b']', 'Ü'),
+    (b'{', 'ä'),
+    (b'|', 'ö'),
+    (b'}', 'ü'),
+    (b'~', 'ß'),
+];
+
+const ITALIAN: [(u8, char); 10] = [
+    (b'#', '£'),
+    (b'@', '§'),
+    (b'[', '°'),
+    (b'\\', 'ç'),
+    (b']', 'é'),
+    (b'`', 'ù'),
+    (b'{', 'à'),
+    (b'|', 'ò'),
+    (b'}', 'è'),
+    (b'~', 'ì'),
+];
+
+const NORWEGIAN_DANISH: [(u8, char); 10] = [
+    (b'@', 'Ä'),
+    (b'[', 'Æ'),
+    (b'\\', 'Ø'),
+    (b']', 'Å'),
+    (b'^', 'Ü'),
+    (b'`', 'ä'),
+    (b'{', 'æ'),
+    (b'|', 'ø'),
+    (b'}', 'å'),
+    (b'~', 'ü'),
+];
+
+const PORTUGUESE: [(u8, char); 6] = [
+    (b'[', 'Ã'),
+    (b'\\', 'Ç'),
+    (b']', 'Õ'),
+    (b'{', 'ã'),
+    (b'|', 'ç'),
+    (b'}', 'õ'),
+];
+
+const SPANISH: [(u8, char); 8] = [
+    (b'#', '£'),
+    (b'@', '§'),
+    (b'[', '¡'),
+    (b'\\', 'Ñ'),
+    (b']', '¿'),
+    (b'{', '°'),
+    (b'|', 'ñ'),
+    (b'}', 'ç'),
+];
+
+const SWEDISH: [(u8, char); 10] = [
+    (b'@', 'É'),
+    (b'[', 'Ä'),
+    (b'\\', 'Ö'),
+    (b']', 'Å'),
+    (b'^', 'Ü'),
+    (b'`', 'é'),
+    (b'{', 'ä'),
+    (b'|', 'ö'),
+    (b'}', 'å'),
+    (b'~', 'ü'),
+];
+
+const SWISS: [(u8, char); 12] = [
+    (b'#', 'ù'),
+    (b'@', 'à'),
+    (b'[', 'é'),
+    (b'\\', 'ç'),
+    (b']', 'ê'),
+    (b'^', 'î'),
+    (b'_', 'è'),
+    (b'`', 'ô'),
+    (b'{', 'ä'),
+    (b'|', 'ö'),
+    (b'}', 'ü'),
+    (b'~', 'û'),
+];
+
+impl Charset {
+    /// Decodes the intermediates and final byte of an nF escape sequence which designates a character set
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn from_designation(bytes: &[u8]) -> Option<(CharsetSlot, Self)> {
+        let (first, rest) = bytes.split_first()?;
+        let (slot, set96) = match first {
+            b'(' => (CharsetSlot::G0, false),
+            b')' => (CharsetSlot::G1, false),
+            b'*' => (CharsetSlot::G2, false),
+            b'+' => (CharsetSlot::G3, false),
+            b'-' => (CharsetSlot::G1, true),
+            b'.' => (CharsetSlot::G2, true),
+            b'/' => (CharsetSlot::G3, true),
+            _ => return None,
+        };
+        let charset = match (set96, rest) {
+            (false, b"B") => Self::Ascii,
+            (false, b"A") => Self::Uk,
+            (false, b"0") => Self::DecSpecialGraphics,
+            (false, b"<" | b"%5") => Self::DecSupplemental,
+            (false, b"4") => Self::Dutch,
+            (false, b"C" | b"5") => Self::Finnish,
+            (false, b"R" | b"f") => Self::French,
+            (false, b"Q" | b"9") => Self::FrenchCanadian,
+            (false, b"K") => Self::German,
+            (false, b"Y") => Self::Italian,
+            (false, b"E" | b"6" | b"`") => Self::NorwegianDanish,
+            (false, b"%6") => Self::Portuguese,
+            (false, b"Z") => Self::Spanish,
+            (false, b"H" | b"7") => Self::Swedish,
+            (false, b"=") => Self::Swiss,
+            (true, b"A") => Self::Latin1Supplemental,
+            _ => return None,
+        };
+        Some((slot, charset))
+    }
+
+    /// The intermediate and final bytes which designate `self` into `slot`, the inverse of
+    /// [`Self::from_designation`] picking the first of several aliases. None for a 96
+    /// character set in G0, which has no designation.
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn designation(self, slot: CharsetSlot) -> Option<(u8, &'static [u8])> {
+        let intermediate = match (slot, self) {
+            (CharsetSlot::G0, Self::Latin1Supplemental) => return None,
+            (CharsetSlot::G1, Self::Latin1Supplemental) => b'-',
+            (CharsetSlot::G2, Self::Latin1Supplemental) => b'.',
+            (CharsetSlot::G3, Self::Latin1Supplemental) => b'/',
+            (CharsetSlot::G0, _) => b'(',
+            (CharsetSlot::G1, _) => b')',
+            (CharsetSlot::G2, _) => b'*',
+            (CharsetSlot::G3, _) => b'+',
+        };
+        let bytes: &'static [u8] = match self {
+            Self::Ascii => b"B",
+            Self::Uk => b"A",
+            Self::DecSpecialGraphics => b"0",
+            Self::DecSupplemental => b"<",
+            Self::Latin1Supplemental => b"A",
+            Self::Dutch => b"4",
+            Self::Finnish => b"C",
+            Self::French => b"R",
+            Self::FrenchCanadian => b"Q",
+            Self::German => b"K",
+            Self::Italian => b"Y",
+            Self::NorwegianDanish => b"E",
+            Self::Portuguese => b"%6",
+            Self::Spanish => b"Z",
+            Self::Swedish => b"H",
+            Self::Swiss => b"=",
+        };
+        Some((intermediate, bytes))
+    }
+
+    /// Maps a byte in 0x20..=0x7F, the position within the set, to its character
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn map(self, byte: u8) -> char {
+        let replacements: &[(u8, char)] = match self {
+            // the space and delete positions are not part of 94 character sets
+            _ if self != Self::Latin1Supplemental && matches!(byte, 0x20 | 0x7F) => &[],
+            Self::Ascii => &[],
+            Self::Uk => &[(b'#', '£')],
+            Self::DecSpecialGraphics => {
+                return match DEC_SPECIAL_GRAPHICS.get((byte as usize).wrapping_sub(0x5F)) {
+                    Some(c) => *c,
+                    None => byte as char,
+                };
+            }
+            Self::DecSupplemental => &DEC_SUPPLEMENTAL,
+            Self::Latin1Supplemental => return (byte | 0x80) as char,
+            Self::Dutch => &DUTCH,
+            Self::Finnish => &FINNISH,
+            Self::French => &FRENCH,
+            Self::FrenchCanadian => &FRENCH_CANADIAN,
+            Self::German => &GERMAN,
+            Self::Italian => &ITALIAN,
+            Self::NorwegianDanish => &NORWEGIAN_DANISH,
+            Self::Portuguese => &PORTUGUESE,
+            Self::Spanish => &SPANISH,
+            Self::Swedish => &SWEDISH,
+            Self::Swiss => &SWISS,
+        };
+        match replacements.iter().find(|(b, _)| *b == byte) {
+            Some((_, c)) => *c,
+            // DEC Supplemental otherwise follows the layout of Latin-1
+            None if self == Self::DecSupplemental && byte != 0x20 && byte != 0x7F => {
+                (byte | 0x80) as char
+            }
+            None => byte as char,
+        }
+    }
+}
+
+/// An optional layer on top of the parser which tracks designations, locking
+/// shifts and single shifts and translates [`Out::Data`] and [`Out::Text`] through
+/// them. Designations are only reported with [`Config::designations`] enabled.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub struct Charsets {
+    pub g: [Charset; 4],
+    /// The set invoked into 0x20..=0x7F
+    pub gl: CharsetSlot,
+    /// The set invoked into 0xA0..=0xFF
+    pub gr: CharsetSlot,
+    /// The set used for the next character only
+    pub single_shift: crate::FfiOption<CharsetSlot>,
+    /// Translate data in 0xA0..=0xFF through GR, only sensible when UTF-8 is disabled
+    pub use_gr: bool,
+}
+
+impl Default for Charsets {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Charsets {
+    pub const fn new() -> Self {
+        Self {
+            g: [Charset::Ascii; 4],
+            gl: CharsetSlot::G0,
+            gr: CharsetSlot::G2,
+            single_shift: crate::FfiOption::None,
+            use_gr: false,
+        }
+    }
+
+    /// Updates the shift state from `out` and translates it if it is data.
+    ///
+    /// [`Out::Text`] is returned whole while the sets in effect leave it unchanged,
+    /// otherwise it is split into an [`Out::Data`] per character.
+    pub fn translate<'a, 'c>(&'c mut self, out: Out<'a>) -> Translated<'a, 'c> {
+        Translated {
+            charsets: self,
+            out,
+        }
+    }
+
+    /// Characters pass through GL unchanged and no single shift is pending
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn identity(&self) -> bool {
+        matches!(self.single_shift, crate::FfiOption::None)
+            && !self.use_gr
+            && self.g.get(self.gl as usize) == Some(&Charset::Ascii)
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn update<'a>(&mut self, out: Out<'a>) -> Out<'a> {
+        match out {
+            Out::Designate { slot, charset, .. } => {
+                if let Some(g) = self.g.get_mut(slot as usize) {
+                    *g = charset;
+                }
+            }
+            Out::C0(C0::SO) => self.gl = CharsetSlot::G1,
+            Out::C0(C0::SI) => self.gl = CharsetSlot::G0,
+            Out::Fs(Fs::LS2) => self.gl = CharsetSlot::G2,
+            Out::Fs(Fs::LS3) => self.gl = CharsetSlot::G3,
+            Out::Fs(Fs::LS1R) => self.gr = CharsetSlot::G1,
+            Out::Fs(Fs::LS2R) => self.gr = CharsetSlot::G2,
+            Out::Fs(Fs::LS3R) => self.gr = CharsetSlot::G3,
+            Out::C1(C1::SS2) => self.single_shift = crate::FfiOption::Some(CharsetSlot::G2),
+            Out::C1(C1::SS3) => self.single_shift = crate::FfiOption::Some(CharsetSlot::G3),
+            Out::Data(c) => return Out::Data(self.map(c)),
+            _ => {}
+        }
+        out
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    #[allow(clippy::useless_conversion)]
+    fn map(&mut self, c: crate::FfiChar) -> crate::FfiChar {
+        let (byte, locked) = match u32::from(c) {
+            code @ 0x20..=0x7F => (code as u8, self.gl),
+            code @ 0xA0..=0xFF if self.use_gr => ((code as u8) & 0x7F, self.gr),
+            _ => return c,
+        };
+        let slot = core::mem::take(&mut self.single_shift).unwrap_or(locked);
+        match self.g.get(slot as usize) {
+            Some(charset) => crate::FfiChar::from(charset.map(byte)),
+            None => c,
+        }
+    }
+}
+
+/// The events returned by [`Charsets::translate`] for a single event
+#[derive(Debug)]
+pub struct Translated<'a, 'c> {
+    charsets: &'c mut Charsets,
+    out: Out<'a>,
+}
+
+impl<'a> Iterator for Translated<'a, '_> {
+    type Item = Out<'a>;
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    #[allow(clippy::useless_conversion)]
+    fn next(&mut self) -> Option<Out<'a>> {
+        match core::mem::replace(&mut self.out, Out::None) {
+            Out::None => None,
+            Out::Text(text) if self.charsets.identity() => Some(Out::Text(text)),
+            Out::Text(text) => {
+                let mut chars = <&str>::from(text).chars();
+                let c = chars.next()?;
+                if !chars.as_str().is_empty() {
+                    self.out = Out::Text(chars.as_str().into());
+                }
+                Some(Out::Data(self.charsets.map(c.into())))
+            }
+            out => Some(self.charsets.update(out)),
+        }
+    }
+}
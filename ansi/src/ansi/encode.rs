@@ -0,0 +1,251 @@
+use crate::ansi::*;
+
+/// Whether C1 controls are written as `ESC Fe` or as a single byte in 0x80..=0x9F
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum ControlForm {
+    #[default]
+    SevenBit,
+    /// Requires [`Config::bit8_enabled`] to be parsed back
+    EightBit,
+}
+
+/// A destination for encoded events
+pub trait ByteSink {
+    type Error;
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), Self::Error>;
+}
+
+/// The slice was too short to hold the encoded event
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BufferFull;
+
+/// Writes to the front of the slice and advances it past the written bytes
+impl ByteSink for &mut [u8] {
+    type Error = BufferFull;
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        let (head, tail) = core::mem::take(self)
+            .split_at_mut_checked(bytes.len())
+            .ok_or(BufferFull)?;
+        head.iter_mut()
+            .zip(bytes)
+            .for_each(|(dest, byte)| *dest = *byte);
+        *self = tail;
+        Ok(())
+    }
+}
+
+/// Bytes which are not valid UTF-8, such as 8-bit C1 controls, are written as the
+/// code point of the same value.
+pub(crate) struct FmtSink<'a, W: ?Sized>(pub(crate) &'a mut W);
+
+impl<W: core::fmt::Write + ?Sized> ByteSink for FmtSink<'_, W> {
+    type Error = core::fmt::Error;
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        for chunk in bytes.utf8_chunks() {
+            self.0.write_str(chunk.valid())?;
+            for byte in chunk.invalid() {
+                self.0.write_char(char::from(*byte))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl C0 {
+    pub fn write_bytes<S: ByteSink + ?Sized>(self, sink: &mut S) -> Result<(), S::Error> {
+        sink.write_bytes(&[self as u8])
+    }
+
+    pub fn write_to(self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        self.write_bytes(&mut FmtSink(w))
+    }
+}
+
+impl C1 {
+    pub fn write_bytes<S: ByteSink + ?Sized>(
+        self,
+        sink: &mut S,
+        form: ControlForm,
+    ) -> Result<(), S::Error> {
+        match form {
+            ControlForm::SevenBit => sink.write_bytes(&[0x1B, self as u8]),
+            ControlForm::EightBit => sink.write_bytes(&[(self as u8).wrapping_add(0x40)]),
+        }
+    }
+
+    pub fn write_to(self, w: &mut impl core::fmt::Write, form: ControlForm) -> core::fmt::Result {
+        self.write_bytes(&mut FmtSink(w), form)
+    }
+}
+
+impl Fp {
+    pub fn write_bytes<S: ByteSink + ?Sized>(self, sink: &mut S) -> Result<(), S::Error> {
+        sink.write_bytes(&[0x1B, self as u8])
+    }
+
+    pub fn write_to(self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        self.write_bytes(&mut FmtSink(w))
+    }
+}
+
+impl Fs {
+    pub fn write_bytes<S: ByteSink + ?Sized>(self, sink: &mut S) -> Result<(), S::Error> {
+        sink.write_bytes(&[0x1B, self as u8])
+    }
+
+    pub fn write_to(self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        self.write_bytes(&mut FmtSink(w))
+    }
+}
+
+impl StringTerminator {
+    pub fn write_bytes<S: ByteSink + ?Sized>(
+        self,
+        sink: &mut S,
+        form: ControlForm,
+    ) -> Result<(), S::Error> {
+        match self {
+            Self::ST => C1::ST.write_bytes(sink, form),
+            Self::BEL => C0::BEL.write_bytes(sink),
+        }
+    }
+}
+
+impl crate::CSI<'_> {
+    pub fn write_bytes<S: ByteSink + ?Sized>(
+        &self,
+        sink: &mut S,
+        form: ControlForm,
+    ) -> Result<(), S::Error> {
+        C1::CSI.write_bytes(sink, form)?;
+        sink.write_bytes(self.bytes())
+    }
+
+    pub fn write_to(&self, w: &mut impl core::fmt::Write, form: ControlForm) -> core::fmt::Result {
+        self.write_bytes(&mut FmtSink(w), form)
+    }
+}
+
+/// Writes a parameter in decimal
+pub(crate) fn write_param<S: ByteSink + ?Sized>(
+    sink: &mut S,
+    mut value: u16,
+) -> Result<(), S::Error> {
+    let mut digits = [0; 5];
+    let mut start = digits.len();
+    while let Some(digit) = start.checked_sub(1).and_then(|i| digits.get_mut(i)) {
+        *digit = b'0' + (value % 10) as u8;
+        start -= 1;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    sink.write_bytes(digits.get(start..).unwrap_or(&[]))
+}
+
+impl Out<'_> {
+    /// Writes the bytes the event was parsed from in canonical form.
+    ///
+    /// Data is written as UTF-8. Buffered control strings such as [`Out::OSC`] only
+    /// write their payload and terminator, their introducer is an event of its own.
+    /// Errors which do not keep the bytes they were parsed from, such as
+    /// [`Out::CSISequenceTooLarge`], write nothing.
+    #[allow(clippy::useless_conversion)]
+    pub fn write_bytes<S: ByteSink + ?Sized>(
+        &self,
+        sink: &mut S,
+        form: ControlForm,
+    ) -> Result<(), S::Error> {
+        match *self {
+            Out::Data(c)
+            | Out::DCSData(c)
+            | Out::SData(c)
+            | Out::PMData(c)
+            | Out::APCData(c)
+            | Out::OSData(c) => match char::try_from(c) {
+                Ok(c) => sink.write_bytes(c.encode_utf8(&mut [0; 4]).as_bytes()),
+                Err(_) => Ok(()),
+            },
+            Out::Text(text) => {
+                let text: &str = text.into();
+                sink.write_bytes(text.as_bytes())
+            }
+
+            Out::DCS {
+                payload,
+                terminator,
+            }
+            | Out::SOS {
+                payload,
+                terminator,
+            }
+            | Out::PM {
+                payload,
+                terminator,
+            }
+            | Out::APC {
+                payload,
+                terminator,
+            }
+            | Out::OSC {
+                payload,
+                terminator,
+            } => {
+                sink.write_bytes(payload.into())?;
+                terminator.write_bytes(sink, form)
+            }
+            Out::StringEnd { terminator, .. } => terminator.write_bytes(sink, form),
+            Out::StringAborted {
+                cause: StringAbort::CAN,
+                ..
+            } => C0::CAN.write_bytes(sink),
+            Out::StringAborted {
+                cause: StringAbort::SUB,
+                ..
+            } => C0::SUB.write_bytes(sink),
+            Out::StringAborted {
+                cause: StringAbort::Interrupted,
+                ..
+            } => Ok(()),
+
+            Out::CSI(csi) | Out::CSITruncated { csi, .. } => csi.write_bytes(sink, form),
+
+            Out::nF(bytes) | Out::Designate { bytes, .. } => {
+                sink.write_bytes(&[0x1B])?;
+                sink.write_bytes(bytes.into())
+            }
+
+            Out::C0(c0) => c0.write_bytes(sink),
+            Out::C1(c1) => c1.write_bytes(sink, form),
+            Out::Fp(fp) => fp.write_bytes(sink),
+            Out::Fs(fs) => fs.write_bytes(sink),
+
+            Out::SP => sink.write_bytes(b" "),
+            Out::DEL => sink.write_bytes(&[0x7F]),
+            Out::ESC => sink.write_bytes(&[0x1B]),
+            // replaces the SS3 which already wrote its bytes, see `AnsiParser::flush_pending`
+            Out::Alt(b'O') => Ok(()),
+            Out::Alt(byte) | Out::InvalidEscapeByte(byte) => sink.write_bytes(&[0x1B, byte]),
+
+            Out::StringSequenceTooLarge
+            | Out::CSISequenceTooLarge
+            | Out::CSIIntermediateOverflow
+            | Out::nFSequenceTooLarge
+            | Out::nFInvalidSequence
+            | Out::InvalidUtf8Sequence
+            | Out::None => Ok(()),
+        }
+    }
+
+    /// Same as [`Self::write_bytes`], bytes which are not valid UTF-8 are written as
+    /// the code point of the same value
+    pub fn write_to(&self, w: &mut impl core::fmt::Write, form: ControlForm) -> core::fmt::Result {
+        self.write_bytes(&mut FmtSink(w), form)
+    }
+}
@@ -0,0 +1,385 @@
+use super::unicode_tables::*;
+use crate::ansi::*;
+
+/// The Grapheme_Cluster_Break property with InCB=Consonant split out of Other
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub(super) enum GraphemeBreak {
+    Any,
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    ExtendedPictographic,
+    InCBConsonant,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+enum Emoji {
+    #[default]
+    None,
+    /// Extended_Pictographic Extend*
+    Pictographic,
+    /// Extended_Pictographic Extend* ZWJ
+    Joined,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+enum Conjunct {
+    #[default]
+    None,
+    /// InCB=Consonant followed by InCB=Extend
+    Consonant,
+    /// InCB=Consonant followed by at least one InCB=Linker
+    Linked,
+}
+
+#[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+fn in_ranges(table: &[(u32, u32)], c: u32) -> bool {
+    table
+        .binary_search_by(|(lo, hi)| {
+            if c < *lo {
+                core::cmp::Ordering::Greater
+            } else if c > *hi {
+                core::cmp::Ordering::Less
+            } else {
+                core::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+#[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+fn grapheme_break(c: char) -> GraphemeBreak {
+    let c = c as u32;
+    match GRAPHEME_BREAK.binary_search_by(|(lo, hi, _)| {
+        if c < *lo {
+            core::cmp::Ordering::Greater
+        } else if c > *hi {
+            core::cmp::Ordering::Less
+        } else {
+            core::cmp::Ordering::Equal
+        }
+    }) {
+        Ok(i) => GRAPHEME_BREAK
+            .get(i)
+            .map_or(GraphemeBreak::Any, |(_, _, g)| *g),
+        Err(_) => GraphemeBreak::Any,
+    }
+}
+
+#[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+fn is_incb_linker(c: char) -> bool {
+    matches!(
+        c,
+        '\u{94D}' | '\u{9CD}' | '\u{ACD}' | '\u{B4D}' | '\u{C4D}' | '\u{D4D}'
+    )
+}
+
+/// The number of columns `c` takes up on its own, 0, 1 or 2
+#[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+pub fn char_width(c: char) -> u8 {
+    if in_ranges(ZERO_WIDTH, c as u32) {
+        0
+    } else if in_ranges(WIDE, c as u32) {
+        2
+    } else {
+        1
+    }
+}
+
+/// An extended grapheme cluster along with the columns it takes up
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub struct Cluster<'a> {
+    pub text: crate::FfiStr<'a>,
+    pub width: u8,
+}
+
+pub type SizedGraphemes<const BUF_CAP: usize> = Graphemes<[u8; BUF_CAP]>;
+
+pub type UnsizedGraphemes = Graphemes<[u8]>;
+
+/// Groups the characters of the [`Out`] stream into extended grapheme clusters.
+///
+/// Code points which do not fit into the buffer are dropped from the cluster text
+/// but still take part in segmentation.
+#[derive(Debug)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub struct Graphemes<T: ?Sized> {
+    /// Cluster characters as DEC private mode 2027 does, when disabled every
+    /// code point is a cluster of its own with its wcwidth
+    pub clustering: bool,
+
+    prev: Option<GraphemeBreak>,
+    regional_odd: bool,
+    emoji: Emoji,
+    conjunct: Conjunct,
+
+    /// The character which broke off the returned cluster and starts the next one
+    carry: Option<char>,
+    /// The buffer holds a cluster which has already been returned
+    stale: bool,
+    width: u8,
+    regional: u8,
+    presentation: bool,
+
+    len: usize,
+    buffer: T,
+}
+
+impl<const BYTE_BUF_SIZE: usize> core::default::Default for SizedGraphemes<BYTE_BUF_SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const BYTE_BUF_SIZE: usize> SizedGraphemes<BYTE_BUF_SIZE> {
+    pub const fn new() -> Self {
+        Self {
+            clustering: true,
+
+            prev: None,
+            regional_odd: false,
+            emoji: Emoji::None,
+            conjunct: Conjunct::None,
+
+            carry: None,
+            stale: false,
+            width: 0,
+            regional: 0,
+            presentation: false,
+
+            len: 0,
+            buffer: [0; BYTE_BUF_SIZE],
+        }
+    }
+
+    #[inline(always)]
+    pub fn next(&mut self, out: &mut Out<'_>) -> Option<Cluster<'_>> {
+        let tc: &mut UnsizedGraphemes = self;
+        tc.next(out)
+    }
+
+    #[inline(always)]
+    pub fn push(&mut self, c: char) -> Option<Cluster<'_>> {
+        let tc: &mut UnsizedGraphemes = self;
+        tc.push(c)
+    }
+
+    #[inline(always)]
+    pub fn flush(&mut self) -> Option<Cluster<'_>> {
+        let tc: &mut UnsizedGraphemes = self;
+        tc.flush()
+    }
+
+    #[inline(always)]
+    pub fn observe(&mut self, out: &Out<'_>) {
+        let tc: &mut UnsizedGraphemes = self;
+        tc.observe(out)
+    }
+}
+
+impl UnsizedGraphemes {
+    /// Feeds an event from the parser, returning the cluster it completed.
+    ///
+    /// [`Out::Data`] and [`Out::Text`] are clustered, any other event besides [`Out::None`]
+    /// ends the current cluster. `out` is taken, apart from the rest of a text run which
+    /// can complete more clusters, so call this until it returns none.
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    #[allow(clippy::useless_conversion)]
+    pub fn next(&mut self, out: &mut Out<'_>) -> Option<Cluster<'_>> {
+        match core::mem::replace(out, Out::None) {
+            Out::None => None,
+            Out::Data(c) => match char::try_from(c) {
+                Ok(c) => self.push(c),
+                Err(_) => self.flush(),
+            },
+            Out::Text(text) => {
+                let mut chars = <&str>::from(text).chars();
+                for c in chars.by_ref() {
+                    if self.push_char(c) {
+                        if !chars.as_str().is_empty() {
+                            *out = Out::Text(chars.as_str().into());
+                        }
+                        return Some(self.cluster());
+                    }
+                }
+                None
+            }
+            taken => {
+                self.observe(&taken);
+                self.flush()
+            }
+        }
+    }
+
+    /// Adds a character, returning the previous cluster if `c` starts a new one
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn push(&mut self, c: char) -> Option<Cluster<'_>> {
+        if self.push_char(c) {
+            Some(self.cluster())
+        } else {
+            None
+        }
+    }
+
+    /// Adds a character, true if it broke off the cluster before it
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn push_char(&mut self, c: char) -> bool {
+        self.restore();
+        let class = grapheme_break(c);
+        let boundary = match self.prev {
+            None => false,
+            Some(_) if !self.clustering => true,
+            Some(prev) => self.is_boundary(prev, class),
+        };
+        self.advance_state(class, c);
+        if boundary {
+            self.carry = Some(c);
+            self.stale = true;
+        } else {
+            self.append(c);
+        }
+        boundary
+    }
+
+    /// Ends the current cluster and returns it
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn flush(&mut self) -> Option<Cluster<'_>> {
+        self.restore();
+        self.prev.take()?;
+        self.regional_odd = false;
+        self.emoji = Emoji::None;
+        self.conjunct = Conjunct::None;
+        self.stale = true;
+        Some(self.cluster())
+    }
+
+    /// Tracks DEC private mode 2027 being set or reset
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn observe(&mut self, out: &Out<'_>) {
+        let Out::CSI(csi) = out else {
+            return;
+        };
+        let (mut modes, set) = match csi.parse() {
+            crate::KnownCSI::SetPrivateModes(modes) => (modes, true),
+            crate::KnownCSI::ResetPrivateModes(modes) => (modes, false),
+            _ => return,
+        };
+        if modes.any(|mode| mode == crate::DecPrivateMode::GraphemeClustering) {
+            self.clustering = set;
+        }
+    }
+
+    /// Clears out a cluster which was already returned and starts the next one
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn restore(&mut self) {
+        if self.stale {
+            self.stale = false;
+            self.len = 0;
+            self.width = 0;
+            self.regional = 0;
+            self.presentation = false;
+        }
+        if let Some(c) = self.carry.take() {
+            self.append(c);
+        }
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn append(&mut self, c: char) {
+        let mut bytes = [0; 4];
+        let bytes = c.encode_utf8(&mut bytes).as_bytes();
+        let end = self.len.saturating_add(bytes.len());
+        if let Some(dest) = self.buffer.get_mut(self.len..end) {
+            dest.iter_mut()
+                .zip(bytes)
+                .for_each(|(dest, byte)| *dest = *byte);
+            self.len = end;
+        }
+
+        self.width = self.width.max(char_width(c));
+        if grapheme_break(c) == GraphemeBreak::RegionalIndicator {
+            self.regional = self.regional.saturating_add(1);
+        }
+        self.presentation |= c == '\u{FE0F}';
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn cluster(&self) -> Cluster<'_> {
+        let text = self
+            .buffer
+            .get(..self.len)
+            .and_then(|text| core::str::from_utf8(text).ok())
+            .unwrap_or_default();
+        // an emoji presentation selector or a flag widens the cluster
+        let width = if self.presentation || self.regional >= 2 {
+            2
+        } else {
+            self.width
+        };
+        #[allow(clippy::useless_conversion)]
+        Cluster {
+            text: text.into(),
+            width,
+        }
+    }
+
+    /// Whether there is a boundary between the cluster so far and `c`, see UAX #29
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn is_boundary(&self, prev: GraphemeBreak, class: GraphemeBreak) -> bool {
+        use GraphemeBreak as G;
+        match (prev, class) {
+            // GB3 - GB5
+            (G::Cr, G::Lf) => false,
+            (G::Control | G::Cr | G::Lf, _) | (_, G::Control | G::Cr | G::Lf) => true,
+            // GB6 - GB8
+            (G::L, G::L | G::V | G::Lv | G::Lvt) => false,
+            (G::Lv | G::V, G::V | G::T) => false,
+            (G::Lvt | G::T, G::T) => false,
+            // GB9 - GB9b
+            (_, G::Extend | G::Zwj | G::SpacingMark) => false,
+            (G::Prepend, _) => false,
+            // GB9c
+            (_, G::InCBConsonant) if self.conjunct == Conjunct::Linked => false,
+            // GB11
+            (G::Zwj, G::ExtendedPictographic) if self.emoji == Emoji::Joined => false,
+            // GB12 - GB13
+            (G::RegionalIndicator, G::RegionalIndicator) if self.regional_odd => false,
+            _ => true,
+        }
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn advance_state(&mut self, class: GraphemeBreak, c: char) {
+        use GraphemeBreak as G;
+        self.regional_odd = class == G::RegionalIndicator
+            && !(self.prev == Some(G::RegionalIndicator) && self.regional_odd);
+        self.emoji = match (class, self.emoji) {
+            (G::ExtendedPictographic, _) => Emoji::Pictographic,
+            (G::Extend, Emoji::Pictographic) => Emoji::Pictographic,
+            (G::Zwj, Emoji::Pictographic) => Emoji::Joined,
+            _ => Emoji::None,
+        };
+        self.conjunct = match self.conjunct {
+            _ if class == G::InCBConsonant => Conjunct::Consonant,
+            Conjunct::None => Conjunct::None,
+            _ if is_incb_linker(c) => Conjunct::Linked,
+            conjunct if in_ranges(INCB_EXTEND, c as u32) => conjunct,
+            _ => Conjunct::None,
+        };
+        self.prev = Some(class);
+    }
+}
@@ -0,0 +1,100 @@
+use crate::ansi::*;
+use alloc::vec::Vec;
+
+/// A byte buffer on the heap which grows as sequences need it, up to a hard limit
+#[derive(Debug, Clone)]
+pub struct GrowableBuffer {
+    bytes: Vec<u8>,
+    limit: usize,
+}
+
+impl GrowableBuffer {
+    pub const fn new(limit: usize) -> Self {
+        Self {
+            bytes: Vec::new(),
+            limit,
+        }
+    }
+
+    /// The most bytes the buffer will grow to, sequences past it overflow as usual
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// The number of bytes currently allocated
+    pub fn capacity(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Releases memory beyond `len` bytes, such as after a large payload
+    pub fn shrink_to(&mut self, len: usize) {
+        self.bytes.truncate(len);
+        self.bytes.shrink_to(len);
+    }
+}
+
+impl ParserBuffer for GrowableBuffer {
+    #[inline(always)]
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    #[inline(always)]
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn reserve(&mut self, len: usize) -> bool {
+        let current = self.bytes.len();
+        if len <= current {
+            return true;
+        }
+        if len > self.limit {
+            return false;
+        }
+        grow(
+            &mut self.bytes,
+            len.max(current.saturating_mul(2)).min(self.limit),
+        )
+    }
+}
+
+/// Grows `bytes` to `len` zeroed bytes, false if the allocation failed.
+///
+/// `resize` and `extend` keep their panicking reallocation even after the capacity was
+/// reserved, while `push` only reallocates when the length reached the capacity, which
+/// the loop rules out.
+#[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+fn grow(bytes: &mut Vec<u8>, len: usize) -> bool {
+    if bytes
+        .try_reserve_exact(len.saturating_sub(bytes.len()))
+        .is_err()
+    {
+        return false;
+    }
+    while bytes.len() < len {
+        if bytes.len() == bytes.capacity() {
+            return false;
+        }
+        bytes.push(0);
+    }
+    true
+}
+
+pub type VecAnsiParser = AnsiParser<GrowableBuffer>;
+
+impl VecAnsiParser {
+    /// A parser which buffers sequences of up to `limit` bytes
+    pub const fn with_limit(limit: usize) -> Self {
+        Self::with_buffer(GrowableBuffer::new(limit))
+    }
+
+    pub fn buffer(&self) -> &GrowableBuffer {
+        &self.buffer
+    }
+
+    pub fn buffer_mut(&mut self) -> &mut GrowableBuffer {
+        &mut self.buffer
+    }
+}
@@ -1,13 +1,45 @@
+mod charset;
+mod encode;
+mod encoding;
+mod grapheme;
+#[cfg(any(test, feature = "alloc"))]
+mod growable;
+#[cfg(any(test, feature = "alloc"))]
+mod owned;
 mod parser;
+mod perform;
+#[cfg(any(test, feature = "std"))]
+mod reader;
+mod snapshot;
+mod span;
+mod table;
 #[cfg(test)]
 mod test;
+mod unicode_tables;
+mod vt500;
+pub use charset::*;
+pub use encode::*;
+pub use encoding::*;
+pub use grapheme::*;
+#[cfg(any(test, feature = "alloc"))]
+pub use growable::*;
+#[cfg(any(test, feature = "alloc"))]
+pub use owned::*;
 pub use parser::*;
+pub use perform::*;
+#[cfg(any(test, feature = "std"))]
+pub use reader::*;
+pub use snapshot::*;
+pub use span::*;
+pub use vt500::*;
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 #[allow(non_camel_case_types)]
 #[cfg_attr(feature = "crepr", repr(C))]
 pub enum Out<'a> {
     Data(crate::FfiChar),
+    /// A run of printable characters, see [`Config::text_runs`]
+    Text(crate::FfiStr<'a>),
 
     DCSData(crate::FfiChar),
     SData(crate::FfiChar),
@@ -15,13 +47,61 @@ pub enum Out<'a> {
     APCData(crate::FfiChar),
     OSData(crate::FfiChar),
 
+    /// Complete control string bodies and what terminated them, see [`Config::buffer_strings`]
+    DCS {
+        payload: crate::FfiSlice<'a, u8>,
+        terminator: StringTerminator,
+    },
+    SOS {
+        payload: crate::FfiSlice<'a, u8>,
+        terminator: StringTerminator,
+    },
+    PM {
+        payload: crate::FfiSlice<'a, u8>,
+        terminator: StringTerminator,
+    },
+    APC {
+        payload: crate::FfiSlice<'a, u8>,
+        terminator: StringTerminator,
+    },
+    OSC {
+        payload: crate::FfiSlice<'a, u8>,
+        terminator: StringTerminator,
+    },
+    StringSequenceTooLarge,
+
+    /// A control string was properly terminated
+    StringEnd {
+        kind: StringKind,
+        terminator: StringTerminator,
+    },
+    /// A control string was cut off by CAN, SUB or the start of another sequence
+    StringAborted {
+        kind: StringKind,
+        cause: StringAbort,
+    },
+
     CSI(crate::csi::CSI<'a>),
+    /// A control sequence which lost bytes to an overflow, see [`Config::csi_overflow`]
+    CSITruncated {
+        csi: crate::csi::CSI<'a>,
+        /// The number of parameters dropped, zero if only intermediates were lost
+        dropped: usize,
+    },
     CSISequenceTooLarge,
     CSIIntermediateOverflow,
 
     nF(crate::FfiSlice<'a, u8>),
     nFSequenceTooLarge,
     nFInvalidSequence,
+    /// An nF sequence such as `ESC ( 0` which designates a character set, see [`Charsets`]
+    Designate {
+        slot: CharsetSlot,
+        charset: Charset,
+        /// The intermediate and final bytes as received, several of them can name the
+        /// same charset
+        bytes: crate::FfiSlice<'a, u8>,
+    },
 
     // 0x00 ..= 0x1F
     C0(C0),
@@ -34,11 +114,17 @@ pub enum Out<'a> {
     SP,
     /// Delete
     DEL,
+    /// Escape on its own, see [`AnsiParser::flush_pending`]
+    ESC,
 
     InvalidEscapeByte(u8),
+    /// `ESC` followed by a byte which was left on its own, usually Alt and a key.
+    /// `Alt(b'O')` stands in for the SS3 reported just before it, see
+    /// [`AnsiParser::flush_pending`]
+    Alt(u8),
 
+    /// A maximal ill-formed UTF-8 subpart, see [`Config::utf8_replacement`]
     InvalidUtf8Sequence,
-    InvalidCodepoint(u32),
 
     None,
 }
@@ -259,3 +345,157 @@ pub enum Fs {
     /// Locking shift one right
     LS1R = b'~',
 }
+
+impl TryFrom<u8> for C0 {
+    type Error = u8;
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::NUL,
+            1 => Self::SOH,
+            2 => Self::STX,
+            3 => Self::ETX,
+            4 => Self::EOT,
+            5 => Self::ENQ,
+            6 => Self::ACK,
+            7 => Self::BEL,
+            8 => Self::BS,
+            9 => Self::HT,
+            10 => Self::LF,
+            11 => Self::VT,
+            12 => Self::FF,
+            13 => Self::CR,
+            14 => Self::SO,
+            15 => Self::SI,
+            16 => Self::DLE,
+            17 => Self::DC1,
+            18 => Self::DC2,
+            19 => Self::DC3,
+            20 => Self::DC4,
+            21 => Self::NAK,
+            22 => Self::SYN,
+            23 => Self::ETB,
+            24 => Self::CAN,
+            25 => Self::EM,
+            26 => Self::SUB,
+            28 => Self::FS,
+            29 => Self::GS,
+            30 => Self::RS,
+            31 => Self::US,
+            _ => return Err(value),
+        })
+    }
+}
+
+impl TryFrom<u8> for Fp {
+    type Error = u8;
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            b'0' => Self::UnknownX30,
+            b'1' => Self::UnknownX31,
+            b'2' => Self::UnknownX32,
+            b'3' => Self::UnknownX33,
+            b'4' => Self::UnknownX34,
+            b'5' => Self::UnknownX35,
+            b'6' => Self::DECFI,
+            b'7' => Self::DECSC,
+            b'8' => Self::DECRC,
+            b'9' => Self::UnknownX39,
+            b':' => Self::UnknownX3A,
+            b';' => Self::UnknownX3B,
+            b'<' => Self::UnknownX3C,
+            b'=' => Self::DECKPAM,
+            b'>' => Self::DECKPNM,
+            b'?' => Self::UnknownX3F,
+            _ => return Err(value),
+        })
+    }
+}
+
+impl TryFrom<u8> for C1 {
+    type Error = u8;
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            b'@' => Self::PAD,
+            b'A' => Self::HOP,
+            b'B' => Self::BPH,
+            b'C' => Self::NBH,
+            b'D' => Self::IND,
+            b'E' => Self::NEL,
+            b'F' => Self::SSA,
+            b'G' => Self::ESA,
+            b'H' => Self::HTS,
+            b'I' => Self::HTJ,
+            b'J' => Self::VTS,
+            b'K' => Self::PLD,
+            b'L' => Self::PLU,
+            b'M' => Self::RI,
+            b'N' => Self::SS2,
+            b'O' => Self::SS3,
+            b'P' => Self::DCS,
+            b'Q' => Self::PU1,
+            b'R' => Self::PU2,
+            b'S' => Self::STS,
+            b'T' => Self::CCH,
+            b'U' => Self::MW,
+            b'V' => Self::SPA,
+            b'W' => Self::EPA,
+            b'X' => Self::SOS,
+            b'Y' => Self::SGCI,
+            b'Z' => Self::SCI,
+            b'[' => Self::CSI,
+            b'\\' => Self::ST,
+            b']' => Self::OSC,
+            b'^' => Self::PM,
+            b'_' => Self::APC,
+            _ => return Err(value),
+        })
+    }
+}
+
+impl TryFrom<u8> for Fs {
+    type Error = u8;
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            b'`' => Self::DMI,
+            b'a' => Self::INT,
+            b'b' => Self::EMI,
+            b'c' => Self::RIS,
+            b'd' => Self::CMD,
+            b'e' => Self::UnknownX65,
+            b'f' => Self::UnknownX66,
+            b'g' => Self::UnknownX67,
+            b'h' => Self::UnknownX68,
+            b'i' => Self::UnknownX69,
+            b'j' => Self::UnknownX6A,
+            b'k' => Self::UnknownX6B,
+            b'l' => Self::LCKMEM,
+            b'm' => Self::ULKMEM,
+            b'n' => Self::LS2,
+            b'o' => Self::LS3,
+            b'p' => Self::UnknownX70,
+            b'q' => Self::UnknownX71,
+            b'r' => Self::UnknownX72,
+            b's' => Self::UnknownX73,
+            b't' => Self::UnknownX74,
+            b'u' => Self::UnknownX75,
+            b'v' => Self::UnknownX76,
+            b'w' => Self::UnknownX77,
+            b'x' => Self::UnknownX78,
+            b'y' => Self::UnknownX79,
+            b'z' => Self::UnknownX7A,
+            b'{' => Self::UnknownX7B,
+            b'|' => Self::LS3R,
+            b'}' => Self::LS2R,
+            b'~' => Self::LS1R,
+            _ => return Err(value),
+        })
+    }
+}
@@ -0,0 +1,270 @@
+use crate::ansi::*;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// An [`Out`] which owns its payload, so it can be stored or sent to another thread
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[allow(non_camel_case_types)]
+pub enum OwnedOut {
+    Data(crate::FfiChar),
+    Text(String),
+
+    DCSData(crate::FfiChar),
+    SData(crate::FfiChar),
+    PMData(crate::FfiChar),
+    APCData(crate::FfiChar),
+    OSData(crate::FfiChar),
+
+    DCS {
+        payload: Vec<u8>,
+        terminator: StringTerminator,
+    },
+    SOS {
+        payload: Vec<u8>,
+        terminator: StringTerminator,
+    },
+    PM {
+        payload: Vec<u8>,
+        terminator: StringTerminator,
+    },
+    APC {
+        payload: Vec<u8>,
+        terminator: StringTerminator,
+    },
+    OSC {
+        payload: Vec<u8>,
+        terminator: StringTerminator,
+    },
+    StringSequenceTooLarge,
+
+    StringEnd {
+        kind: StringKind,
+        terminator: StringTerminator,
+    },
+    StringAborted {
+        kind: StringKind,
+        cause: StringAbort,
+    },
+
+    CSI(Vec<u8>),
+    CSITruncated {
+        csi: Vec<u8>,
+        dropped: usize,
+    },
+    CSISequenceTooLarge,
+    CSIIntermediateOverflow,
+
+    nF(Vec<u8>),
+    nFSequenceTooLarge,
+    nFInvalidSequence,
+    Designate {
+        slot: CharsetSlot,
+        charset: Charset,
+        bytes: Vec<u8>,
+    },
+
+    C0(C0),
+    C1(C1),
+    Fp(Fp),
+    Fs(Fs),
+
+    SP,
+    DEL,
+    ESC,
+
+    InvalidEscapeByte(u8),
+    Alt(u8),
+
+    InvalidUtf8Sequence,
+
+    None,
+}
+
+impl From<Out<'_>> for OwnedOut {
+    #[allow(clippy::useless_conversion)]
+    fn from(out: Out<'_>) -> Self {
+        fn bytes(slice: crate::FfiSlice<'_, u8>) -> Vec<u8> {
+            let slice: &[u8] = slice.into();
+            slice.to_vec()
+        }
+        match out {
+            Out::Data(c) => Self::Data(c),
+            Out::Text(text) => {
+                let text: &str = text.into();
+                Self::Text(text.into())
+            }
+            Out::DCSData(c) => Self::DCSData(c),
+            Out::SData(c) => Self::SData(c),
+            Out::PMData(c) => Self::PMData(c),
+            Out::APCData(c) => Self::APCData(c),
+            Out::OSData(c) => Self::OSData(c),
+            Out::DCS {
+                payload,
+                terminator,
+            } => Self::DCS {
+                payload: bytes(payload),
+                terminator,
+            },
+            Out::SOS {
+                payload,
+                terminator,
+            } => Self::SOS {
+                payload: bytes(payload),
+                terminator,
+            },
+            Out::PM {
+                payload,
+                terminator,
+            } => Self::PM {
+                payload: bytes(payload),
+                terminator,
+            },
+            Out::APC {
+                payload,
+                terminator,
+            } => Self::APC {
+                payload: bytes(payload),
+                terminator,
+            },
+            Out::OSC {
+                payload,
+                terminator,
+            } => Self::OSC {
+                payload: bytes(payload),
+                terminator,
+            },
+            Out::StringSequenceTooLarge => Self::StringSequenceTooLarge,
+            Out::StringEnd { kind, terminator } => Self::StringEnd { kind, terminator },
+            Out::StringAborted { kind, cause } => Self::StringAborted { kind, cause },
+            Out::CSI(csi) => Self::CSI(csi.bytes().to_vec()),
+            Out::CSITruncated { csi, dropped } => Self::CSITruncated {
+                csi: csi.bytes().to_vec(),
+                dropped,
+            },
+            Out::CSISequenceTooLarge => Self::CSISequenceTooLarge,
+            Out::CSIIntermediateOverflow => Self::CSIIntermediateOverflow,
+            Out::nF(nf) => Self::nF(bytes(nf)),
+            Out::nFSequenceTooLarge => Self::nFSequenceTooLarge,
+            Out::nFInvalidSequence => Self::nFInvalidSequence,
+            Out::Designate {
+                slot,
+                charset,
+                bytes: designation,
+            } => Self::Designate {
+                slot,
+                charset,
+                bytes: bytes(designation),
+            },
+            Out::C0(c0) => Self::C0(c0),
+            Out::C1(c1) => Self::C1(c1),
+            Out::Fp(fp) => Self::Fp(fp),
+            Out::Fs(fs) => Self::Fs(fs),
+            Out::SP => Self::SP,
+            Out::DEL => Self::DEL,
+            Out::ESC => Self::ESC,
+            Out::InvalidEscapeByte(byte) => Self::InvalidEscapeByte(byte),
+            Out::Alt(byte) => Self::Alt(byte),
+            Out::InvalidUtf8Sequence => Self::InvalidUtf8Sequence,
+            Out::None => Self::None,
+        }
+    }
+}
+
+impl OwnedOut {
+    /// Borrows the event as an [`Out`], to parse a CSI or re-encode it
+    #[allow(clippy::useless_conversion)]
+    pub fn as_out(&self) -> Out<'_> {
+        match self {
+            Self::Data(c) => Out::Data(*c),
+            Self::Text(text) => Out::Text(text.as_str().into()),
+            Self::DCSData(c) => Out::DCSData(*c),
+            Self::SData(c) => Out::SData(*c),
+            Self::PMData(c) => Out::PMData(*c),
+            Self::APCData(c) => Out::APCData(*c),
+            Self::OSData(c) => Out::OSData(*c),
+            Self::DCS {
+                payload,
+                terminator,
+            } => Out::DCS {
+                payload: payload[..].into(),
+                terminator: *terminator,
+            },
+            Self::SOS {
+                payload,
+                terminator,
+            } => Out::SOS {
+                payload: payload[..].into(),
+                terminator: *terminator,
+            },
+            Self::PM {
+                payload,
+                terminator,
+            } => Out::PM {
+                payload: payload[..].into(),
+                terminator: *terminator,
+            },
+            Self::APC {
+                payload,
+                terminator,
+            } => Out::APC {
+                payload: payload[..].into(),
+                terminator: *terminator,
+            },
+            Self::OSC {
+                payload,
+                terminator,
+            } => Out::OSC {
+                payload: payload[..].into(),
+                terminator: *terminator,
+            },
+            Self::StringSequenceTooLarge => Out::StringSequenceTooLarge,
+            Self::StringEnd { kind, terminator } => Out::StringEnd {
+                kind: *kind,
+                terminator: *terminator,
+            },
+            Self::StringAborted { kind, cause } => Out::StringAborted {
+                kind: *kind,
+                cause: *cause,
+            },
+            Self::CSI(csi) => Out::CSI(crate::CSI::new(&csi[..])),
+            Self::CSITruncated { csi, dropped } => Out::CSITruncated {
+                csi: crate::CSI::new(&csi[..]),
+                dropped: *dropped,
+            },
+            Self::CSISequenceTooLarge => Out::CSISequenceTooLarge,
+            Self::CSIIntermediateOverflow => Out::CSIIntermediateOverflow,
+            Self::nF(nf) => Out::nF(nf[..].into()),
+            Self::nFSequenceTooLarge => Out::nFSequenceTooLarge,
+            Self::nFInvalidSequence => Out::nFInvalidSequence,
+            Self::Designate {
+                slot,
+                charset,
+                bytes,
+            } => Out::Designate {
+                slot: *slot,
+                charset: *charset,
+                bytes: bytes[..].into(),
+            },
+            Self::C0(c0) => Out::C0(*c0),
+            Self::C1(c1) => Out::C1(*c1),
+            Self::Fp(fp) => Out::Fp(*fp),
+            Self::Fs(fs) => Out::Fs(*fs),
+            Self::SP => Out::SP,
+            Self::DEL => Out::DEL,
+            Self::ESC => Out::ESC,
+            Self::InvalidEscapeByte(byte) => Out::InvalidEscapeByte(*byte),
+            Self::Alt(byte) => Out::Alt(*byte),
+            Self::InvalidUtf8Sequence => Out::InvalidUtf8Sequence,
+            Self::None => Out::None,
+        }
+    }
+}
+
+impl ByteSink for Vec<u8> {
+    type Error = core::convert::Infallible;
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
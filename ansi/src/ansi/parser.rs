@@ -1,3 +1,4 @@
+use super::table::{Action, transition};
 use crate::ansi::*;
 
 #[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
@@ -14,17 +15,62 @@ pub enum CsiMod {
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 #[repr(u8)]
-enum StringKind {
+pub enum StringKind {
+    /// Device Control String [DCS]
     DeviceControl,
+    /// Start of String [SOS]
     Regular,
+    /// Privacy Message [PM]
     Privacy,
+    /// Application Program Command [APC]
     ApplicationProgramCommand,
+    /// Operating System Command [OSC]
     Os,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum StringTerminator {
+    /// String Terminator, either `ESC \` or 0x9C
+    ST,
+    /// Bell, only terminates operating system commands
+    BEL,
+}
+
+/// What cut a control string off, see [`Out::StringAborted`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum StringAbort {
+    /// Cancel, consumed by the abort
+    CAN,
+    /// Substitute, consumed by the abort
+    SUB,
+    /// The start of another sequence or a byte which is acted on by itself,
+    /// it is reported by the events which follow the abort
+    Interrupted,
+}
+
+/// What to do with a control sequence which does not fit into the byte buffer
+///
+/// Parameters are only dropped whole, but that can still change what a sequence means:
+/// keeping the last parameters of `38;2;255;255;255m` leaves `255;255m`, which is no
+/// longer a color. Sequences where not a single parameter fits are always rejected, as
+/// an empty parameter list means the defaults, `CSI m` is a reset for instance.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum CsiOverflow {
+    /// Discard the sequence and emit [`Out::CSISequenceTooLarge`]
+    Reject,
+    /// Keep as many leading parameters as fit and emit [`Out::CSITruncated`]
+    #[default]
+    KeepFirst,
+    /// Keep as many trailing parameters as fit and emit [`Out::CSITruncated`]
+    KeepLast,
+}
+
 #[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
 #[repr(u8)]
-enum IgnoreKind {
+pub(super) enum IgnoreKind {
     #[default]
     InvalidByteEncountered,
     SequenceOverflow,
@@ -32,9 +78,23 @@ enum IgnoreKind {
     InvalidSequence,
 }
 
+/// How far [`AnsiParser::advance`] got through the header of a streamed device control string
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub(super) enum DcsHeader {
+    #[default]
+    Idle,
+    /// The header bytes seen so far are in the byte buffer
+    Collecting,
+    /// [`Perform::hook`] was called, the rest of the string goes to [`Perform::put`]
+    Hooked,
+    /// The header was malformed, the rest of the string goes to [`Perform::other`]
+    Invalid,
+}
+
 #[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
 #[repr(u8)]
-enum State {
+pub(super) enum State {
     #[default]
     Ground = 0,
     Escape,
@@ -43,7 +103,10 @@ enum State {
     CsiI,
     CsiIgnore(IgnoreKind),
 
-    String(StringKind),
+    /// The flag is set once a buffered string has overflowed
+    String(StringKind, bool),
+    /// An ESC was encountered inside of a string
+    StringEscape(StringKind, bool),
 
     Nf(bool),
 }
@@ -59,16 +122,35 @@ pub struct Config {
     pub del_special: bool,
     pub space_special: bool,
 
-    pub csi_silent_sequence_overflow: bool,
+    pub csi_overflow: CsiOverflow,
     pub csi_silent_intermediate_overflow: bool,
+    /// Execute C0 controls found inside of escape, nF and control sequences
+    /// and keep collecting the sequence instead of discarding it
     pub csi_pass_through_c0: bool,
 
     pub nf_silent_sequence_overflow: bool,
+    /// Report nF sequences which designate a character set as [`Out::Designate`]
+    /// instead of [`Out::nF`], required by [`Charsets`]
+    pub designations: bool,
 
     pub utf8: bool,
+    /// Emit U+FFFD in place of each maximal ill-formed UTF-8 subpart instead of [`Out::InvalidUtf8Sequence`]
+    pub utf8_replacement: bool,
+    /// Decodes data bytes outside of UTF-8 in the ground state and in strings
+    pub encoding: Encoding,
+    /// When feeding slices, emit runs of printable UTF-8 in the ground state as a single [`Out::Text`].
+    ///
+    /// Spaces end a run while [`Self::space_special`] is set, which is the default,
+    /// so disable it as well to get whole lines of text.
+    pub text_runs: bool,
 
     pub string_pass_through_c0: bool,
     pub utf8_strings: bool,
+    /// Collect control string bodies in the byte buffer and emit them as a
+    /// single event such as [`Out::OSC`] once the string is terminated
+    pub buffer_strings: bool,
+    /// Decode the parameters of control sequences while collecting them, see [`crate::CSI::params`]
+    pub csi_params: bool,
 
     pub max_immediate_count: usize,
 }
@@ -86,14 +168,20 @@ impl Config {
             del_special: true,
             space_special: true,
 
-            csi_silent_sequence_overflow: true,
+            csi_overflow: CsiOverflow::KeepFirst,
             csi_silent_intermediate_overflow: true,
             csi_pass_through_c0: true,
             string_pass_through_c0: true,
 
             nf_silent_sequence_overflow: true,
+            designations: false,
             utf8: true,
+            utf8_replacement: false,
+            encoding: Encoding::Latin1,
+            text_runs: false,
             utf8_strings: true,
+            buffer_strings: false,
+            csi_params: false,
 
             max_immediate_count: 4,
         }
@@ -103,13 +191,25 @@ impl Config {
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "crepr", repr(C))]
 pub struct ParserState {
-    immediate_count: usize,
+    pub(super) immediate_count: usize,
+    /// Bytes of the current control sequence were dropped
+    pub(super) csi_truncated: bool,
+    /// Parameter bytes are dropped until the next parameter, see [`CsiOverflow`]
+    pub(super) csi_skip: bool,
+    /// Parameters of the current control sequence which were dropped
+    pub(super) csi_dropped: usize,
 
-    state: State,
-    utf8_state: u8,
-    codepoint: u32,
+    pub(super) state: State,
+    pub(super) utf8_state: u8,
+    /// The range the next continuation byte has to be in
+    pub(super) utf8_lower: u8,
+    pub(super) utf8_upper: u8,
+    pub(super) codepoint: u32,
+    /// The byte after a 7-bit `ESC` which was the last byte seen, see [`AnsiParser::flush_pending`]
+    pub(super) escape_prefix: u8,
+    pub(super) dcs_header: DcsHeader,
 
-    buffer_count: usize,
+    pub(super) buffer_count: usize,
 }
 
 impl Default for ParserState {
@@ -122,10 +222,17 @@ impl ParserState {
     pub const fn new() -> Self {
         Self {
             immediate_count: 0,
+            csi_truncated: false,
+            csi_skip: false,
+            csi_dropped: 0,
 
             state: State::Ground,
             utf8_state: 0,
+            utf8_lower: 0x80,
+            utf8_upper: 0xBF,
             codepoint: 0,
+            escape_prefix: 0,
+            dcs_header: DcsHeader::Idle,
 
             buffer_count: 0,
         }
@@ -136,8 +243,10 @@ impl ParserState {
 #[cfg_attr(feature = "crepr", repr(C))]
 pub struct AnsiParser<T: ?Sized> {
     pub cfg: Config,
-    state: ParserState,
-    buffer: T,
+    pub(super) state: ParserState,
+    /// Parameters of the control sequence being collected, see [`Config::csi_params`]
+    pub(super) params: crate::CsiParams,
+    pub(super) buffer: T,
 }
 
 impl<const BYTE_BUF_SIZE: usize> core::default::Default for SizedAnsiParser<BYTE_BUF_SIZE> {
@@ -148,11 +257,7 @@ impl<const BYTE_BUF_SIZE: usize> core::default::Default for SizedAnsiParser<BYTE
 
 impl<const BYTE_BUF_SIZE: usize> SizedAnsiParser<BYTE_BUF_SIZE> {
     pub const fn new() -> Self {
-        Self {
-            cfg: Config::new(),
-            state: ParserState::new(),
-            buffer: [0; BYTE_BUF_SIZE],
-        }
+        Self::with_buffer([0; BYTE_BUF_SIZE])
     }
 
     #[inline(always)]
@@ -166,21 +271,268 @@ impl<const BYTE_BUF_SIZE: usize> SizedAnsiParser<BYTE_BUF_SIZE> {
         let tc: &mut UnsizedAnsiParser = self;
         tc.next(input)
     }
+
+    #[inline(always)]
+    pub fn feed<'a>(&'a mut self, input: &'a [u8]) -> Feed<'a> {
+        let tc: &mut UnsizedAnsiParser = self;
+        tc.feed(input)
+    }
+
+    #[inline(always)]
+    pub fn flush_pending(&mut self) -> Out<'static> {
+        let tc: &mut UnsizedAnsiParser = self;
+        tc.flush_pending()
+    }
+}
+
+/// The result of feeding a chunk of input into the parser
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub struct Feed<'a> {
+    /// Number of bytes taken from the front of the input
+    pub consumed: usize,
+    /// The first event produced, [`Out::None`] if the input ran out first
+    pub out: Out<'a>,
+}
+
+/// A single step of the state machine, events which borrow the byte
+/// buffer are only materialized once the step is done mutating the parser.
+pub(super) enum Step {
+    Out(Out<'static>),
+    Csi,
+    Nf,
+    String(StringKind, StringTerminator),
+    /// The event ends a sequence without consuming the byte, which has to be
+    /// stepped again from the new state
+    Reprocess(Out<'static>),
 }
 
 enum Utf8Result {
     Produce(char),
     Consume,
     Pass,
+    /// The byte can never appear in well-formed UTF-8
+    InvalidByte,
+    /// The byte broke off a sequence and has to be processed on its own
     InvalidSequence,
-    InvalidCodepoint(u32),
 }
 
-impl UnsizedAnsiParser {
+/// Storage for the bytes of the sequence being collected
+pub trait ParserBuffer {
+    fn bytes(&self) -> &[u8];
+
+    fn bytes_mut(&mut self) -> &mut [u8];
+
+    /// Makes at least `len` bytes available, false if the buffer can not hold that many
+    fn reserve(&mut self, len: usize) -> bool {
+        len <= self.bytes().len()
+    }
+}
+
+impl ParserBuffer for [u8] {
+    #[inline(always)]
+    fn bytes(&self) -> &[u8] {
+        self
+    }
+
+    #[inline(always)]
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+impl<T> AnsiParser<T> {
+    pub(super) const fn with_buffer(buffer: T) -> Self {
+        Self {
+            cfg: Config::new(),
+            state: ParserState::new(),
+            params: crate::CsiParams::new(),
+            buffer,
+        }
+    }
+}
+
+impl<T: ParserBuffer + ?Sized> AnsiParser<T> {
     #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
     pub fn reset(&mut self) {
         self.state.state = State::Ground;
         self.state.buffer_count = 0;
+        self.state.utf8_state = 0;
+        self.state.escape_prefix = 0;
+        self.state.dcs_header = DcsHeader::Idle;
+    }
+
+    /// Feeds a single byte into the parser.
+    ///
+    /// Only one event can be returned per byte, when a byte both ends a sequence early
+    /// and produces an event of its own the latter is returned. [`Self::feed`] reports both.
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn next(&mut self, input: u8) -> Out<'_> {
+        let step = match self.step(input) {
+            Step::Reprocess(out) => match self.step(input) {
+                Step::Out(Out::None) => Step::Out(out),
+                step => step,
+            },
+            step => step,
+        };
+        self.resolve(step)
+    }
+
+    /// Feeds bytes from `input` until an event is produced or the input is exhausted.
+    ///
+    /// Returns the event along with how many bytes were consumed, the remaining
+    /// bytes should be fed again to get the following events.
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn feed<'a>(&'a mut self, input: &'a [u8]) -> Feed<'a> {
+        if let Some(text) = self.text_run(input) {
+            return Feed {
+                consumed: text.len(),
+                #[allow(clippy::useless_conversion)]
+                out: Out::Text(text.into()),
+            };
+        }
+        for (i, byte) in input.iter().copied().enumerate() {
+            match self.step(byte) {
+                Step::Out(Out::None) => {}
+                Step::Reprocess(out) => return Feed { consumed: i, out },
+                step => {
+                    return Feed {
+                        consumed: i.wrapping_add(1),
+                        out: self.resolve(step),
+                    };
+                }
+            }
+        }
+        Feed {
+            consumed: input.len(),
+            out: Out::None,
+        }
+    }
+
+    /// Whether the next byte starts a new event rather than continuing a sequence
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub(super) fn at_boundary(&self) -> bool {
+        self.state.utf8_state == 0
+            && match self.state.state {
+                State::Ground => true,
+                State::String(..) => !self.cfg.buffer_strings,
+                _ => false,
+            }
+    }
+
+    /// Resolves input which is waiting on bytes that may never come, call this
+    /// once interactive input has been idle for a while.
+    ///
+    /// A lone `ESC` becomes [`Out::ESC`] and `ESC [` becomes [`Out::Alt`], both return the
+    /// parser to the ground state. `ESC O` is reported as SS3 right away, when nothing
+    /// followed it this returns `Out::Alt(b'O')`, which replaces that SS3 rather than
+    /// following it: drop the SS3 when it arrives, and it encodes to nothing since the SS3
+    /// already wrote `ESC O`.
+    /// An unfinished UTF-8 sequence is reported as an error, longer sequences and control
+    /// strings are left pending.
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn flush_pending(&mut self) -> Out<'static> {
+        let prefix = core::mem::take(&mut self.state.escape_prefix);
+        if self.state.utf8_state != 0 {
+            self.state.utf8_state = 0;
+            return self.utf8_error();
+        }
+        match self.state.state {
+            State::Escape => {
+                self.state.state = State::Ground;
+                Out::ESC
+            }
+            State::CsiP if prefix == b'[' => {
+                self.state.state = State::Ground;
+                Out::Alt(prefix)
+            }
+            State::Ground if prefix == b'O' => Out::Alt(prefix),
+            _ => Out::None,
+        }
+    }
+
+    /// The longest valid UTF-8 prefix of `input` which contains only printable characters
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub(super) fn text_run<'a>(&mut self, input: &'a [u8]) -> Option<&'a str> {
+        if !self.cfg.text_runs
+            || !self.cfg.utf8
+            || self.state.state != State::Ground
+            || self.state.utf8_state != 0
+        {
+            return None;
+        }
+        let end = input
+            .iter()
+            .position(|b| match b {
+                0x00..=0x1F => true,
+                b' ' => self.cfg.space_special,
+                0x7F => self.cfg.del_special,
+                _ => false,
+            })
+            .unwrap_or(input.len());
+        let run = input.get(..end)?;
+        let text = match core::str::from_utf8(run) {
+            Ok(text) => text,
+            Err(err) => core::str::from_utf8(run.get(..err.valid_up_to())?).ok()?,
+        };
+        if text.is_empty() {
+            return None;
+        }
+        self.state.escape_prefix = 0;
+        Some(text)
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    #[allow(clippy::useless_conversion)]
+    pub(super) fn resolve(&self, step: Step) -> Out<'_> {
+        match step {
+            Step::Out(out) | Step::Reprocess(out) => out,
+            Step::Csi if self.state.csi_truncated => Out::CSITruncated {
+                csi: self.current_csi(),
+                dropped: self.state.csi_dropped,
+            },
+            Step::Csi => Out::CSI(self.current_csi()),
+            Step::Nf if !self.cfg.designations => Out::nF(self.current_byte_buffer().into()),
+            Step::Nf => match Charset::from_designation(self.current_byte_buffer()) {
+                Some((slot, charset)) => Out::Designate {
+                    slot,
+                    charset,
+                    bytes: self.current_byte_buffer().into(),
+                },
+                None => Out::nF(self.current_byte_buffer().into()),
+            },
+            Step::String(kind, terminator) => {
+                let payload = self.current_byte_buffer().into();
+                match kind {
+                    StringKind::DeviceControl => Out::DCS {
+                        payload,
+                        terminator,
+                    },
+                    StringKind::Regular => Out::SOS {
+                        payload,
+                        terminator,
+                    },
+                    StringKind::Privacy => Out::PM {
+                        payload,
+                        terminator,
+                    },
+                    StringKind::ApplicationProgramCommand => Out::APC {
+                        payload,
+                        terminator,
+                    },
+                    StringKind::Os => Out::OSC {
+                        payload,
+                        terminator,
+                    },
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn current_csi(&self) -> crate::CSI<'_> {
+        let params = self.cfg.csi_params.then_some(&self.params);
+        crate::CSI::with_params(self.current_byte_buffer(), params)
     }
 
     #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
@@ -189,17 +541,16 @@ impl UnsizedAnsiParser {
     }
 
     #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
-    fn current_byte_buffer(&self) -> &[u8] {
-        if self.state.buffer_count > self.buffer.len() {
-            &self.buffer[..]
-        } else {
-            &self.buffer[..self.state.buffer_count]
-        }
+    pub(super) fn current_byte_buffer(&self) -> &[u8] {
+        let buffer = self.buffer.bytes();
+        buffer.get(..self.state.buffer_count).unwrap_or(buffer)
     }
 
     #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
-    fn insert_into_byte_buffer(&mut self, input: u8) -> bool {
-        if let Some(e) = self.buffer.get_mut(self.state.buffer_count) {
+    pub(super) fn insert_into_byte_buffer(&mut self, input: u8) -> bool {
+        self.buffer
+            .reserve(self.state.buffer_count.saturating_add(1));
+        if let Some(e) = self.buffer.bytes_mut().get_mut(self.state.buffer_count) {
             *e = input;
             if let Some(r) = self.state.buffer_count.checked_add(1) {
                 self.state.buffer_count = r;
@@ -213,112 +564,196 @@ impl UnsizedAnsiParser {
     }
 
     #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
-    fn parse_safe_c0(&mut self, input: u8) -> Out<'_> {
-        Out::C0(match input {
-            0 => C0::NUL,
-            1 => C0::SOH,
-            2 => C0::STX,
-            3 => C0::ETX,
-            4 => C0::EOT,
-            5 => C0::ENQ,
-            6 => C0::ACK,
-            7 => C0::BEL,
-            8 => C0::BS,
-            9 => C0::HT,
-            10 => C0::LF,
-            11 => C0::VT,
-            12 => C0::FF,
-            13 => C0::CR,
-            14 => C0::SO,
-            15 => C0::SI,
-            16 => C0::DLE,
-            17 => C0::DC1,
-            18 => C0::DC2,
-            19 => C0::DC3,
-            20 => C0::DC4,
-            21 => C0::NAK,
-            22 => C0::SI,
-            23 => C0::ETB,
-            // 24 => C0::CAN,
-            25 => C0::EM,
-            // 26 => C0::SUB,
-            // 27 => ESC
-            28 => C0::FS,
-            29 => C0::GS,
-            30 => C0::RS,
-            31 => C0::US,
-            _ => return Out::None,
-        })
+    fn parse_safe_c0(&self, input: u8) -> Out<'static> {
+        match input {
+            // CAN, SUB and ESC are never passed through
+            24 | 26 | 27 => Out::None,
+            _ => C0::try_from(input).map_or(Out::None, Out::C0),
+        }
     }
 
     #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
     fn next_utf8(&mut self, input: u8) -> Utf8Result {
         match self.state.state {
             State::Ground if self.cfg.utf8 => {}
-            State::String(_) if self.cfg.utf8_strings => {}
+            State::String(..) if self.cfg.utf8_strings && !self.cfg.buffer_strings => {}
             _ => return Utf8Result::Pass,
         }
         if self.state.utf8_state != 0 {
-            if input & 0b11000000 == 0b10000000 {
-                self.state.codepoint = self.state.codepoint.wrapping_shl(6);
-                self.state.codepoint |= input as u32 & !0b11000000;
-                self.state.utf8_state -= 1;
-
-                if self.state.utf8_state == 0 {
-                    if let Some(char) = char::from_u32(self.state.codepoint) {
-                        return Utf8Result::Produce(char);
-                    } else {
-                        return Utf8Result::InvalidCodepoint(self.state.codepoint);
-                    }
-                } else {
-                    return Utf8Result::Consume;
-                }
-            } else {
+            if !(self.state.utf8_lower..=self.state.utf8_upper).contains(&input) {
                 self.state.utf8_state = 0;
                 return Utf8Result::InvalidSequence;
             }
-        } else {
-            for i in 2..=6 {
-                let mask = 0xFF >> (i + 1);
-                let eq = (!mask) << 1;
-                if input & !mask == eq {
-                    self.state.codepoint = (input & mask) as u32;
-                    self.state.utf8_state = i - 1;
-                    return Utf8Result::Consume;
-                }
+            self.state.codepoint = self.state.codepoint.wrapping_shl(6);
+            self.state.codepoint |= input as u32 & 0b00111111;
+            self.state.utf8_state -= 1;
+            self.state.utf8_lower = 0x80;
+            self.state.utf8_upper = 0xBF;
+
+            if self.state.utf8_state != 0 {
+                return Utf8Result::Consume;
             }
+            // the ranges above only admit scalar values
+            return char::from_u32(self.state.codepoint)
+                .map_or(Utf8Result::InvalidByte, Utf8Result::Produce);
+        }
+
+        // well-formed byte sequences, see table 3-7 of the Unicode standard
+        let (len, lower, upper) = match input {
+            0x00..=0x7F => return Utf8Result::Pass,
+            0x80..=0x9F if self.cfg.bit8_enabled => return Utf8Result::Pass,
+            0xC2..=0xDF => (1, 0x80, 0xBF),
+            0xE0 => (2, 0xA0, 0xBF),
+            0xED => (2, 0x80, 0x9F),
+            0xE1..=0xEF => (2, 0x80, 0xBF),
+            0xF0 => (3, 0x90, 0xBF),
+            0xF4 => (3, 0x80, 0x8F),
+            0xF1..=0xF3 => (3, 0x80, 0xBF),
+            _ => return Utf8Result::InvalidByte,
+        };
+        self.state.codepoint = (input & (0x7F >> len)) as u32;
+        self.state.utf8_state = len;
+        self.state.utf8_lower = lower;
+        self.state.utf8_upper = upper;
+        Utf8Result::Consume
+    }
+
+    /// The event for a character in the current state
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn char_out(&self, c: impl Into<crate::FfiChar>) -> Out<'static> {
+        let c = c.into();
+        match self.state.state {
+            State::String(kind, _) => match kind {
+                StringKind::DeviceControl => Out::DCSData(c),
+                StringKind::Regular => Out::SData(c),
+                StringKind::Privacy => Out::PMData(c),
+                StringKind::ApplicationProgramCommand => Out::APCData(c),
+                StringKind::Os => Out::OSData(c),
+            },
+            _ => Out::Data(c),
+        }
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn utf8_error(&self) -> Out<'static> {
+        if self.cfg.utf8_replacement {
+            self.char_out(char::REPLACEMENT_CHARACTER)
+        } else {
+            Out::InvalidUtf8Sequence
         }
-        Utf8Result::Pass
     }
 
     #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
     fn push_p(&mut self, input: u8) {
-        if !self.insert_into_byte_buffer(input) && !self.cfg.csi_silent_sequence_overflow {
-            self.state.state = State::CsiIgnore(IgnoreKind::SequenceOverflow);
+        if self.state.csi_skip {
+            match (input, self.cfg.csi_overflow) {
+                (b';', CsiOverflow::KeepLast) => self.state.csi_skip = false,
+                (b';', _) => self.drop_param(),
+                _ => {}
+            }
+            return;
+        }
+        if self.insert_into_byte_buffer(input) {
+            if self.cfg.csi_params {
+                self.params.push(input);
+            }
+            return;
+        }
+        self.state.csi_truncated = true;
+        match self.cfg.csi_overflow {
+            CsiOverflow::Reject => {
+                self.state.state = State::CsiIgnore(IgnoreKind::SequenceOverflow);
+            }
+            // the parameters collected so far are complete
+            CsiOverflow::KeepFirst if input == b';' => {
+                self.state.csi_skip = true;
+                self.drop_param();
+            }
+            CsiOverflow::KeepFirst => {
+                self.trim_params(self.state.buffer_count);
+                self.state.csi_skip = true;
+            }
+            CsiOverflow::KeepLast => {
+                // a single parameter longer than the buffer is dropped entirely
+                if !self.trim_params(self.state.buffer_count) {
+                    self.state.csi_skip = input != b';';
+                } else {
+                    self.insert_into_byte_buffer(input);
+                }
+            }
         }
     }
 
     #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
-    fn shift_csi(&mut self, input: u8) {
-        let mut position = self
-            .buffer
-            .len()
-            .saturating_sub(self.state.immediate_count)
-            .saturating_sub(1);
-        if position == 0 && matches!(self.buffer.first(), Some(b'?' | b'<' | b'>' | b'=')) {
-            position += 1;
-        }
-        if !matches!(self.buffer.get(position), Some(0x20..=0x2F)) {
-            while let (Some(v), Some(p)) = (
-                self.buffer.get(position.wrapping_add(1)).copied(),
-                self.buffer.get_mut(position),
-            ) {
+    fn drop_param(&mut self) {
+        self.state.csi_dropped = self.state.csi_dropped.saturating_add(1);
+    }
+
+    /// Removes the bytes in `start..end` from the byte buffer
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn remove_bytes(&mut self, start: usize, end: usize) {
+        let count = self.state.buffer_count.min(self.buffer.bytes().len());
+        if start >= end || end > count {
+            return;
+        }
+        let (mut to, mut from) = (start, end);
+        while from < count {
+            let buffer = self.buffer.bytes_mut();
+            if let (Some(v), Some(p)) = (buffer.get(from).copied(), buffer.get_mut(to)) {
                 *p = v;
-                position += 1;
+            }
+            to += 1;
+            from += 1;
+        }
+        self.state.buffer_count = to;
+    }
+
+    /// Drops one whole parameter from the parameters ending at `end`, the last one
+    /// for [`CsiOverflow::KeepFirst`] and the first one for [`CsiOverflow::KeepLast`].
+    /// Returns false if there was only a single parameter, which is dropped as well.
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn trim_params(&mut self, end: usize) -> bool {
+        let start = usize::from(matches!(
+            self.buffer.bytes().first(),
+            Some(b'?' | b'<' | b'>' | b'=')
+        ));
+        self.drop_param();
+        let params = self.buffer.bytes().get(start..end).unwrap_or(&[]);
+        let split = if self.cfg.csi_overflow == CsiOverflow::KeepLast {
+            params
+                .iter()
+                .position(|b| *b == b';')
+                .map(|i| (start, start.saturating_add(i).saturating_add(1)))
+        } else {
+            params
+                .iter()
+                .rposition(|b| *b == b';')
+                .map(|i| (start.saturating_add(i), end))
+        };
+        match split {
+            Some((from, to)) => {
+                self.remove_bytes(from, to);
+                true
+            }
+            None => {
+                self.remove_bytes(start, end);
+                false
             }
         }
+    }
 
-        if let Some(last) = self.buffer.last_mut() {
+    /// Makes room for an intermediate or final byte in a full buffer by dropping a parameter
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn shift_csi(&mut self, input: u8) {
+        self.state.csi_truncated = true;
+        let end = self
+            .state
+            .buffer_count
+            .saturating_sub(self.state.immediate_count);
+        self.trim_params(end);
+        if !self.insert_into_byte_buffer(input)
+            && let Some(last) = self.buffer.bytes_mut().last_mut()
+        {
             *last = input
         }
     }
@@ -329,10 +764,11 @@ impl UnsizedAnsiParser {
             if !self.cfg.csi_silent_intermediate_overflow {
                 self.state.state = State::CsiIgnore(IgnoreKind::ImmediateOverflow);
             }
+            self.state.csi_truncated = true;
             return;
         }
         if !self.insert_into_byte_buffer(input) {
-            if !self.cfg.csi_silent_sequence_overflow {
+            if self.cfg.csi_overflow == CsiOverflow::Reject {
                 self.state.state = State::CsiIgnore(IgnoreKind::SequenceOverflow);
                 return;
             }
@@ -342,90 +778,414 @@ impl UnsizedAnsiParser {
     }
 
     #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
-    fn push_f(&mut self, input: u8) -> Out<'_> {
+    fn push_f(&mut self, input: u8) -> Step {
+        // dropping every parameter would turn the sequence into one using the defaults
+        if self.state.csi_dropped != 0 && self.csi_params_empty() {
+            return Step::Out(Out::CSISequenceTooLarge);
+        }
         if !self.insert_into_byte_buffer(input) {
-            if !self.cfg.csi_silent_sequence_overflow {
-                return Out::CSISequenceTooLarge;
+            if self.cfg.csi_overflow == CsiOverflow::Reject {
+                return Step::Out(Out::CSISequenceTooLarge);
             }
             self.shift_csi(input);
         }
+        // bytes were dropped or collected without decoding them
+        let len = self
+            .state
+            .buffer_count
+            .saturating_sub(self.state.immediate_count)
+            .saturating_sub(1);
+        if self.cfg.csi_params && (self.state.csi_truncated || self.params.decoded() != len) {
+            let bytes = self.buffer.bytes();
+            self.params.decode(bytes.get(..len).unwrap_or(bytes));
+        }
+        Step::Csi
+    }
+
+    /// No parameter bytes besides a private marker are left in the collected sequence
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn csi_params_empty(&self) -> bool {
+        let bytes = self.current_byte_buffer();
+        let params = bytes
+            .get(..bytes.len().saturating_sub(self.state.immediate_count))
+            .unwrap_or(&[]);
+        matches!(params, [] | [b'?' | b'<' | b'>' | b'='])
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn start_string(&mut self, kind: StringKind) {
+        self.state.state = State::String(kind, false);
+        self.reset_byte_buffer();
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn end_string(
+        &mut self,
+        kind: StringKind,
+        terminator: StringTerminator,
+        overflow: bool,
+    ) -> Step {
+        self.state.state = State::Ground;
+        if !self.cfg.buffer_strings {
+            Step::Out(Out::StringEnd { kind, terminator })
+        } else if overflow {
+            Step::Out(Out::StringSequenceTooLarge)
+        } else {
+            Step::String(kind, terminator)
+        }
+    }
+
+    /// Leaves `ESC` and the escape state for the byte following it
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn escape_c1(&mut self, input: u8, bit8: bool) -> Step {
+        self.state.state = State::Ground;
+        if !bit8 && matches!(input, b'[' | b'O') {
+            self.state.escape_prefix = input;
+        }
+        match input {
+            b'P' => self.start_string(StringKind::DeviceControl),
+            b'X' => self.start_string(StringKind::Regular),
+            b'[' => {
+                self.state.state = State::CsiP;
+                self.state.immediate_count = 0;
+                self.state.csi_truncated = false;
+                self.state.csi_skip = false;
+                self.state.csi_dropped = 0;
+                self.params.clear();
+                self.reset_byte_buffer();
+                return Step::Out(Out::None);
+            }
+            b']' => self.start_string(StringKind::Os),
+            b'^' => self.start_string(StringKind::Privacy),
+            b'_' => self.start_string(StringKind::ApplicationProgramCommand),
+            _ => {}
+        }
+        Step::Out(C1::try_from(input).map_or(Out::InvalidEscapeByte(input), Out::C1))
+    }
+
+    /// Feeds a byte through the state machine, the actions most bytes take are
+    /// handled here and everything else by [`Self::perform`]
+    #[inline(always)]
+    pub(super) fn step(&mut self, input: u8) -> Step {
+        // ASCII outside of a multi byte sequence never needs the decoder
+        if (input >= 0x80 || self.state.utf8_state != 0) && (self.cfg.utf8 | self.cfg.utf8_strings)
+        {
+            return self.step_utf8(input);
+        }
+        self.state.escape_prefix = 0;
+        match self.transition(input) {
+            Action::Print if input < 0x80 => Step::Out(Out::Data(input as crate::FfiChar)),
+            Action::Ignore => Step::Out(Out::None),
+            Action::CsiParam
+                if !self.state.csi_skip
+                    && !self.cfg.csi_params
+                    && self.insert_into_byte_buffer(input) =>
+            {
+                Step::Out(Out::None)
+            }
+            action => self.perform(action, input),
+        }
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn step_utf8(&mut self, input: u8) -> Step {
+        match self.next_utf8(input) {
+            Utf8Result::Produce(char) => Step::Out(self.char_out(char)),
+            Utf8Result::Consume => Step::Out(Out::None),
+            Utf8Result::InvalidByte => Step::Out(self.utf8_error()),
+            Utf8Result::InvalidSequence => Step::Reprocess(self.utf8_error()),
+            Utf8Result::Pass => {
+                self.state.escape_prefix = 0;
+                self.perform(self.transition(input), input)
+            }
+        }
+    }
 
-        #[allow(clippy::useless_conversion)]
-        Out::CSI(crate::CSI(self.current_byte_buffer().into()))
+    #[inline(always)]
+    fn transition(&self, input: u8) -> Action {
+        transition(
+            self.state.state,
+            input,
+            self.cfg.bit8_enabled,
+            self.cfg.csi_pass_through_c0,
+        )
     }
 
     #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
-    pub fn next(&mut self, mut input: u8) -> Out<'_> {
+    fn perform(&mut self, action: Action, input: u8) -> Step {
+        Step::Out(match action {
+            Action::Print => Out::Data(self.cfg.encoding.decode(input)),
+            Action::Space if self.cfg.space_special => Out::SP,
+            Action::Del if self.cfg.del_special => Out::DEL,
+            Action::Space | Action::Del => Out::Data(input as crate::FfiChar),
+            Action::Execute | Action::PassC0 => C0::try_from(input).map_or(Out::None, Out::C0),
+            Action::Abort => {
+                self.state.state = State::Ground;
+                C0::try_from(input).map_or(Out::None, Out::C0)
+            }
+            Action::EnterEscape => {
+                self.state.state = State::Escape;
+                Out::None
+            }
+            Action::C1 => return self.escape_c1(input.wrapping_sub(0x40), true),
+
+            Action::EscIntermediate => {
+                self.reset_byte_buffer();
+                self.state.state = State::Nf(!self.insert_into_byte_buffer(input));
+                Out::None
+            }
+            Action::EscFp => {
+                self.state.state = State::Ground;
+                Fp::try_from(input).map_or(Out::InvalidEscapeByte(input), Out::Fp)
+            }
+            Action::EscC1 => return self.escape_c1(input, false),
+            Action::EscFs => {
+                self.state.state = State::Ground;
+                Fs::try_from(input).map_or(Out::InvalidEscapeByte(input), Out::Fs)
+            }
+            Action::EscInvalid => {
+                self.state.state = State::Ground;
+                Out::InvalidEscapeByte(input)
+            }
+
+            Action::NfCollect => {
+                self.state.state = State::Nf(!self.insert_into_byte_buffer(input));
+                Out::None
+            }
+            Action::NfFinal => {
+                let err = matches!(self.state.state, State::Nf(true));
+                self.state.state = State::Ground;
+                if (err || !self.insert_into_byte_buffer(input))
+                    && !self.cfg.nf_silent_sequence_overflow
+                {
+                    Out::nFSequenceTooLarge
+                } else {
+                    return Step::Nf;
+                }
+            }
+            Action::NfInvalid => {
+                self.state.state = State::Ground;
+                Out::nFInvalidSequence
+            }
+
+            Action::CsiParam => {
+                self.push_p(input);
+                Out::None
+            }
+            Action::CsiEnterIntermediate => {
+                self.state.immediate_count = 0;
+                self.state.state = State::CsiI;
+                self.push_i(input);
+                Out::None
+            }
+            Action::CsiIntermediate => {
+                self.push_i(input);
+                Out::None
+            }
+            Action::CsiInvalidSequence => {
+                self.state.state = State::CsiIgnore(IgnoreKind::InvalidSequence);
+                Out::None
+            }
+            Action::CsiFinal => {
+                self.state.state = State::Ground;
+                return self.push_f(input);
+            }
+            Action::CsiInvalid => {
+                self.state.state = State::CsiIgnore(IgnoreKind::InvalidByteEncountered);
+                Out::None
+            }
+            Action::IgnoreFinal => {
+                let State::CsiIgnore(kind) = self.state.state else {
+                    return Step::Out(Out::None);
+                };
+                self.state.state = State::Ground;
+                match kind {
+                    IgnoreKind::InvalidByteEncountered => Out::None,
+                    IgnoreKind::SequenceOverflow => Out::CSISequenceTooLarge,
+                    IgnoreKind::ImmediateOverflow => Out::CSIIntermediateOverflow,
+                    IgnoreKind::InvalidSequence => Out::None,
+                }
+            }
+            Action::Ignore => Out::None,
+
+            Action::StringPut => match self.state.state {
+                State::String(kind, overflow) if self.cfg.buffer_strings => {
+                    if !overflow && !self.insert_into_byte_buffer(input) {
+                        self.state.state = State::String(kind, true);
+                    }
+                    Out::None
+                }
+                _ => self.char_out(self.cfg.encoding.decode(input)),
+            },
+            Action::StringC0 if self.cfg.string_pass_through_c0 => self.parse_safe_c0(input),
+            Action::StringC0 => Out::None,
+            Action::StringBel => match self.state.state {
+                State::String(kind @ StringKind::Os, overflow) => {
+                    return self.end_string(kind, StringTerminator::BEL, overflow);
+                }
+                _ if self.cfg.string_pass_through_c0 => self.parse_safe_c0(input),
+                _ => Out::None,
+            },
+            Action::StringAbort => {
+                let kind = self.string_kind();
+                self.state.state = State::Ground;
+                let cause = match input {
+                    0x1A => StringAbort::SUB,
+                    _ => StringAbort::CAN,
+                };
+                Out::StringAborted { kind, cause }
+            }
+            Action::StringEscape => {
+                if let State::String(kind, overflow) = self.state.state {
+                    self.state.state = State::StringEscape(kind, overflow);
+                }
+                Out::None
+            }
+            Action::StringC1 => {
+                let kind = self.string_kind();
+                self.state.state = State::Ground;
+                return Step::Reprocess(Out::StringAborted {
+                    kind,
+                    cause: StringAbort::Interrupted,
+                });
+            }
+            Action::StringSt | Action::StringEscapeEnd => {
+                let (State::String(kind, overflow) | State::StringEscape(kind, overflow)) =
+                    self.state.state
+                else {
+                    return Step::Out(Out::None);
+                };
+                return self.end_string(kind, StringTerminator::ST, overflow);
+            }
+            Action::StringEscapeAbort => {
+                let kind = self.string_kind();
+                self.state.state = State::Escape;
+                return Step::Reprocess(Out::StringAborted {
+                    kind,
+                    cause: StringAbort::Interrupted,
+                });
+            }
+        })
+    }
+
+    /// The kind of the control string being parsed
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn string_kind(&self) -> StringKind {
+        match self.state.state {
+            State::String(kind, _) | State::StringEscape(kind, _) => kind,
+            _ => StringKind::Regular,
+        }
+    }
+}
+
+/// The state machine as it was written before it was driven by [`transition`],
+/// kept to check the table and the fast paths of [`UnsizedAnsiParser::step`] against.
+#[cfg(test)]
+impl UnsizedAnsiParser {
+    /// Every event `input` produces, which is two when the byte ends a sequence and is
+    /// then acted on by itself, stepping with [`Self::step_reference`] if `reference` is set
+    pub(super) fn step_events(&mut self, input: u8, reference: bool) -> alloc::vec::Vec<OwnedOut> {
+        let mut events = alloc::vec::Vec::new();
+        loop {
+            let step = if reference {
+                self.step_reference(input)
+            } else {
+                self.step(input)
+            };
+            match step {
+                Step::Reprocess(out) => events.push(out.into()),
+                step => {
+                    events.push(self.resolve(step).into());
+                    return events;
+                }
+            }
+        }
+    }
+
+    fn step_reference_string_end(&mut self, input: u8) -> Option<Step> {
+        match self.state.state {
+            State::String(kind, overflow) => match input {
+                0x07 if kind == StringKind::Os => {
+                    Some(self.end_string(kind, StringTerminator::BEL, overflow))
+                }
+                0x18 | 0x1A => {
+                    self.state.state = State::Ground;
+                    let cause = if input == 0x18 {
+                        StringAbort::CAN
+                    } else {
+                        StringAbort::SUB
+                    };
+                    Some(Step::Out(Out::StringAborted { kind, cause }))
+                }
+                0x1B => {
+                    self.state.state = State::StringEscape(kind, overflow);
+                    Some(Step::Out(Out::None))
+                }
+                0x9C if self.cfg.bit8_enabled => {
+                    Some(self.end_string(kind, StringTerminator::ST, overflow))
+                }
+                0x80..=0x9F if self.cfg.bit8_enabled => {
+                    self.state.state = State::Ground;
+                    Some(Step::Reprocess(Out::StringAborted {
+                        kind,
+                        cause: StringAbort::Interrupted,
+                    }))
+                }
+                _ => None,
+            },
+            State::StringEscape(kind, overflow) => match input {
+                b'\\' => Some(self.end_string(kind, StringTerminator::ST, overflow)),
+                _ => {
+                    self.state.state = State::Escape;
+                    Some(Step::Reprocess(Out::StringAborted {
+                        kind,
+                        cause: StringAbort::Interrupted,
+                    }))
+                }
+            },
+            _ => None,
+        }
+    }
+
+    fn step_reference(&mut self, mut input: u8) -> Step {
         if self.cfg.utf8 | self.cfg.utf8_strings {
             match self.next_utf8(input) {
-                Utf8Result::Produce(char) => match self.state.state {
-                    State::String(str) => match str {
-                        StringKind::DeviceControl => return Out::DCSData(char as crate::FfiChar),
-                        StringKind::Regular => return Out::SData(char as crate::FfiChar),
-                        StringKind::Privacy => return Out::PMData(char as crate::FfiChar),
-                        StringKind::ApplicationProgramCommand => {
-                            return Out::APCData(char as crate::FfiChar);
-                        }
-                        StringKind::Os => return Out::OSData(char as crate::FfiChar),
-                    },
-                    _ => return Out::Data(char as crate::FfiChar),
-                },
-                Utf8Result::Consume => return Out::None,
-                Utf8Result::InvalidCodepoint(code) => return Out::InvalidCodepoint(code),
-                Utf8Result::InvalidSequence => return Out::InvalidUtf8Sequence,
+                Utf8Result::Produce(char) => return Step::Out(self.char_out(char)),
+                Utf8Result::Consume => return Step::Out(Out::None),
+                Utf8Result::InvalidByte => return Step::Out(self.utf8_error()),
+                Utf8Result::InvalidSequence => return Step::Reprocess(self.utf8_error()),
                 Utf8Result::Pass => {}
             }
         }
-        match input as u32 {
-            24 | 26 => self.state.state = State::Ground,
-            27 => self.state.state = State::Ground,
+        self.state.escape_prefix = 0;
+        if let Some(step) = self.step_reference_string_end(input) {
+            return step;
+        }
+        let mut bit8 = false;
+        match input {
+            24 | 26 | 27 => self.state.state = State::Ground,
             0x80..=0x9F if self.cfg.bit8_enabled => {
                 self.state.state = State::Escape;
                 input -= 0x40;
+                bit8 = true;
             }
             _ => {}
         }
-        match self.state.state {
-            State::Ground => Out::C0(match input {
-                0 => C0::NUL,
-                1 => C0::SOH,
-                2 => C0::STX,
-                3 => C0::ETX,
-                4 => C0::EOT,
-                5 => C0::ENQ,
-                6 => C0::ACK,
-                7 => C0::BEL,
-                8 => C0::BS,
-                9 => C0::HT,
-                10 => C0::LF,
-                11 => C0::VT,
-                12 => C0::FF,
-                13 => C0::CR,
-                14 => C0::SO,
-                15 => C0::SI,
-                16 => C0::DLE,
-                17 => C0::DC1,
-                18 => C0::DC2,
-                19 => C0::DC3,
-                20 => C0::DC4,
-                21 => C0::NAK,
-                22 => C0::SI,
-                23 => C0::ETB,
-                24 => C0::CAN,
-                25 => C0::EM,
-                26 => C0::SUB,
+        let pass_c0 = matches!(input, 0x00..=0x17 | 0x19 | 0x1C..=0x1F);
+        Step::Out(match self.state.state {
+            State::Ground => match input {
                 27 => {
                     self.state.state = State::Escape;
-                    return Out::None;
-                }
-                28 => C0::FS,
-                29 => C0::GS,
-                30 => C0::RS,
-                31 => C0::US,
-                32 if self.cfg.space_special => return Out::SP,
-                127 if self.cfg.del_special => return Out::DEL,
-                _ => return Out::Data(input as crate::FfiChar),
-            }),
+                    Out::None
+                }
+                32 if self.cfg.space_special => Out::SP,
+                127 if self.cfg.del_special => Out::DEL,
+                _ => C0::try_from(input)
+                    .map_or_else(|_| Out::Data(self.cfg.encoding.decode(input)), Out::C0),
+            },
+            _ if pass_c0 && self.cfg.csi_pass_through_c0 && !self.in_string() => {
+                self.parse_safe_c0(input)
+            }
             State::Escape => match input {
                 0x20..=0x2F => {
                     self.reset_byte_buffer();
@@ -433,123 +1193,13 @@ impl UnsizedAnsiParser {
                     Out::None
                 }
                 0x30..=0x3F => {
-                    // Fp
-                    self.state.state = State::Ground;
-                    Out::Fp(match input {
-                        b'0' => Fp::UnknownX30,
-                        b'1' => Fp::UnknownX31,
-                        b'2' => Fp::UnknownX32,
-                        b'3' => Fp::UnknownX33,
-                        b'4' => Fp::UnknownX34,
-                        b'5' => Fp::UnknownX35,
-                        b'6' => Fp::DECFI,
-                        b'7' => Fp::DECSC,
-                        b'8' => Fp::DECRC,
-                        b'9' => Fp::UnknownX39,
-                        b':' => Fp::UnknownX3A,
-                        b';' => Fp::UnknownX3B,
-                        b'<' => Fp::UnknownX3C,
-                        b'=' => Fp::DECKPAM,
-                        b'>' => Fp::DECKPNM,
-                        b'?' => Fp::UnknownX3F,
-                        _ => unreachable!(),
-                    })
-                }
-                0x40..=0x5F => {
-                    // Fe
                     self.state.state = State::Ground;
-                    Out::C1(match input {
-                        b'@' => C1::PAD,
-                        b'A' => C1::HOP,
-                        b'B' => C1::BPH,
-                        b'C' => C1::NBH,
-                        b'D' => C1::IND,
-                        b'E' => C1::NEL,
-                        b'F' => C1::SSA,
-                        b'G' => C1::ESA,
-                        b'H' => C1::HTS,
-                        b'I' => C1::HTJ,
-                        b'J' => C1::VTS,
-                        b'K' => C1::PLD,
-                        b'L' => C1::PLU,
-                        b'M' => C1::RI,
-                        b'N' => C1::SS2,
-                        b'O' => C1::SS3,
-                        b'P' => {
-                            self.state.state = State::String(StringKind::DeviceControl);
-                            C1::DCS
-                        }
-                        b'Q' => C1::PU1,
-                        b'R' => C1::PU2,
-                        b'S' => C1::STS,
-                        b'T' => C1::CCH,
-                        b'U' => C1::MW,
-                        b'V' => C1::SPA,
-                        b'W' => C1::EPA,
-                        b'X' => {
-                            self.state.state = State::String(StringKind::Regular);
-                            C1::SOS
-                        }
-                        b'Y' => C1::SGCI,
-                        b'Z' => C1::SCI,
-                        b'[' => {
-                            self.state.state = State::CsiP;
-                            self.reset_byte_buffer();
-                            return Out::None;
-                        }
-                        b'\\' => C1::ST,
-                        b']' => {
-                            self.state.state = State::String(StringKind::Os);
-                            C1::OSC
-                        }
-                        b'^' => {
-                            self.state.state = State::String(StringKind::Privacy);
-                            C1::PM
-                        }
-                        b'_' => {
-                            self.state.state = State::String(StringKind::ApplicationProgramCommand);
-                            C1::APC
-                        }
-                        _ => unreachable!(),
-                    })
+                    Fp::try_from(input).map_or(Out::InvalidEscapeByte(input), Out::Fp)
                 }
+                0x40..=0x5F => return self.escape_c1(input, bit8),
                 0x60..=0x7E => {
-                    // Fs
                     self.state.state = State::Ground;
-                    Out::Fs(match input {
-                        b'`' => Fs::DMI,
-                        b'a' => Fs::INT,
-                        b'b' => Fs::EMI,
-                        b'c' => Fs::RIS,
-                        b'd' => Fs::CMD,
-                        b'e' => Fs::UnknownX65,
-                        b'f' => Fs::UnknownX66,
-                        b'g' => Fs::UnknownX67,
-                        b'h' => Fs::UnknownX68,
-                        b'i' => Fs::UnknownX69,
-                        b'j' => Fs::UnknownX6A,
-                        b'k' => Fs::UnknownX6B,
-                        b'l' => Fs::LCKMEM,
-                        b'm' => Fs::ULKMEM,
-                        b'n' => Fs::LS2,
-                        b'o' => Fs::LS3,
-                        b'p' => Fs::UnknownX70,
-                        b'q' => Fs::UnknownX71,
-                        b'r' => Fs::UnknownX72,
-                        b's' => Fs::UnknownX73,
-                        b't' => Fs::UnknownX74,
-                        b'u' => Fs::UnknownX75,
-                        b'v' => Fs::UnknownX76,
-                        b'w' => Fs::UnknownX77,
-                        b'x' => Fs::UnknownX78,
-                        b'y' => Fs::UnknownX79,
-                        b'z' => Fs::UnknownX7A,
-                        b'{' => Fs::UnknownX7B,
-                        b'|' => Fs::LS3R,
-                        b'}' => Fs::LS2R,
-                        b'~' => Fs::LS1R,
-                        _ => unreachable!(),
-                    })
+                    Fs::try_from(input).map_or(Out::InvalidEscapeByte(input), Out::Fs)
                 }
                 _ => {
                     self.state.state = State::Ground;
@@ -557,20 +1207,18 @@ impl UnsizedAnsiParser {
                 }
             },
             State::Nf(err) => match input {
-                0x20..=0x2f => {
+                0x20..=0x2F => {
                     self.state.state = State::Nf(!self.insert_into_byte_buffer(input));
                     Out::None
                 }
                 0x30..=0x7E => {
                     self.state.state = State::Ground;
-                    if err
-                        && !self.insert_into_byte_buffer(input)
+                    if (err || !self.insert_into_byte_buffer(input))
                         && !self.cfg.nf_silent_sequence_overflow
                     {
                         Out::nFSequenceTooLarge
                     } else {
-                        #[allow(clippy::useless_conversion)]
-                        Out::nF(self.current_byte_buffer().into())
+                        return Step::Nf;
                     }
                 }
                 _ => {
@@ -591,7 +1239,7 @@ impl UnsizedAnsiParser {
                 }
                 0x40..=0x7E => {
                     self.state.state = State::Ground;
-                    self.push_f(input)
+                    return self.push_f(input);
                 }
                 _ => {
                     self.state.state = State::CsiIgnore(IgnoreKind::InvalidByteEncountered);
@@ -609,7 +1257,7 @@ impl UnsizedAnsiParser {
                 }
                 0x40..=0x7E => {
                     self.state.state = State::Ground;
-                    self.push_f(input)
+                    return self.push_f(input);
                 }
                 _ => {
                     self.state.state = State::CsiIgnore(IgnoreKind::InvalidByteEncountered);
@@ -628,22 +1276,26 @@ impl UnsizedAnsiParser {
                 }
                 _ => Out::None,
             },
-            State::String(kind) => match input {
-                0x00..=0x17 | 0x19 | 0x1C..=0x1F => {
-                    if self.cfg.string_pass_through_c0 {
-                        self.parse_safe_c0(input)
-                    } else {
-                        Out::None
+            // handled by `step_reference_string_end`
+            State::StringEscape(..) => Out::None,
+            State::String(kind, overflow) => match input {
+                _ if pass_c0 && self.cfg.string_pass_through_c0 => self.parse_safe_c0(input),
+                _ if pass_c0 => Out::None,
+                c if self.cfg.buffer_strings => {
+                    if !overflow && !self.insert_into_byte_buffer(c) {
+                        self.state.state = State::String(kind, true);
                     }
+                    Out::None
                 }
-                c => match kind {
-                    StringKind::DeviceControl => Out::DCSData(c as crate::FfiChar),
-                    StringKind::Regular => Out::SData(c as crate::FfiChar),
-                    StringKind::Os => Out::OSData(c as crate::FfiChar),
-                    StringKind::Privacy => Out::PMData(c as crate::FfiChar),
-                    StringKind::ApplicationProgramCommand => Out::APCData(c as crate::FfiChar),
-                },
+                c => self.char_out(self.cfg.encoding.decode(c)),
             },
-        }
+        })
+    }
+
+    fn in_string(&self) -> bool {
+        matches!(
+            self.state.state,
+            State::String(..) | State::StringEscape(..)
+        )
     }
 }
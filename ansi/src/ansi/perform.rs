@@ -0,0 +1,264 @@
+use crate::ansi::*;
+
+/// The number of `;` separated parameters passed to [`Perform::osc_dispatch`],
+/// the last one holds the rest of the command including its separators
+pub const OSC_MAX_PARAMS: usize = 16;
+
+/// Callbacks invoked by [`AnsiParser::advance`], every hook does nothing by default.
+///
+/// Control strings are delivered depending on [`Config::buffer_strings`]. When it is
+/// set a complete OSC or DCS goes to [`Perform::osc_dispatch`] or [`Perform::dcs_dispatch`],
+/// otherwise, which is the default, their bodies are streamed one character at a time
+/// through [`Perform::osc_start`]/[`Perform::osc_put`]/[`Perform::osc_end`] and
+/// [`Perform::hook`]/[`Perform::put`]/[`Perform::unhook`].
+pub trait Perform {
+    /// A printable character, including runs of [`Out::Text`] and [`Out::SP`]
+    fn print(&mut self, _c: char) {}
+
+    /// A C0 or C1 control, C1 controls are given by their 8-bit code
+    /// regardless of whether they were sent as `ESC Fe`
+    fn execute(&mut self, _byte: u8) {}
+
+    /// A character set designation such as `ESC ( 0`, requires [`Config::designations`]
+    /// and goes to [`Self::esc_dispatch`] otherwise
+    fn designate(&mut self, _slot: CharsetSlot, _charset: Charset) {}
+
+    /// A complete control sequence
+    fn csi_dispatch(&mut self, _csi: crate::KnownCSI<'_>) {}
+
+    /// An Fp, Fs or nF escape sequence, `intermediates` is only non empty for nF sequences
+    fn esc_dispatch(&mut self, _intermediates: &[u8], _byte: u8) {}
+
+    /// A complete operating system command split at `;`, requires [`Config::buffer_strings`]
+    ///
+    /// `bell_terminated` is set when the command ended with BEL instead of ST, replies
+    /// are expected to use the same terminator.
+    fn osc_dispatch(&mut self, _params: &[&[u8]], _bell_terminated: bool) {}
+
+    /// An operating system command has started, not called when [`Config::buffer_strings`] is set
+    fn osc_start(&mut self) {}
+
+    /// A character of an operating system command
+    fn osc_put(&mut self, _c: char) {}
+
+    /// The operating system command was terminated or aborted
+    fn osc_end(&mut self) {}
+
+    /// The header of a device control string, its parameters, intermediates and final byte,
+    /// was complete. Not called when [`Config::buffer_strings`] is set.
+    ///
+    /// The parameters are decoded with [`Config::csi_params`], a malformed header
+    /// or one longer than the byte buffer goes to [`Self::other`] with the rest of the string.
+    fn hook(&mut self, _header: crate::CSI<'_>) {}
+
+    /// A character of a device control string after its header
+    fn put(&mut self, _c: char) {}
+
+    /// The device control string was terminated or aborted, only called after [`Self::hook`]
+    fn unhook(&mut self) {}
+
+    /// A complete device control string and the data after its header,
+    /// requires [`Config::buffer_strings`]
+    fn dcs_dispatch(&mut self, _header: crate::CSI<'_>, _data: &[u8]) {}
+
+    /// Every event which has no dedicated hook, such as errors and other control strings
+    fn other(&mut self, _out: Out<'_>) {}
+}
+
+#[inline(always)]
+#[allow(clippy::useless_conversion)]
+fn to_char(c: crate::FfiChar) -> Option<char> {
+    char::try_from(c).ok()
+}
+
+impl<const BYTE_BUF_SIZE: usize> SizedAnsiParser<BYTE_BUF_SIZE> {
+    #[inline(always)]
+    pub fn advance(&mut self, performer: &mut impl Perform, input: &[u8]) {
+        let tc: &mut UnsizedAnsiParser = self;
+        tc.advance(performer, input)
+    }
+}
+
+impl<T: ParserBuffer + ?Sized> AnsiParser<T> {
+    /// Feeds all of `input` into the parser, calling into `performer` for every event
+    pub fn advance(&mut self, performer: &mut impl Perform, mut input: &[u8]) {
+        while !input.is_empty() {
+            let buffer_strings = self.cfg.buffer_strings;
+            let csi_params = self.cfg.csi_params;
+            let dcs_header = self.state.dcs_header;
+            let Feed { consumed, out } = self.feed(input);
+            input = input.get(consumed..).unwrap_or(&[]);
+            let dcs_header = match out {
+                Out::C1(C1::DCS) if !buffer_strings => DcsHeader::Collecting,
+                Out::DCSData(c) if dcs_header == DcsHeader::Collecting => {
+                    self.dcs_header_byte(performer, c)
+                }
+                Out::DCSData(_) if dcs_header == DcsHeader::Invalid => {
+                    performer.other(out);
+                    dcs_header
+                }
+                Out::StringEnd {
+                    kind: StringKind::DeviceControl,
+                    ..
+                }
+                | Out::StringAborted {
+                    kind: StringKind::DeviceControl,
+                    ..
+                } if !buffer_strings => {
+                    if dcs_header == DcsHeader::Hooked {
+                        performer.unhook();
+                    }
+                    DcsHeader::Idle
+                }
+                Out::DCS { payload, .. } => {
+                    match split_dcs_header(payload.into()) {
+                        Some((header, data)) => {
+                            let mut params = crate::CsiParams::new();
+                            performer
+                                .dcs_dispatch(with_params(&mut params, header, csi_params), data)
+                        }
+                        None => performer.other(out),
+                    }
+                    dcs_header
+                }
+                out => {
+                    perform(performer, out, buffer_strings);
+                    dcs_header
+                }
+            };
+            self.state.dcs_header = dcs_header;
+        }
+    }
+
+    /// Collects a byte of a streamed DCS header, calling [`Perform::hook`] on its final byte
+    #[allow(clippy::useless_conversion)]
+    fn dcs_header_byte(&mut self, performer: &mut impl Perform, c: crate::FfiChar) -> DcsHeader {
+        let byte = u8::try_from(u32::from(c)).ok();
+        let next = byte.and_then(|b| continues_dcs_header(self.current_byte_buffer(), b));
+        match (byte, next) {
+            (Some(byte), Some(last)) if self.insert_into_byte_buffer(byte) => {
+                if !last {
+                    return DcsHeader::Collecting;
+                }
+                let mut params = crate::CsiParams::new();
+                let header = self.current_byte_buffer();
+                performer.hook(with_params(&mut params, header, self.cfg.csi_params));
+                DcsHeader::Hooked
+            }
+            _ => {
+                let header: &[u8] = self.current_byte_buffer();
+                header
+                    .iter()
+                    .for_each(|b| performer.other(Out::DCSData(char::from(*b).into())));
+                performer.other(Out::DCSData(c));
+                DcsHeader::Invalid
+            }
+        }
+    }
+}
+
+/// Whether `byte` may follow `header` in a DCS header, true if it is the final byte
+fn continues_dcs_header(header: &[u8], byte: u8) -> Option<bool> {
+    let intermediates = header.last().is_some_and(|b| matches!(b, 0x20..=0x2F));
+    match byte {
+        0x30..=0x3F if !intermediates => Some(false),
+        0x20..=0x2F => Some(false),
+        0x40..=0x7E => Some(true),
+        _ => None,
+    }
+}
+
+/// Splits a buffered DCS payload after the final byte of its header
+fn split_dcs_header(payload: &[u8]) -> Option<(&[u8], &[u8])> {
+    for (i, byte) in payload.iter().enumerate() {
+        if continues_dcs_header(payload.get(..i)?, *byte)? {
+            return payload.get(..=i).zip(payload.get(i + 1..));
+        }
+    }
+    None
+}
+
+fn with_params<'a>(
+    params: &'a mut crate::CsiParams,
+    header: &'a [u8],
+    decode: bool,
+) -> crate::CSI<'a> {
+    if decode {
+        params.decode(header);
+    }
+    crate::CSI::with_params(header, decode.then_some(&*params))
+}
+
+/// Splits an OSC payload at `;`, the last parameter keeps any further separators
+fn osc_params(payload: &[u8]) -> ([&[u8]; OSC_MAX_PARAMS], usize) {
+    let mut params = [&[][..]; OSC_MAX_PARAMS];
+    let mut count = 0;
+    for (slot, param) in params
+        .iter_mut()
+        .zip(payload.splitn(OSC_MAX_PARAMS, |b| *b == b';'))
+    {
+        *slot = param;
+        count += 1;
+    }
+    (params, count)
+}
+
+#[allow(clippy::useless_conversion)]
+fn perform(performer: &mut impl Perform, out: Out<'_>, buffer_strings: bool) {
+    match out {
+        Out::None => {}
+        Out::Data(c) => match to_char(c) {
+            Some(c) => performer.print(c),
+            None => performer.other(out),
+        },
+        Out::Text(text) => text.chars().for_each(|c| performer.print(c)),
+        Out::SP => performer.print(' '),
+
+        Out::C0(c0) => performer.execute(c0 as u8),
+        Out::C1(C1::OSC) if !buffer_strings => performer.osc_start(),
+        Out::C1(C1::DCS | C1::SOS | C1::PM | C1::APC | C1::OSC) => performer.other(out),
+        Out::C1(c1) => performer.execute((c1 as u8).wrapping_add(0x40)),
+
+        Out::Fp(fp) => performer.esc_dispatch(&[], fp as u8),
+        Out::Fs(fs) => performer.esc_dispatch(&[], fs as u8),
+        Out::nF(bytes) => {
+            let bytes: &[u8] = bytes.into();
+            if let Some((last, intermediates)) = bytes.split_last() {
+                performer.esc_dispatch(intermediates, *last)
+            }
+        }
+
+        Out::Designate { slot, charset, .. } => performer.designate(slot, charset),
+
+        Out::CSI(csi) => performer.csi_dispatch(csi.parse()),
+
+        Out::DCSData(c) => match to_char(c) {
+            Some(c) => performer.put(c),
+            None => performer.other(out),
+        },
+        Out::OSData(c) => match to_char(c) {
+            Some(c) => performer.osc_put(c),
+            None => performer.other(out),
+        },
+        Out::StringEnd {
+            kind: StringKind::Os,
+            ..
+        }
+        | Out::StringAborted {
+            kind: StringKind::Os,
+            ..
+        } if !buffer_strings => performer.osc_end(),
+        Out::OSC {
+            payload,
+            terminator,
+        } => {
+            let (params, count) = osc_params(payload.into());
+            performer.osc_dispatch(
+                params.get(..count).unwrap_or(&params),
+                terminator == StringTerminator::BEL,
+            )
+        }
+
+        _ => performer.other(out),
+    }
+}
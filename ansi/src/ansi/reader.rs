@@ -0,0 +1,77 @@
+use crate::ansi::*;
+use alloc::vec::Vec;
+
+/// Parses everything read from `R`, yielding [`OwnedOut`] events
+#[derive(Debug)]
+pub struct AnsiReader<R, const BUF_CAP: usize = 1024> {
+    reader: R,
+    pub parser: SizedAnsiParser<BUF_CAP>,
+    input: Vec<u8>,
+    pos: usize,
+    len: usize,
+}
+
+impl<R: std::io::Read, const BUF_CAP: usize> AnsiReader<R, BUF_CAP> {
+    pub fn new(reader: R) -> Self {
+        Self::with_capacity(reader, 4096)
+    }
+
+    /// `capacity` is the number of bytes read from `reader` at once
+    pub fn with_capacity(reader: R, capacity: usize) -> Self {
+        Self {
+            reader,
+            parser: SizedAnsiParser::new(),
+            input: alloc::vec![0; capacity.max(1)],
+            pos: 0,
+            len: 0,
+        }
+    }
+
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Returns the reader, input which was read but not parsed yet is lost
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: std::io::Read, const BUF_CAP: usize> Iterator for AnsiReader<R, BUF_CAP> {
+    type Item = std::io::Result<OwnedOut>;
+
+    /// Reads until the parser produces an event, None once the reader is exhausted.
+    ///
+    /// At the end of input whatever the parser is still waiting on is resolved with
+    /// [`AnsiParser::flush_pending`], so a trailing lone `ESC` is still reported.
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.pos >= self.len {
+                self.pos = 0;
+                self.len = 0;
+                self.len = match self.reader.read(&mut self.input) {
+                    Ok(0) => {
+                        return match self.parser.flush_pending() {
+                            Out::None => None,
+                            out => Some(Ok(out.into())),
+                        };
+                    }
+                    Ok(len) => len,
+                    Err(err) if err.kind() == std::io::ErrorKind::Interrupted => 0,
+                    Err(err) => return Some(Err(err)),
+                };
+                continue;
+            }
+            let Feed { consumed, out } = self.parser.feed(&self.input[self.pos..self.len]);
+            let out = OwnedOut::from(out);
+            self.pos += consumed;
+            if out != OwnedOut::None {
+                return Some(Ok(out));
+            }
+        }
+    }
+}
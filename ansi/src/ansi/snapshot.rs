@@ -0,0 +1,380 @@
+use crate::ansi::*;
+
+/// The format version written by [`AnsiParser::write_snapshot`]
+pub const SNAPSHOT_VERSION: u8 = 1;
+
+const MAGIC: [u8; 4] = *b"ANSP";
+const HEADER_LEN: usize = 40;
+const CHECKSUM_LEN: usize = 4;
+const CHECKSUM_SEED: u32 = 0x811C_9DC5;
+
+/// Why a snapshot was rejected by [`AnsiParser::restore`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub enum SnapshotError {
+    /// The snapshot is shorter than its header or does not hold exactly its buffered bytes
+    Length,
+    /// The bytes are not a parser snapshot
+    Magic,
+    /// The snapshot was written in a format version this parser does not read
+    Version(u8),
+    /// The snapshot was modified after it was written
+    Checksum,
+    /// The fields describe a state the parser can never be in
+    Invalid,
+    /// The buffered bytes do not fit into the buffer of the parser
+    BufferTooSmall,
+}
+
+/// FNV-1a, any single changed byte changes the result
+#[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+fn checksum(hash: u32, bytes: &[u8]) -> u32 {
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ *byte as u32).wrapping_mul(0x0100_0193)
+    })
+}
+
+/// Feeds everything written through it into the checksum
+struct Checksummed<'a, S: ?Sized> {
+    sink: &'a mut S,
+    hash: u32,
+}
+
+impl<S: ByteSink + ?Sized> ByteSink for Checksummed<'_, S> {
+    type Error = S::Error;
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.hash = checksum(self.hash, bytes);
+        self.sink.write_bytes(bytes)
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], SnapshotError> {
+        let (head, tail) = self.0.split_first_chunk().ok_or(SnapshotError::Length)?;
+        self.0 = tail;
+        Ok(*head)
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn take_slice(&mut self, len: usize) -> Result<&'a [u8], SnapshotError> {
+        let (head, tail) = self.0.split_at_checked(len).ok_or(SnapshotError::Length)?;
+        self.0 = tail;
+        Ok(head)
+    }
+}
+
+#[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+fn encode_state(state: State) -> (u8, u8, bool) {
+    match state {
+        State::Ground => (0, 0, false),
+        State::Escape => (1, 0, false),
+        State::CsiP => (2, 0, false),
+        State::CsiI => (3, 0, false),
+        State::CsiIgnore(kind) => (4, kind as u8, false),
+        State::String(kind, overflow) => (5, kind as u8, overflow),
+        State::StringEscape(kind, overflow) => (6, kind as u8, overflow),
+        State::Nf(err) => (7, 0, err),
+    }
+}
+
+#[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+fn decode_state(tag: u8, arg: u8, flag: bool) -> Option<State> {
+    let string_kind = || match arg {
+        0 => Some(StringKind::DeviceControl),
+        1 => Some(StringKind::Regular),
+        2 => Some(StringKind::Privacy),
+        3 => Some(StringKind::ApplicationProgramCommand),
+        4 => Some(StringKind::Os),
+        _ => None,
+    };
+    Some(match (tag, arg, flag) {
+        (0, 0, false) => State::Ground,
+        (1, 0, false) => State::Escape,
+        (2, 0, false) => State::CsiP,
+        (3, 0, false) => State::CsiI,
+        (4, _, false) => State::CsiIgnore(match arg {
+            0 => IgnoreKind::InvalidByteEncountered,
+            1 => IgnoreKind::SequenceOverflow,
+            2 => IgnoreKind::ImmediateOverflow,
+            3 => IgnoreKind::InvalidSequence,
+            _ => return None,
+        }),
+        (5, _, _) => State::String(string_kind()?, flag),
+        (6, _, _) => State::StringEscape(string_kind()?, flag),
+        (7, 0, _) => State::Nf(flag),
+        _ => return None,
+    })
+}
+
+/// Checks everything about a snapshot which does not depend on the parser it is restored into
+#[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+fn decode(snapshot: &[u8]) -> Result<(ParserState, &[u8]), SnapshotError> {
+    let (body, sum) = snapshot
+        .split_last_chunk::<CHECKSUM_LEN>()
+        .ok_or(SnapshotError::Length)?;
+    let mut reader = Reader(body);
+    if reader.take()? != MAGIC {
+        return Err(SnapshotError::Magic);
+    }
+    let [version] = reader.take()?;
+    if version != SNAPSHOT_VERSION {
+        return Err(SnapshotError::Version(version));
+    }
+    if body.len() < HEADER_LEN {
+        return Err(SnapshotError::Length);
+    }
+    if checksum(CHECKSUM_SEED, body) != u32::from_le_bytes(*sum) {
+        return Err(SnapshotError::Checksum);
+    }
+
+    let [
+        tag,
+        arg,
+        flags,
+        utf8_state,
+        utf8_lower,
+        utf8_upper,
+        escape_prefix,
+    ] = reader.take()?;
+    let codepoint = u32::from_le_bytes(reader.take()?);
+    let immediate_count =
+        usize::try_from(u64::from_le_bytes(reader.take()?)).map_err(|_| SnapshotError::Invalid)?;
+    let csi_dropped =
+        usize::try_from(u64::from_le_bytes(reader.take()?)).map_err(|_| SnapshotError::Invalid)?;
+    let buffer_count =
+        usize::try_from(u64::from_le_bytes(reader.take()?)).map_err(|_| SnapshotError::Invalid)?;
+    let buffered = reader.take_slice(buffer_count)?;
+    if !reader.0.is_empty() {
+        return Err(SnapshotError::Length);
+    }
+
+    let state = decode_state(tag, arg, flags & 1 != 0).ok_or(SnapshotError::Invalid)?;
+    let utf8_valid = match utf8_state {
+        0 => utf8_lower == 0x80 && utf8_upper == 0xBF && codepoint == 0,
+        // the code point has to stay a scalar value once the remaining bytes are appended
+        1..=3 => {
+            0x80 <= utf8_lower
+                && utf8_lower <= utf8_upper
+                && utf8_upper <= 0xBF
+                && (codepoint as u64).wrapping_shl((utf8_state as u32).wrapping_mul(6)) <= 0x10FFFF
+        }
+        _ => false,
+    };
+    let dcs_header = match flags >> 3 {
+        0 => DcsHeader::Idle,
+        1 => DcsHeader::Collecting,
+        2 => DcsHeader::Hooked,
+        3 => DcsHeader::Invalid,
+        _ => return Err(SnapshotError::Invalid),
+    };
+    if !utf8_valid || !matches!(escape_prefix, 0 | b'[' | b'O') {
+        return Err(SnapshotError::Invalid);
+    }
+
+    Ok((
+        ParserState {
+            immediate_count,
+            csi_truncated: flags & 0b010 != 0,
+            csi_skip: flags & 0b100 != 0,
+            csi_dropped,
+            state,
+            utf8_state,
+            utf8_lower,
+            utf8_upper,
+            codepoint,
+            escape_prefix,
+            dcs_header,
+            buffer_count,
+        },
+        buffered,
+    ))
+}
+
+impl<const BYTE_BUF_SIZE: usize> SizedAnsiParser<BYTE_BUF_SIZE> {
+    #[inline(always)]
+    pub fn snapshot_len(&self) -> usize {
+        let tc: &UnsizedAnsiParser = self;
+        tc.snapshot_len()
+    }
+
+    #[inline(always)]
+    pub fn write_snapshot<S: ByteSink + ?Sized>(&self, sink: &mut S) -> Result<(), S::Error> {
+        let tc: &UnsizedAnsiParser = self;
+        tc.write_snapshot(sink)
+    }
+
+    #[inline(always)]
+    pub fn restore(&mut self, snapshot: &[u8]) -> Result<(), SnapshotError> {
+        let tc: &mut UnsizedAnsiParser = self;
+        tc.restore(snapshot)
+    }
+
+    #[cfg(any(test, feature = "alloc"))]
+    #[inline(always)]
+    pub fn snapshot(&self) -> Snapshot {
+        let tc: &UnsizedAnsiParser = self;
+        tc.snapshot()
+    }
+}
+
+impl<T: ParserBuffer + ?Sized> AnsiParser<T> {
+    /// The number of bytes [`Self::write_snapshot`] writes
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn snapshot_len(&self) -> usize {
+        HEADER_LEN
+            .saturating_add(self.current_byte_buffer().len())
+            .saturating_add(CHECKSUM_LEN)
+    }
+
+    /// Writes the progress of the parser through its input, including any buffered
+    /// bytes, so it can be resumed with [`Self::restore`]. The [`Config`] is not part
+    /// of the snapshot.
+    ///
+    /// The format is stable for a given [`SNAPSHOT_VERSION`]: a magic number, the
+    /// version, the state and counts in little endian, the buffered bytes and a checksum.
+    pub fn write_snapshot<S: ByteSink + ?Sized>(&self, sink: &mut S) -> Result<(), S::Error> {
+        let state = &self.state;
+        let buffered = self.current_byte_buffer();
+        let (tag, arg, flag) = encode_state(state.state);
+        let flags = flag as u8
+            | (state.csi_truncated as u8) << 1
+            | (state.csi_skip as u8) << 2
+            | (state.dcs_header as u8) << 3;
+        // leftovers of a finished sequence are not written so equal states compare equal
+        let (utf8_lower, utf8_upper, codepoint) = match state.utf8_state {
+            0 => (0x80, 0xBF, 0),
+            _ => (state.utf8_lower, state.utf8_upper, state.codepoint),
+        };
+
+        let mut sink = Checksummed {
+            sink,
+            hash: CHECKSUM_SEED,
+        };
+        sink.write_bytes(&MAGIC)?;
+        sink.write_bytes(&[
+            SNAPSHOT_VERSION,
+            tag,
+            arg,
+            flags,
+            state.utf8_state,
+            utf8_lower,
+            utf8_upper,
+            state.escape_prefix,
+        ])?;
+        sink.write_bytes(&codepoint.to_le_bytes())?;
+        sink.write_bytes(&(state.immediate_count as u64).to_le_bytes())?;
+        sink.write_bytes(&(state.csi_dropped as u64).to_le_bytes())?;
+        sink.write_bytes(&(buffered.len() as u64).to_le_bytes())?;
+        sink.write_bytes(buffered)?;
+        let hash = sink.hash;
+        sink.sink.write_bytes(&hash.to_le_bytes())
+    }
+
+    /// Resumes from a snapshot taken by [`Self::write_snapshot`], possibly by another
+    /// parser. The parser is left untouched if the snapshot is rejected.
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn restore(&mut self, snapshot: &[u8]) -> Result<(), SnapshotError> {
+        let (state, buffered) = decode(snapshot)?;
+        if !self.buffer.reserve(buffered.len()) {
+            return Err(SnapshotError::BufferTooSmall);
+        }
+        let dest = self
+            .buffer
+            .bytes_mut()
+            .get_mut(..buffered.len())
+            .ok_or(SnapshotError::BufferTooSmall)?;
+        dest.iter_mut()
+            .zip(buffered)
+            .for_each(|(dest, byte)| *dest = *byte);
+        self.state = state;
+        self.params.clear();
+        Ok(())
+    }
+
+    #[cfg(any(test, feature = "alloc"))]
+    pub fn snapshot(&self) -> Snapshot {
+        let mut bytes = alloc::vec::Vec::with_capacity(self.snapshot_len());
+        let Ok(()) = self.write_snapshot(&mut bytes);
+        Snapshot(bytes)
+    }
+}
+
+/// An owned snapshot which is known to be well formed, see [`AnsiParser::write_snapshot`]
+#[cfg(any(test, feature = "alloc"))]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Snapshot(alloc::vec::Vec<u8>);
+
+#[cfg(any(test, feature = "alloc"))]
+impl Snapshot {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn into_bytes(self) -> alloc::vec::Vec<u8> {
+        self.0
+    }
+}
+
+#[cfg(any(test, feature = "alloc"))]
+impl TryFrom<alloc::vec::Vec<u8>> for Snapshot {
+    type Error = SnapshotError;
+
+    fn try_from(bytes: alloc::vec::Vec<u8>) -> Result<Self, Self::Error> {
+        decode(&bytes)?;
+        Ok(Self(bytes))
+    }
+}
+
+/// Serialized as a byte string in the format of [`AnsiParser::write_snapshot`]
+#[cfg(feature = "serde")]
+impl serde::Serialize for Snapshot {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Snapshot {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use alloc::vec::Vec;
+
+        struct BytesVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for BytesVisitor {
+            type Value = Vec<u8>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.write_str("a parser snapshot")
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                Ok(v.into())
+            }
+
+            fn visit_byte_buf<E: serde::de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+                Ok(v)
+            }
+
+            // formats without byte strings such as JSON write a sequence of numbers
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut bytes = Vec::new();
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
+                }
+                Ok(bytes)
+            }
+        }
+
+        let bytes = deserializer.deserialize_byte_buf(BytesVisitor)?;
+        Snapshot::try_from(bytes).map_err(|err| {
+            serde::de::Error::custom(format_args!("invalid parser snapshot: {err:?}"))
+        })
+    }
+}
@@ -0,0 +1,142 @@
+use crate::ansi::*;
+
+/// A range of absolute offsets into the input stream
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub struct Span {
+    pub start: u64,
+    pub end: u64,
+}
+
+/// The result of feeding a chunk of input into a [`SpanParser`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub struct SpannedFeed<'a> {
+    /// Number of bytes taken from the front of the input
+    pub consumed: usize,
+    /// The input bytes which make up `out`, including introducers and terminators
+    pub span: Span,
+    /// The first event produced, [`Out::None`] if the input ran out first
+    pub out: Out<'a>,
+}
+
+pub type SizedSpanParser<const BUF_CAP: usize> = SpanParser<[u8; BUF_CAP]>;
+
+pub type UnsizedSpanParser = SpanParser<[u8]>;
+
+/// Wraps an [`AnsiParser`] and tracks which bytes of the stream each event came from.
+///
+/// C0 controls which are passed through from the middle of a sequence span only
+/// their own byte, the sequence they interrupted still spans all of its bytes.
+#[derive(Debug)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub struct SpanParser<T: ?Sized> {
+    offset: u64,
+    start: u64,
+    pub parser: AnsiParser<T>,
+}
+
+impl<const BYTE_BUF_SIZE: usize> core::default::Default for SizedSpanParser<BYTE_BUF_SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const BYTE_BUF_SIZE: usize> SizedSpanParser<BYTE_BUF_SIZE> {
+    pub const fn new() -> Self {
+        Self {
+            offset: 0,
+            start: 0,
+            parser: SizedAnsiParser::new(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn offset(&self) -> u64 {
+        let tc: &UnsizedSpanParser = self;
+        tc.offset()
+    }
+
+    #[inline(always)]
+    pub fn feed<'a>(&'a mut self, input: &'a [u8]) -> SpannedFeed<'a> {
+        let tc: &mut UnsizedSpanParser = self;
+        tc.feed(input)
+    }
+}
+
+impl UnsizedSpanParser {
+    /// The number of bytes consumed so far
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Same as [`AnsiParser::feed`] but also returns the span of the event
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn feed<'a>(&'a mut self, input: &'a [u8]) -> SpannedFeed<'a> {
+        if let Some(text) = self.parser.text_run(input) {
+            let start = self.offset;
+            self.offset = self.offset.wrapping_add(text.len() as u64);
+            self.start = self.offset;
+            return SpannedFeed {
+                consumed: text.len(),
+                span: Span {
+                    start,
+                    end: self.offset,
+                },
+                #[allow(clippy::useless_conversion)]
+                out: Out::Text(text.into()),
+            };
+        }
+        for (i, byte) in input.iter().copied().enumerate() {
+            if self.parser.at_boundary() {
+                self.start = self.offset;
+            }
+            let step = self.parser.step(byte);
+            if let Step::Reprocess(out) = step {
+                let span = Span {
+                    start: self.start,
+                    end: self.offset,
+                };
+                self.start = self.offset;
+                return SpannedFeed {
+                    consumed: i,
+                    span,
+                    out,
+                };
+            }
+
+            let byte_start = self.offset;
+            self.offset = self.offset.wrapping_add(1);
+            let span = match step {
+                Step::Out(Out::None) => continue,
+                // passed through from the middle of a sequence
+                Step::Out(Out::C0(_)) if !self.parser.at_boundary() => Span {
+                    start: byte_start,
+                    end: self.offset,
+                },
+                _ => {
+                    let span = Span {
+                        start: self.start,
+                        end: self.offset,
+                    };
+                    self.start = self.offset;
+                    span
+                }
+            };
+            return SpannedFeed {
+                consumed: i.wrapping_add(1),
+                span,
+                out: self.parser.resolve(step),
+            };
+        }
+        SpannedFeed {
+            consumed: input.len(),
+            span: Span {
+                start: self.offset,
+                end: self.offset,
+            },
+            out: Out::None,
+        }
+    }
+}
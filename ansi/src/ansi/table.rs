@@ -0,0 +1,240 @@
+use super::parser::State;
+
+/// Bytes which the state machine never tells apart share a class
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub(super) enum ByteClass {
+    /// C0 controls other than the ones below
+    Execute,
+    Bel,
+    /// CAN and SUB
+    Abort,
+    Esc,
+    Space,
+    /// 0x21..=0x2F
+    Intermediate,
+    /// 0x30..=0x3F
+    Param,
+    /// 0x40..=0x5F except for the backslash
+    Upper,
+    Backslash,
+    /// 0x60..=0x7E
+    Lower,
+    Del,
+    /// 0x80..=0x9F except for ST
+    C1,
+    St,
+    /// 0xA0..=0xFF
+    High,
+}
+
+// powers of two so masking the indices proves them in bounds
+const CLASS_COUNT: usize = 16;
+const STATE_COUNT: usize = 8;
+
+/// What [`super::AnsiParser::step`] does with a byte, picked by state and byte class
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub(super) enum Action {
+    Print,
+    Space,
+    Del,
+    Execute,
+    /// A C0 control passed through from the middle of a sequence
+    PassC0,
+    /// CAN or SUB, which cancel the current sequence
+    Abort,
+    EnterEscape,
+    /// An 8-bit C1 control, handled like the `ESC Fe` it stands for
+    C1,
+
+    EscIntermediate,
+    EscFp,
+    EscC1,
+    EscFs,
+    EscInvalid,
+
+    NfCollect,
+    NfFinal,
+    NfInvalid,
+
+    CsiParam,
+    CsiEnterIntermediate,
+    CsiIntermediate,
+    /// A parameter byte after an intermediate
+    CsiInvalidSequence,
+    CsiFinal,
+    CsiInvalid,
+    IgnoreFinal,
+    Ignore,
+
+    StringPut,
+    StringC0,
+    StringBel,
+    StringAbort,
+    StringEscape,
+    StringC1,
+    StringSt,
+    StringEscapeEnd,
+    StringEscapeAbort,
+}
+
+const fn classify(byte: u8) -> ByteClass {
+    match byte {
+        0x07 => ByteClass::Bel,
+        0x18 | 0x1A => ByteClass::Abort,
+        0x1B => ByteClass::Esc,
+        0x00..=0x1F => ByteClass::Execute,
+        0x20 => ByteClass::Space,
+        0x21..=0x2F => ByteClass::Intermediate,
+        0x30..=0x3F => ByteClass::Param,
+        b'\\' => ByteClass::Backslash,
+        0x40..=0x5F => ByteClass::Upper,
+        0x60..=0x7E => ByteClass::Lower,
+        0x7F => ByteClass::Del,
+        0x9C => ByteClass::St,
+        0x80..=0x9F => ByteClass::C1,
+        0xA0..=0xFF => ByteClass::High,
+    }
+}
+
+/// The action for bytes which can not appear in the state, C1 controls fall back to
+/// it when 8-bit controls are disabled and C0 controls when they are not passed through
+const fn fallback(state: usize) -> Action {
+    match state {
+        GROUND => Action::Print,
+        ESCAPE => Action::EscInvalid,
+        CSI_P | CSI_I => Action::CsiInvalid,
+        CSI_IGNORE => Action::Ignore,
+        STRING => Action::StringPut,
+        STRING_ESCAPE => Action::StringEscapeAbort,
+        _ => Action::NfInvalid,
+    }
+}
+
+const GROUND: usize = 0;
+const ESCAPE: usize = 1;
+const CSI_P: usize = 2;
+const CSI_I: usize = 3;
+const CSI_IGNORE: usize = 4;
+const STRING: usize = 5;
+const STRING_ESCAPE: usize = 6;
+const NF: usize = 7;
+
+const fn action(state: usize, class: ByteClass, bit8: bool, pass_c0: bool) -> Action {
+    use ByteClass as B;
+
+    if state == STRING_ESCAPE {
+        return match class {
+            B::Backslash => Action::StringEscapeEnd,
+            _ => Action::StringEscapeAbort,
+        };
+    }
+    if state == STRING {
+        return match class {
+            B::Execute => Action::StringC0,
+            B::Bel => Action::StringBel,
+            B::Abort => Action::StringAbort,
+            B::Esc => Action::StringEscape,
+            B::C1 if bit8 => Action::StringC1,
+            B::St if bit8 => Action::StringSt,
+            _ => Action::StringPut,
+        };
+    }
+    match class {
+        B::Abort => return Action::Abort,
+        B::Esc => return Action::EnterEscape,
+        B::C1 | B::St if bit8 => return Action::C1,
+        B::C1 | B::St | B::High => return fallback(state),
+        B::Execute | B::Bel if state == GROUND => return Action::Execute,
+        B::Execute | B::Bel if pass_c0 => return Action::PassC0,
+        B::Execute | B::Bel => return fallback(state),
+        _ => {}
+    }
+    match (state, class) {
+        (GROUND, B::Space) => Action::Space,
+        (GROUND, B::Del) => Action::Del,
+        (GROUND, _) => Action::Print,
+
+        (ESCAPE, B::Space | B::Intermediate) => Action::EscIntermediate,
+        (ESCAPE, B::Param) => Action::EscFp,
+        (ESCAPE, B::Upper | B::Backslash) => Action::EscC1,
+        (ESCAPE, B::Lower) => Action::EscFs,
+
+        (NF, B::Space | B::Intermediate) => Action::NfCollect,
+        (NF, B::Param | B::Upper | B::Backslash | B::Lower) => Action::NfFinal,
+
+        (CSI_P, B::Param) => Action::CsiParam,
+        (CSI_P, B::Space | B::Intermediate) => Action::CsiEnterIntermediate,
+        (CSI_I, B::Param) => Action::CsiInvalidSequence,
+        (CSI_I, B::Space | B::Intermediate) => Action::CsiIntermediate,
+        (CSI_P | CSI_I, B::Upper | B::Backslash | B::Lower) => Action::CsiFinal,
+
+        (CSI_IGNORE, B::Upper | B::Backslash | B::Lower) => Action::IgnoreFinal,
+
+        _ => fallback(state),
+    }
+}
+
+const fn transitions(bit8: bool, pass_c0: bool) -> [[Action; CLASS_COUNT]; STATE_COUNT] {
+    let mut table = [[Action::Ignore; CLASS_COUNT]; STATE_COUNT];
+    let mut state = 0;
+    while state < STATE_COUNT {
+        let mut byte = 0;
+        // every class has a representative among the first 0xA1 bytes
+        while byte <= 0xA0 {
+            let class = classify(byte);
+            table[state][class as usize] = action(state, class, bit8, pass_c0);
+            byte += 1;
+        }
+        state += 1;
+    }
+    table
+}
+
+const fn classes() -> [ByteClass; 256] {
+    let mut classes = [ByteClass::High; 256];
+    let mut byte = 0;
+    while byte < 256 {
+        classes[byte] = classify(byte as u8);
+        byte += 1;
+    }
+    classes
+}
+
+static CLASSES: [ByteClass; 256] = classes();
+
+/// Indexed by [`Config::bit8_enabled`] and [`Config::csi_pass_through_c0`], the only
+/// settings which change the transitions of more than one state
+///
+/// [`Config::bit8_enabled`]: super::Config::bit8_enabled
+/// [`Config::csi_pass_through_c0`]: super::Config::csi_pass_through_c0
+static TRANSITIONS: [[[Action; CLASS_COUNT]; STATE_COUNT]; 4] = [
+    transitions(false, false),
+    transitions(true, false),
+    transitions(false, true),
+    transitions(true, true),
+];
+
+impl State {
+    #[inline(always)]
+    const fn index(self) -> usize {
+        match self {
+            State::Ground => GROUND,
+            State::Escape => ESCAPE,
+            State::CsiP => CSI_P,
+            State::CsiI => CSI_I,
+            State::CsiIgnore(_) => CSI_IGNORE,
+            State::String(..) => STRING,
+            State::StringEscape(..) => STRING_ESCAPE,
+            State::Nf(_) => NF,
+        }
+    }
+}
+
+/// Looks up what to do with `byte` in `state`
+#[inline(always)]
+pub(super) fn transition(state: State, byte: u8, bit8: bool, pass_c0: bool) -> Action {
+    let table = &TRANSITIONS[usize::from(bit8) | usize::from(pass_c0) << 1];
+    table[state.index() & (STATE_COUNT - 1)][CLASSES[byte as usize] as usize & (CLASS_COUNT - 1)]
+}
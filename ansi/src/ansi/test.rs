@@ -8,27 +8,27 @@ fn csi() {
         for i in 0x20..=0x2F {
             for f in 0x40..=0x7E {
                 parser.cfg.csi_silent_intermediate_overflow = false;
-                parser.cfg.csi_silent_sequence_overflow = false;
+                parser.cfg.csi_overflow = CsiOverflow::Reject;
 
                 assert_eq!(parser.next(0x1b), crate::Out::None);
                 assert_eq!(parser.next(b'['), crate::Out::None);
                 assert_eq!(parser.next(p), crate::Out::None);
                 assert_eq!(parser.next(i), crate::Out::None);
-                assert_eq!(parser.next(f), Out::CSI(CSI([p, i, f][..].into())));
+                assert_eq!(parser.next(f), Out::CSI(CSI::new([p, i, f][..].into())));
 
                 assert_eq!(parser.next(0x1b), crate::Out::None);
                 assert_eq!(parser.next(b'['), crate::Out::None);
                 assert_eq!(parser.next(p), crate::Out::None);
-                assert_eq!(parser.next(f), Out::CSI(CSI([p, f][..].into())));
+                assert_eq!(parser.next(f), Out::CSI(CSI::new([p, f][..].into())));
 
                 assert_eq!(parser.next(0x1b), crate::Out::None);
                 assert_eq!(parser.next(b'['), crate::Out::None);
                 assert_eq!(parser.next(i), crate::Out::None);
-                assert_eq!(parser.next(f), Out::CSI(CSI([i, f][..].into())));
+                assert_eq!(parser.next(f), Out::CSI(CSI::new([i, f][..].into())));
 
                 assert_eq!(parser.next(0x1b), crate::Out::None);
                 assert_eq!(parser.next(b'['), crate::Out::None);
-                assert_eq!(parser.next(f), Out::CSI(CSI([f][..].into())));
+                assert_eq!(parser.next(f), Out::CSI(CSI::new([f][..].into())));
 
                 assert_eq!(parser.next(0x1b), crate::Out::None);
                 assert_eq!(parser.next(b'['), crate::Out::None);
@@ -49,7 +49,10 @@ fn csi() {
                 assert_eq!(parser.next(i), crate::Out::None);
                 assert_eq!(parser.next(i), crate::Out::None);
                 assert_eq!(parser.next(i), crate::Out::None);
-                assert_eq!(parser.next(f), Out::CSI(CSI([i, i, i, i, f][..].into())));
+                assert_eq!(
+                    parser.next(f),
+                    Out::CSI(CSI::new([i, i, i, i, f][..].into()))
+                );
 
                 assert_eq!(parser.next(0x1b), crate::Out::None);
                 assert_eq!(parser.next(b'['), crate::Out::None);
@@ -94,9 +97,18 @@ fn csi() {
             }
         }
     }
+}
 
+/// The default [`CsiOverflow::KeepFirst`] drops whole parameters and reports the
+/// loss, these sequences used to be cut byte by byte and come back as `Out::CSI`.
+/// Where not a single parameter fits the sequence is rejected.
+#[test]
+fn csi_overflow_default() {
+    use crate::*;
+    let mut parser = crate::SizedAnsiParser::<12>::new();
+    parser.cfg.max_immediate_count = 4;
+    assert_eq!(parser.cfg.csi_overflow, CsiOverflow::KeepFirst);
     parser.cfg.csi_silent_intermediate_overflow = true;
-    parser.cfg.csi_silent_sequence_overflow = true;
 
     assert_eq!(parser.next(0x1b), crate::Out::None);
     assert_eq!(parser.next(b'['), crate::Out::None);
@@ -108,7 +120,10 @@ fn csi() {
     assert_eq!(parser.next(0x25), crate::Out::None);
     assert_eq!(
         parser.next(0x40),
-        Out::CSI(CSI([0x20, 0x21, 0x22, 0x23, 0x40][..].into()))
+        Out::CSITruncated {
+            csi: CSI::new([0x20, 0x21, 0x22, 0x23, 0x40][..].into()),
+            dropped: 0,
+        }
     );
 
     assert_eq!(parser.next(0x1b), crate::Out::None);
@@ -122,13 +137,7 @@ fn csi() {
     assert_eq!(parser.next(0x23), crate::Out::None);
     assert_eq!(parser.next(0x24), crate::Out::None);
     assert_eq!(parser.next(0x25), crate::Out::None);
-    assert_eq!(
-        parser.next(0x40),
-        Out::CSI(CSI([
-            0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x20, 0x21, 0x22, 0x23, 0x40
-        ][..]
-            .into()))
-    );
+    assert_eq!(parser.next(0x40), Out::CSISequenceTooLarge);
 
     parser.cfg.max_immediate_count = 12;
     assert_eq!(parser.next(0x1b), crate::Out::None);
@@ -139,13 +148,7 @@ fn csi() {
     for i in 0x20..0x2F {
         assert_eq!(parser.next(i), crate::Out::None);
     }
-    assert_eq!(
-        parser.next(0x40),
-        Out::CSI(CSI([
-            0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2A, 0x40
-        ][..]
-            .into()))
-    );
+    assert_eq!(parser.next(0x40), Out::CSISequenceTooLarge);
 
     assert_eq!(parser.next(0x1b), crate::Out::None);
     assert_eq!(parser.next(b'['), crate::Out::None);
@@ -155,92 +158,760 @@ fn csi() {
     for i in 0x20..0x2F {
         assert_eq!(parser.next(i), crate::Out::None);
     }
+    assert_eq!(parser.next(0x40), Out::CSISequenceTooLarge);
+}
+
+#[test]
+fn csi_overflow() {
+    use crate::*;
+    let mut parser = SizedAnsiParser::<8>::new();
+    parser.cfg.max_immediate_count = 2;
+
+    let mut check = |policy, input: &[u8], expected: Out<'_>| {
+        parser.cfg.csi_overflow = policy;
+        assert_eq!(parser.feed(input).out, expected, "{policy:?}");
+    };
+
+    check(
+        CsiOverflow::Reject,
+        b"\x1b[1;22;333m",
+        Out::CSISequenceTooLarge,
+    );
+    check(
+        CsiOverflow::Reject,
+        b"\x1b[?1;22;3 q",
+        Out::CSISequenceTooLarge,
+    );
+    check(
+        CsiOverflow::KeepFirst,
+        b"\x1b[1;2;3;4m",
+        Out::CSI(CSI::new(b"1;2;3;4m"[..].into())),
+    );
+    check(
+        CsiOverflow::KeepLast,
+        b"\x1b[1;2;3;4m",
+        Out::CSI(CSI::new(b"1;2;3;4m"[..].into())),
+    );
+
+    check(
+        CsiOverflow::KeepFirst,
+        b"\x1b[1;22;333m",
+        Out::CSITruncated {
+            csi: CSI::new(b"1;22m"[..].into()),
+            dropped: 1,
+        },
+    );
+    check(
+        CsiOverflow::KeepFirst,
+        b"\x1b[1;2;3;4;5m",
+        Out::CSITruncated {
+            csi: CSI::new(b"1;2;3;4m"[..].into()),
+            dropped: 1,
+        },
+    );
+    check(
+        CsiOverflow::KeepFirst,
+        b"\x1b[?1;22;3 q",
+        Out::CSITruncated {
+            csi: CSI::new(b"?1;22 q"[..].into()),
+            dropped: 1,
+        },
+    );
+    // an empty parameter list would mean the defaults, `CSI m` is a reset
+    check(
+        CsiOverflow::KeepFirst,
+        b"\x1b[123456789m",
+        Out::CSISequenceTooLarge,
+    );
+
+    check(
+        CsiOverflow::KeepLast,
+        b"\x1b[1;22;333m",
+        Out::CSITruncated {
+            csi: CSI::new(b"22;333m"[..].into()),
+            dropped: 1,
+        },
+    );
+    check(
+        CsiOverflow::KeepLast,
+        b"\x1b[1;2:3;4;5;6m",
+        Out::CSITruncated {
+            csi: CSI::new(b"4;5;6m"[..].into()),
+            dropped: 2,
+        },
+    );
+    check(
+        CsiOverflow::KeepLast,
+        b"\x1b[?1;22;3 q",
+        Out::CSITruncated {
+            csi: CSI::new(b"?22;3 q"[..].into()),
+            dropped: 1,
+        },
+    );
+    check(
+        CsiOverflow::KeepLast,
+        b"\x1b[123456789;1m",
+        Out::CSITruncated {
+            csi: CSI::new(b"1m"[..].into()),
+            dropped: 1,
+        },
+    );
+    check(
+        CsiOverflow::KeepLast,
+        b"\x1b[?123456789m",
+        Out::CSISequenceTooLarge,
+    );
+    // whole parameters survive but the color is gone
+    check(
+        CsiOverflow::KeepLast,
+        b"\x1b[38;2;255;255;255m",
+        Out::CSITruncated {
+            csi: CSI::new(b"255;255m"[..].into()),
+            dropped: 3,
+        },
+    );
+
+    // dropped intermediates are reported as well
+    check(
+        CsiOverflow::KeepFirst,
+        b"\x1b[1   m",
+        Out::CSITruncated {
+            csi: CSI::new(b"1  m"[..].into()),
+            dropped: 0,
+        },
+    );
+}
+
+#[test]
+fn flush_pending() {
+    use crate::*;
+    let mut parser = SizedAnsiParser::<16>::new();
+
+    assert_eq!(parser.flush_pending(), Out::None);
+    assert_eq!(parser.feed(b"\x1b").out, Out::None);
+    assert_eq!(parser.flush_pending(), Out::ESC);
+    assert_eq!(parser.flush_pending(), Out::None);
+    assert_eq!(parser.feed(b"[").out, Out::Data('[' as FfiChar));
+
+    assert_eq!(parser.feed(b"\x1b[").out, Out::None);
+    assert_eq!(parser.flush_pending(), Out::Alt(b'['));
+    assert_eq!(parser.feed(b"A").out, Out::Data('A' as FfiChar));
+
+    assert_eq!(parser.feed(b"\x1bO").out, Out::C1(C1::SS3));
+    assert_eq!(parser.flush_pending(), Out::Alt(b'O'));
+    assert_eq!(parser.flush_pending(), Out::None);
+
+    // the Alt stands in for the SS3 and does not write `ESC O` a second time
+    let mut bytes = std::vec::Vec::new();
+    parser
+        .feed(b"\x1bO")
+        .out
+        .write_bytes(&mut bytes, ControlForm::SevenBit)
+        .unwrap();
+    parser
+        .flush_pending()
+        .write_bytes(&mut bytes, ControlForm::SevenBit)
+        .unwrap();
+    assert_eq!(bytes, b"\x1bO");
+    assert_eq!(parser.feed(b"\x1bOA").out, Out::C1(C1::SS3));
+    assert_eq!(parser.feed(b"A").out, Out::Data('A' as FfiChar));
+    assert_eq!(parser.flush_pending(), Out::None);
+
+    // only a lone prefix is resolved
+    assert_eq!(parser.feed(b"\x1b[1").out, Out::None);
+    assert_eq!(parser.flush_pending(), Out::None);
+    assert_eq!(parser.feed(b"A").out, Out::CSI(CSI::new(b"1A"[..].into())));
+    assert_eq!(parser.feed(b"\x1b]0;").out, Out::C1(C1::OSC));
+    assert_eq!(parser.flush_pending(), Out::None);
     assert_eq!(
-        parser.next(0x40),
-        Out::CSI(CSI([
-            0x3E, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x40
-        ][..]
-            .into()))
+        parser.feed(b"\x07").out,
+        Out::StringEnd {
+            kind: StringKind::Os,
+            terminator: StringTerminator::BEL
+        }
     );
+
+    assert_eq!(parser.feed(b"\xe4\xb8").out, Out::None);
+    assert_eq!(parser.flush_pending(), Out::InvalidUtf8Sequence);
+    assert_eq!(parser.feed(b"a").out, Out::Data('a' as FfiChar));
+
+    parser.cfg.utf8 = false;
+    parser.cfg.bit8_enabled = true;
+    assert_eq!(parser.feed(b"\x9b").out, Out::None);
+    assert_eq!(parser.flush_pending(), Out::None);
+    assert_eq!(parser.feed(b"m").out, Out::CSI(CSI::new(b"m"[..].into())));
+    assert_eq!(parser.feed(b"\x8f").out, Out::C1(C1::SS3));
+    assert_eq!(parser.flush_pending(), Out::None);
 }
 
 #[test]
-pub fn utf8() {
+fn spans() {
+    use crate::*;
+
+    fn spans(parser: &mut UnsizedSpanParser, mut input: &[u8]) -> std::vec::Vec<(u64, u64)> {
+        let mut spans = std::vec::Vec::new();
+        while !input.is_empty() {
+            let SpannedFeed {
+                consumed,
+                span,
+                out,
+            } = parser.feed(input);
+            input = &input[consumed..];
+            if out != Out::None {
+                spans.push((span.start, span.end));
+            }
+        }
+        spans
+    }
+
+    let parser: &mut UnsizedSpanParser = &mut SizedSpanParser::<16>::new();
+    assert_eq!(
+        spans(parser, "a\x1b[1;2mé\x1b(0中\n".as_bytes()),
+        [(0, 1), (1, 7), (7, 9), (9, 12), (12, 15), (15, 16)]
+    );
+    assert_eq!(parser.offset(), 16);
+
+    // sequences are split across chunks and C0 controls pass through
+    assert_eq!(spans(parser, b"\x1b["), []);
+    assert_eq!(spans(parser, b"1\n2"), [(19, 20)]);
+    assert_eq!(spans(parser, b"m\xe4\xb8"), [(16, 22)]);
+    assert_eq!(spans(parser, b"\xadx"), [(22, 25), (25, 26)]);
+
+    // a broken UTF-8 sequence ends before the byte which broke it
+    assert_eq!(spans(parser, b"\xe4\xb8\x1b7"), [(26, 28), (28, 30)]);
+
+    assert_eq!(
+        spans(parser, b"\x1bP1$q\x1b\\\x1b]0;\x18"),
+        [
+            (30, 32),
+            (32, 33),
+            (33, 34),
+            (34, 35),
+            (35, 37),
+            (37, 39),
+            (39, 40),
+            (40, 41),
+            (41, 42)
+        ]
+    );
+
+    parser.parser.cfg.buffer_strings = true;
+    assert_eq!(spans(parser, b"\x1b]0;title\x07"), [(42, 44), (44, 52)]);
+
+    parser.parser.cfg.text_runs = true;
+    assert_eq!(spans(parser, "ab中\r".as_bytes()), [(52, 57), (57, 58)]);
+}
+
+/// Parses `input` and writes every event back out
+fn reencode(
+    parser: &mut crate::UnsizedAnsiParser,
+    form: crate::ControlForm,
+    mut input: &[u8],
+) -> std::vec::Vec<u8> {
+    let mut sink = std::vec::Vec::new();
+    while !input.is_empty() {
+        let crate::Feed { consumed, out } = parser.feed(input);
+        input = &input[consumed..];
+        out.write_bytes(&mut sink, form).unwrap();
+    }
+    sink
+}
+
+#[test]
+fn encode() {
+    use crate::*;
+
+    let mut string = std::string::String::new();
+    Out::CSI(CSI::new(b"?25h"[..].into()))
+        .write_to(&mut string, ControlForm::SevenBit)
+        .unwrap();
+    Out::Data('é' as FfiChar)
+        .write_to(&mut string, ControlForm::SevenBit)
+        .unwrap();
+    C1::OSC
+        .write_to(&mut string, ControlForm::EightBit)
+        .unwrap();
+    Fs::RIS.write_to(&mut string).unwrap();
+    C0::LF.write_to(&mut string).unwrap();
+    assert_eq!(string, "\x1b[?25hé\u{9d}\x1bc\n");
+
+    let mut buf = [0; 8];
+    let mut sink = &mut buf[..];
+    Out::CSI(CSI::new(b"1;2m"[..].into()))
+        .write_bytes(&mut sink, ControlForm::EightBit)
+        .unwrap();
+    assert_eq!(sink.len(), 3);
+    assert_eq!(
+        Out::CSI(CSI::new(b"0m"[..].into())).write_bytes(&mut sink, ControlForm::SevenBit),
+        Err(BufferFull)
+    );
+    assert_eq!(buf[..5], *b"\x9b1;2m");
+
+    // designations keep the alias they were written with and buffered strings their terminator
+    let parser: &mut UnsizedAnsiParser = &mut SizedAnsiParser::<64>::new();
+    for input in [&b"\x1b(7"[..], b"\x1b(H", b"\x1b)%5", b"\x1b-A"] {
+        assert_eq!(reencode(parser, ControlForm::SevenBit, input), input);
+    }
+    parser.cfg.buffer_strings = true;
+    for input in [
+        &b"\x1b]0;title\x07"[..],
+        b"\x1b]0;title\x1b\\",
+        b"\x1bP1$r0m\x1b\\",
+    ] {
+        assert_eq!(reencode(parser, ControlForm::SevenBit, input), input);
+    }
+
+    // pass through controls keep their event but move in front of the sequence
+    parser.cfg.csi_pass_through_c0 = true;
+    assert_eq!(
+        reencode(parser, ControlForm::SevenBit, b"\x1b[1\n2m"),
+        b"\n\x1b[12m"
+    );
+}
+
+#[test]
+fn encode_round_trip() {
     use crate::*;
 
-    #[allow(clippy::identity_op)]
-    #[allow(clippy::precedence)]
-    #[allow(clippy::erasing_op)]
-    fn encode(c: u32, vec: &mut std::vec::Vec<u8>) {
-        let start = vec.len();
-        if c < 0x80 {
-            return vec.insert(start, c as u8);
+    struct Rng(u64);
+    impl Rng {
+        fn next(&mut self, bound: u32) -> u32 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % bound as u64) as u32
+        }
+        fn byte(&mut self, range: core::ops::RangeInclusive<u8>) -> u8 {
+            range.start() + self.next((range.end() - range.start()) as u32 + 1) as u8
+        }
+        fn char(&mut self, input: &mut std::vec::Vec<u8>) {
+            let c = match self.next(3) {
+                0 => self.byte(0x20..=0x7E) as char,
+                1 => char::from_u32(self.next(0x800 - 0xA0) + 0xA0).unwrap(),
+                _ => char::from_u32(self.next(0x10FFFF))
+                    .filter(|c| *c as u32 >= 0xA0)
+                    .unwrap_or('中'),
+            };
+            input.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+        }
+    }
+
+    /// Buffered strings are only generated well-formed, an abort drops their payload and
+    /// controls in their body are reported ahead of it. Their bodies are not decoded, so
+    /// with 8-bit controls they stay ASCII.
+    fn generate(rng: &mut Rng, form: ControlForm, buffered: bool) -> std::vec::Vec<u8> {
+        let c1 = |input: &mut std::vec::Vec<u8>, c1: u8| match form {
+            ControlForm::SevenBit => input.extend_from_slice(&[0x1B, c1]),
+            ControlForm::EightBit => input.push(c1 + 0x40),
+        };
+        let mut input = std::vec::Vec::new();
+        for _ in 0..64 {
+            match rng.next(10) {
+                0..=2 => rng.char(&mut input),
+                3 => input.push(match rng.byte(0x00..=0x1F) {
+                    0x1B => 0x7F,
+                    byte => byte,
+                }),
+                4 => {
+                    c1(&mut input, b'[');
+                    if rng.next(2) == 0 {
+                        input.push(rng.byte(b'<'..=b'?'));
+                    }
+                    for _ in 0..rng.next(12) {
+                        input.push(rng.byte(b'0'..=b';'));
+                    }
+                    for _ in 0..rng.next(3) {
+                        input.push(rng.byte(0x20..=0x2F));
+                    }
+                    input.push(rng.byte(0x40..=0x7E));
+                }
+                5 => {
+                    input.push(0x1B);
+                    for _ in 0..=rng.next(2) {
+                        input.push(rng.byte(0x20..=0x2F));
+                    }
+                    input.push(rng.byte(0x30..=0x7E));
+                }
+                6 => {
+                    // designations including every alias
+                    let finals: [&[u8]; 22] = [
+                        b"B", b"A", b"0", b"<", b"%5", b"4", b"C", b"5", b"R", b"f", b"Q", b"9",
+                        b"K", b"Y", b"E", b"6", b"`", b"%6", b"Z", b"H", b"7", b"=",
+                    ];
+                    input
+                        .extend_from_slice(&[0x1B, *b"()*+-./".get(rng.next(7) as usize).unwrap()]);
+                    input.extend_from_slice(finals[rng.next(22) as usize]);
+                }
+                7 => {
+                    let byte = match rng.next(3) {
+                        0 => rng.byte(0x30..=0x3F),
+                        1 => rng.byte(0x60..=0x7E),
+                        _ => *b"@ABCDEFGHIJKLMNOQRSTUVWYZ\\"
+                            .get(rng.next(26) as usize)
+                            .unwrap(),
+                    };
+                    if (0x40..0x60).contains(&byte) {
+                        c1(&mut input, byte);
+                    } else {
+                        input.extend_from_slice(&[0x1B, byte]);
+                    }
+                }
+                _ => {
+                    let kind = *b"PX]^_".get(rng.next(5) as usize).unwrap();
+                    c1(&mut input, kind);
+                    for _ in 0..rng.next(16) {
+                        match rng.next(8) {
+                            0 if !buffered => input.push(rng.byte(0x08..=0x0D)),
+                            _ if buffered && form == ControlForm::EightBit => {
+                                input.push(rng.byte(0x20..=0x7E))
+                            }
+                            _ => rng.char(&mut input),
+                        }
+                    }
+                    match rng.next(6) {
+                        0 if buffered && kind == b']' => input.push(0x07),
+                        _ if buffered => c1(&mut input, b'\\'),
+                        0 if kind == b']' => input.push(0x07),
+                        1 => input.push(0x18),
+                        2 => input.push(0x1A),
+                        // another sequence cuts the string off
+                        3 => c1(&mut input, b'D'),
+                        4 => input.extend_from_slice(&[0x1B, b'7']),
+                        _ => c1(&mut input, b'\\'),
+                    }
+                }
+            }
+        }
+        input
+    }
+
+    let mut rng = Rng(0x2545F4914F6CDD1D);
+    let parser: &mut UnsizedAnsiParser = &mut SizedAnsiParser::<128>::new();
+    for _ in 0..2000 {
+        for form in [ControlForm::SevenBit, ControlForm::EightBit] {
+            for buffered in [false, true] {
+                let input = generate(&mut rng, form, buffered);
+                for text_runs in [false, true] {
+                    parser.cfg.bit8_enabled = form == ControlForm::EightBit;
+                    parser.cfg.buffer_strings = buffered;
+                    // neither changes the bytes, so they share a pass
+                    parser.cfg.designations = text_runs;
+                    parser.cfg.text_runs = text_runs;
+                    assert_eq!(reencode(parser, form, &input), input, "{:x?}", input);
+                }
+            }
         }
+    }
+}
+
+#[test]
+fn reader() {
+    use crate::*;
 
-        vec.insert(start, (((c >> 6 * 0) as u8) & 0b111111) | 0b10000000);
-        if c < 0x800 {
-            return vec.insert(start, ((c >> 6 * 1) as u8 & 0b11111) | 0b11000000);
+    /// Hands out a few bytes at a time, interrupting every other read
+    struct Trickle<'a>(&'a [u8], bool);
+    impl std::io::Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.1 = !self.1;
+            if self.1 {
+                return Err(std::io::ErrorKind::Interrupted.into());
+            }
+            let len = self.0.len().min(buf.len()).min(3);
+            buf[..len].copy_from_slice(&self.0[..len]);
+            self.0 = &self.0[len..];
+            Ok(len)
         }
-        vec.insert(start, (((c >> 6 * 1) as u8) & 0b111111) | 0b10000000);
-        if c < 10000 {
-            return vec.insert(start, ((c >> 6 * 2) as u8 & 0b1111) | 0b11100000);
+    }
+
+    let input = "a\x1b[1;31mé\x1b(0\x1b]0;title\x07\x1b#8\x1b[0m".as_bytes();
+    let mut reader = AnsiReader::<_, 32>::new(Trickle(input, false));
+    reader.parser.cfg.buffer_strings = true;
+    reader.parser.cfg.designations = true;
+    let events = reader
+        .collect::<std::io::Result<std::vec::Vec<OwnedOut>>>()
+        .unwrap();
+
+    let events = std::thread::spawn(move || events).join().unwrap();
+    assert_eq!(
+        events,
+        [
+            OwnedOut::Data('a' as FfiChar),
+            OwnedOut::CSI(b"1;31m".to_vec()),
+            OwnedOut::Data('é' as FfiChar),
+            OwnedOut::Designate {
+                slot: CharsetSlot::G0,
+                charset: Charset::DecSpecialGraphics,
+                bytes: b"(0".to_vec(),
+            },
+            OwnedOut::C1(C1::OSC),
+            OwnedOut::OSC {
+                payload: b"0;title".to_vec(),
+                terminator: StringTerminator::BEL,
+            },
+            OwnedOut::nF(b"#8".to_vec()),
+            OwnedOut::CSI(b"0m".to_vec()),
+        ]
+    );
+    assert_eq!(events[1].as_out(), Out::CSI(CSI::new(b"1;31m"[..].into())));
+
+    let mut reader = AnsiReader::<_, 8>::with_capacity(&b"\xff"[..], 1);
+    assert_eq!(
+        reader.next().unwrap().unwrap(),
+        OwnedOut::InvalidUtf8Sequence
+    );
+    assert!(reader.next().is_none());
+
+    // input still pending at the end is flushed, a trailing ESC is the escape key
+    let mut reader = AnsiReader::<_, 8>::new(&b"q\x1b"[..]);
+    assert_eq!(
+        reader.next().unwrap().unwrap(),
+        OwnedOut::Data('q' as FfiChar)
+    );
+    assert_eq!(reader.next().unwrap().unwrap(), OwnedOut::ESC);
+    assert!(reader.next().is_none());
+
+    let mut reader = AnsiReader::<_, 8>::new(Trickle(b"\x1b[", false));
+    assert_eq!(reader.next().unwrap().unwrap(), OwnedOut::Alt(b'['));
+    assert!(reader.next().is_none());
+
+    let mut reader = AnsiReader::<_, 8>::new(&b"\xc3"[..]);
+    assert_eq!(
+        reader.next().unwrap().unwrap(),
+        OwnedOut::InvalidUtf8Sequence
+    );
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn growable_buffer() {
+    use crate::*;
+
+    let mut parser = VecAnsiParser::with_limit(1 << 16);
+    parser.cfg.buffer_strings = true;
+    assert_eq!(parser.buffer().capacity(), 0);
+
+    let mut osc = b"\x1b]52;c;".to_vec();
+    osc.extend(core::iter::repeat_n(b'A', 40000));
+    osc.extend_from_slice(b"\x1b\\");
+    let Feed { consumed, out } = parser.feed(&osc);
+    assert_eq!((consumed, out), (2, Out::C1(C1::OSC)));
+    let Feed { consumed, out } = parser.feed(&osc[consumed..]);
+    assert_eq!(consumed, osc.len() - 2);
+    assert_eq!(
+        out,
+        Out::OSC {
+            payload: osc[2..osc.len() - 2].into(),
+            terminator: StringTerminator::ST
         }
-        vec.insert(start, (((c >> 6 * 2) as u8) & 0b111111) | 0b10000000);
-        if c < 200000 {
-            return vec.insert(start, ((c >> 6 * 3) as u8 & 0b111) | 0b11110000);
+    );
+    assert!(parser.buffer().capacity() >= 40005);
+
+    // the hard limit still overflows
+    let mut osc = b"\x1b]52;c;".to_vec();
+    osc.extend(core::iter::repeat_n(b'A', 1 << 16));
+    osc.extend_from_slice(b"\x1b\\");
+    let mut input = &osc[..];
+    let mut events = std::vec::Vec::new();
+    while !input.is_empty() {
+        let Feed { consumed, out } = parser.feed(input);
+        input = &input[consumed..];
+        events.push(OwnedOut::from(out));
+    }
+    assert!(events.contains(&OwnedOut::StringSequenceTooLarge));
+    assert_eq!(parser.buffer().capacity(), 1 << 16);
+
+    parser.buffer_mut().shrink_to(64);
+    assert_eq!(parser.buffer().capacity(), 64);
+    assert_eq!(
+        parser
+            .feed(b"\x1b[38;2;1;2;3;4;5;6;7;8;9;10;11;12;13;14;15;16m")
+            .out,
+        Out::CSI(CSI::new(
+            b"38;2;1;2;3;4;5;6;7;8;9;10;11;12;13;14;15;16m"[..].into()
+        ))
+    );
+
+    let mut parser = VecAnsiParser::with_limit(4);
+    assert_eq!(
+        parser.feed(b"\x1b[1;2;3m").out,
+        Out::CSITruncated {
+            csi: CSI::new(b"1;2m"[..].into()),
+            dropped: 1,
         }
-        vec.insert(start, (((c >> 6 * 3) as u8) & 0b111111) | 0b10000000);
-        if c < 4000000 {
-            return vec.insert(start, ((c >> 6 * 4) as u8 & 0b11) | 0b11111000);
+    );
+}
+
+fn owned_events<T: crate::ParserBuffer + ?Sized>(
+    parser: &mut crate::AnsiParser<T>,
+    mut input: &[u8],
+) -> std::vec::Vec<crate::OwnedOut> {
+    let mut events = std::vec::Vec::new();
+    while !input.is_empty() {
+        let crate::Feed { consumed, out } = parser.feed(input);
+        input = &input[consumed..];
+        events.push(out.into());
+    }
+    events
+}
+
+#[test]
+fn snapshot() {
+    use crate::*;
+
+    let input = "a\x1b[38;2;1;2;3m\x1b]0;tïtle\x07é😀\x1bP1$r\x1b\\\x1b(0\x1bO".as_bytes();
+    for split in 0..=input.len() {
+        let mut reference = SizedAnsiParser::<64>::new();
+        reference.cfg.buffer_strings = true;
+        owned_events::<[u8]>(&mut reference, &input[..split]);
+        let expected = owned_events::<[u8]>(&mut reference, &input[split..]);
+
+        let mut parser = SizedAnsiParser::<64>::new();
+        parser.cfg.buffer_strings = true;
+        owned_events::<[u8]>(&mut parser, &input[..split]);
+        let snapshot = parser.snapshot();
+        assert_eq!(snapshot.as_bytes().len(), parser.snapshot_len());
+
+        let mut resumed = SizedAnsiParser::<64>::new();
+        resumed.cfg.buffer_strings = true;
+        resumed.restore(snapshot.as_bytes()).unwrap();
+        assert_eq!(resumed.snapshot(), snapshot);
+        assert_eq!(
+            owned_events::<[u8]>(&mut resumed, &input[split..]),
+            expected
+        );
+
+        let mut resumed = VecAnsiParser::with_limit(64);
+        resumed.cfg.buffer_strings = true;
+        resumed.restore(snapshot.as_bytes()).unwrap();
+        assert_eq!(owned_events(&mut resumed, &input[split..]), expected);
+    }
+
+    let mut parser = SizedAnsiParser::<64>::new();
+    parser.feed(b"\x1b[1;2;3;4");
+    let bytes = parser.snapshot().into_bytes();
+    let mut slice = &mut [0; 64][..];
+    parser.write_snapshot(&mut slice).unwrap();
+    assert_eq!(slice.len(), 64 - bytes.len());
+    assert_eq!(parser.write_snapshot(&mut &mut [0; 8][..]), Err(BufferFull));
+
+    // corrupted snapshots are rejected and leave the parser untouched
+    let mut target = SizedAnsiParser::<64>::new();
+    target.feed(b"\x1b]2;");
+    let before = target.snapshot();
+    for i in 0..bytes.len() {
+        for bit in 0..8 {
+            let mut corrupted = bytes.clone();
+            corrupted[i] ^= 1 << bit;
+            assert!(target.restore(&corrupted).is_err());
+            assert!(Snapshot::try_from(corrupted).is_err());
         }
-        vec.insert(start, (((c >> 6 * 4) as u8) & 0b111111) | 0b10000000);
-        vec.insert(start, ((c >> 6 * 5) as u8 & 0b1) | 0b11111100);
+        assert!(target.restore(&bytes[..i]).is_err());
+    }
+    assert_eq!(target.snapshot(), before);
+
+    let mut extended = bytes.clone();
+    extended.push(0);
+    assert!(target.restore(&extended).is_err());
+    let mut corrupted = bytes.clone();
+    corrupted[0] = b'X';
+    assert_eq!(target.restore(&corrupted), Err(SnapshotError::Magic));
+    corrupted = bytes.clone();
+    corrupted[4] = 2;
+    assert_eq!(target.restore(&corrupted), Err(SnapshotError::Version(2)));
+    corrupted = bytes.clone();
+    corrupted[20] ^= 1;
+    assert_eq!(target.restore(&corrupted), Err(SnapshotError::Checksum));
+
+    // a well formed snapshot of an impossible state
+    let rehash = |bytes: &mut std::vec::Vec<u8>| {
+        let end = bytes.len() - 4;
+        let hash = bytes[..end].iter().fold(0x811C_9DC5u32, |hash, byte| {
+            (hash ^ *byte as u32).wrapping_mul(0x0100_0193)
+        });
+        bytes[end..].copy_from_slice(&hash.to_le_bytes());
+    };
+    for (at, value) in [(5, 8), (6, 1), (7, 0x28), (8, 4), (9, 0x7F), (11, b'x')] {
+        let mut invalid = bytes.clone();
+        invalid[at] = value;
+        rehash(&mut invalid);
+        assert_eq!(
+            target.restore(&invalid),
+            Err(SnapshotError::Invalid),
+            "{at}"
+        );
+    }
+    assert_eq!(target.snapshot(), before);
+
+    assert_eq!(
+        SizedAnsiParser::<4>::new().restore(&bytes),
+        Err(SnapshotError::BufferTooSmall)
+    );
+    assert_eq!(
+        VecAnsiParser::with_limit(4).restore(&bytes),
+        Err(SnapshotError::BufferTooSmall)
+    );
+    target.restore(&bytes).unwrap();
+    assert_eq!(
+        target.feed(b";5m").out,
+        Out::CSI(CSI::new(b"1;2;3;4;5m"[..].into()))
+    );
+
+    #[cfg(feature = "serde")]
+    {
+        use serde::Deserialize;
+        use serde::de::value::{BytesDeserializer, Error};
+
+        let deserialized = Snapshot::deserialize(BytesDeserializer::<Error>::new(&bytes)).unwrap();
+        assert_eq!(deserialized.as_bytes(), &bytes[..]);
+        assert!(Snapshot::deserialize(BytesDeserializer::<Error>::new(&bytes[1..])).is_err());
     }
+}
+
+#[test]
+pub fn utf8() {
+    use crate::*;
 
     let mut ansi = SizedAnsiParser::<256>::new();
     ansi.cfg.utf8 = true;
     ansi.cfg.del_special = false;
     ansi.cfg.space_special = false;
 
-    let mut vec = std::vec::Vec::new();
-    for c in 0x32..=u32::MAX >> 1 {
-        vec.clear();
-        encode(c, &mut vec);
+    let mut buf = [0; 4];
+    for c in (0x32..=char::MAX as u32).filter_map(char::from_u32) {
+        let vec = c.encode_utf8(&mut buf).as_bytes();
 
         ansi.cfg.bit8_enabled = true;
         assert_eq!(ansi.next(0x00), Out::C0(C0::NUL));
         assert_eq!(ansi.next(0x1F), Out::C0(C0::US));
         for (i, b) in vec.iter().copied().enumerate() {
             if i == vec.len() - 1 {
-                if let Some(c) = char::from_u32(c) {
-                    assert_eq!(ansi.next(b), Out::Data(c as FfiChar));
-                } else {
-                    assert_eq!(ansi.next(b), Out::InvalidCodepoint(c));
-                }
+                assert_eq!(ansi.next(b), Out::Data(c as FfiChar));
             } else {
                 assert_eq!(ansi.next(b), Out::None);
             }
         }
         assert_eq!(ansi.next(0x80), Out::C1(C1::PAD));
         assert_eq!(ansi.next(0x9F), Out::C1(C1::APC));
-        assert_eq!(ansi.next(0x9C), Out::C1(C1::ST));
+        assert_eq!(
+            ansi.next(0x9C),
+            Out::StringEnd {
+                kind: StringKind::ApplicationProgramCommand,
+                terminator: StringTerminator::ST
+            }
+        );
 
         ansi.cfg.bit8_enabled = false;
         assert_eq!(ansi.next(0x00), Out::C0(C0::NUL));
         assert_eq!(ansi.next(0x1F), Out::C0(C0::US));
         for (i, b) in vec.iter().copied().enumerate() {
             if i == vec.len() - 1 {
-                if let Some(c) = char::from_u32(c) {
-                    assert_eq!(ansi.next(b), Out::Data(c as FfiChar));
-                } else {
-                    assert_eq!(ansi.next(b), Out::InvalidCodepoint(c));
-                }
+                assert_eq!(ansi.next(b), Out::Data(c as FfiChar));
             } else {
                 assert_eq!(ansi.next(b), Out::None);
             }
         }
-        assert_eq!(ansi.next(0x80), Out::Data(0x80 as FfiChar));
-        assert_eq!(ansi.next(0x9E), Out::Data(0x9E as FfiChar));
+        assert_eq!(ansi.next(0x80), Out::InvalidUtf8Sequence);
+        assert_eq!(ansi.next(0x9E), Out::InvalidUtf8Sequence);
     }
 }
 
@@ -253,32 +924,1415 @@ pub fn invalid_utf8() {
         ansi.cfg.utf8 = true;
         for (i, b) in data.iter().copied().enumerate() {
             if i == data.len() - 1 {
-                assert_eq!(ansi.next(b), Out::InvalidUtf8Sequence);
+                assert_eq!(ansi.next(b), Out::C0(C0::NUL), "{data:x?}");
+                let mut feed = SizedAnsiParser::<0>::new();
+                let (head, tail) = data.split_at(i);
+                assert_eq!(feed.feed(head).out, Out::None, "{data:x?}");
+                assert_eq!(
+                    feed.feed(tail),
+                    Feed {
+                        consumed: 0,
+                        out: Out::InvalidUtf8Sequence
+                    },
+                    "{data:x?}"
+                );
             } else {
-                assert_eq!(ansi.next(b), Out::None);
+                assert_eq!(ansi.next(b), Out::None, "{data:x?}");
+            }
+        }
+    }
+
+    invalid_sequence(&[0b11000010, 0]);
+
+    invalid_sequence(&[0b11100001, 0b10000000, 0]);
+    invalid_sequence(&[0b11100001, 0]);
+
+    invalid_sequence(&[0b11110001, 0b10000000, 0b10000000, 0]);
+    invalid_sequence(&[0b11110001, 0b10000000, 0]);
+    invalid_sequence(&[0b11110001, 0]);
+
+    // bytes which can never appear in UTF-8, including leads of overlongs
+    // and of sequences longer than four bytes
+    let mut ansi = SizedAnsiParser::<0>::new();
+    for b in [0x80, 0xBF, 0xC0, 0xC1, 0xF5, 0xF8, 0xFC, 0xFE, 0xFF] {
+        assert_eq!(ansi.next(b), Out::InvalidUtf8Sequence);
+    }
+
+    // overlongs, surrogates and code points past U+10FFFF break off after the lead byte
+    for seq in [
+        &b"\xE0\x80\xAF"[..],
+        b"\xE0\x9F\xBF",
+        b"\xF0\x8F\xBF\xBF",
+        b"\xED\xA0\x80",
+        b"\xED\xBF\xBF",
+        b"\xF4\x90\x80\x80",
+    ] {
+        let mut events = 0;
+        let mut input = seq;
+        while !input.is_empty() {
+            let Feed { consumed, out } = ansi.feed(input);
+            input = &input[consumed..];
+            assert_eq!(out, Out::InvalidUtf8Sequence, "{seq:x?}");
+            events += 1;
+        }
+        assert_eq!(events, seq.len(), "{seq:x?}");
+    }
+
+    ansi.cfg.utf8_replacement = true;
+    assert_eq!(ansi.next(0xFF), Out::Data('\u{FFFD}' as FfiChar));
+    assert_eq!(ansi.next(0xC3), Out::None);
+    assert_eq!(ansi.next(b'A'), Out::Data('A' as FfiChar));
+    ansi.next(0x1b);
+    ansi.next(b']');
+    assert_eq!(ansi.next(0xC0), Out::OSData('\u{FFFD}' as FfiChar));
+}
+
+/// Decoding with replacement agrees with `String::from_utf8_lossy`,
+/// which implements the maximal subpart practice
+#[test]
+pub fn utf8_maximal_subpart() {
+    use crate::*;
+
+    const BYTES: [u8; 22] = [
+        b'A', 0x80, 0x8F, 0x90, 0x9F, 0xA0, 0xBF, 0xC0, 0xC1, 0xC2, 0xDF, 0xE0, 0xE1, 0xEC, 0xED,
+        0xEF, 0xF0, 0xF1, 0xF3, 0xF4, 0xF5, 0xFF,
+    ];
+
+    let mut ansi = SizedAnsiParser::<0>::new();
+    ansi.cfg.utf8_replacement = true;
+    let mut seq = std::vec::Vec::new();
+    let mut decoded = std::string::String::new();
+    for text_runs in [false, true] {
+        ansi.cfg.text_runs = text_runs;
+        for i in 0..BYTES.len().pow(4) {
+            seq.clear();
+            let mut i = i;
+            for _ in 0..4 {
+                seq.push(BYTES[i % BYTES.len()]);
+                i /= BYTES.len();
             }
+            // flushes a trailing incomplete sequence
+            seq.push(b'\n');
+
+            decoded.clear();
+            let mut input = &seq[..];
+            while !input.is_empty() {
+                let Feed { consumed, out } = ansi.feed(input);
+                input = &input[consumed..];
+                match out {
+                    Out::Data(c) => decoded.push(char::try_from(c).unwrap()),
+                    Out::Text(text) => decoded.push_str(&text),
+                    Out::C0(C0::LF) => decoded.push('\n'),
+                    out => panic!("{out:?}"),
+                }
+            }
+            assert_eq!(
+                decoded,
+                std::string::String::from_utf8_lossy(&seq),
+                "{seq:x?}"
+            );
+        }
+    }
+}
+
+#[test]
+pub fn feed() {
+    use crate::*;
+
+    let mut parser = SizedAnsiParser::<16>::new();
+    let mut input: &[u8] = b"a\x1b[1;2Hb\x1b7\xc3\xa9\x1b[";
+    let mut events = std::vec::Vec::new();
+    while !input.is_empty() {
+        let Feed { consumed, out } = parser.feed(input);
+        events.push((consumed, format!("{out:?}")));
+        input = &input[consumed..];
+    }
+    assert_eq!(
+        events,
+        [
+            (1, format!("{:?}", Out::Data('a' as FfiChar))),
+            (6, format!("{:?}", Out::CSI(CSI::new(b"1;2H"[..].into())))),
+            (1, format!("{:?}", Out::Data('b' as FfiChar))),
+            (2, format!("{:?}", Out::Fp(Fp::DECSC))),
+            (2, format!("{:?}", Out::Data('é' as FfiChar))),
+            (2, format!("{:?}", Out::None)),
+        ]
+    );
+
+    let mut parser = SizedAnsiParser::<16>::new();
+    for bytes in [&b"\x1b[3"[..], b"8;5", b";1m"] {
+        let feed = parser.feed(bytes);
+        assert_eq!(feed.consumed, bytes.len());
+        if bytes == b";1m" {
+            assert_eq!(feed.out, Out::CSI(CSI::new(b"38;5;1m"[..].into())));
+        } else {
+            assert_eq!(feed.out, Out::None);
+        }
+    }
+}
+
+#[test]
+pub fn text_runs() {
+    use crate::*;
+
+    let mut parser = SizedAnsiParser::<16>::new();
+    parser.cfg.text_runs = true;
+    parser.cfg.space_special = false;
+
+    let bytes = [
+        "héllo wörld\r\n\x1b[1mbold\x1b[0m \u{1F600}".as_bytes(),
+        b"\xff!\xe2\x94",
+    ]
+    .concat();
+    let mut input = &bytes[..];
+    let mut events = std::vec::Vec::new();
+    while !input.is_empty() {
+        let Feed { consumed, out } = parser.feed(input);
+        events.push((consumed, format!("{out:?}")));
+        input = &input[consumed..];
+    }
+    assert_eq!(
+        events,
+        [
+            (13, format!("{:?}", Out::Text("héllo wörld".into()))),
+            (1, format!("{:?}", Out::C0(C0::CR))),
+            (1, format!("{:?}", Out::C0(C0::LF))),
+            (4, format!("{:?}", Out::CSI(CSI::new(b"1m"[..].into())))),
+            (4, format!("{:?}", Out::Text("bold".into()))),
+            (4, format!("{:?}", Out::CSI(CSI::new(b"0m"[..].into())))),
+            (5, format!("{:?}", Out::Text(" \u{1F600}".into()))),
+            (1, format!("{:?}", Out::InvalidUtf8Sequence)),
+            (1, format!("{:?}", Out::Text("!".into()))),
+            (2, format!("{:?}", Out::None)),
+        ]
+    );
+
+    // a character split across chunks is finished by the byte path
+    assert_eq!(parser.feed(b"\x80a").out, Out::Data('─' as FfiChar));
+    assert_eq!(parser.feed(b"a").out, Out::Text("a".into()));
+
+    parser.cfg.space_special = true;
+    let feed = parser.feed(b"ab cd");
+    assert_eq!((feed.consumed, feed.out), (2, Out::Text("ab".into())));
+}
+
+#[test]
+pub fn buffered_strings() {
+    use crate::*;
+
+    let mut parser = SizedAnsiParser::<16>::new();
+    parser.cfg.buffer_strings = true;
+
+    for b in b"\x1b]0;t\xc3\xadtle" {
+        if *b == b']' {
+            assert_eq!(parser.next(*b), Out::C1(C1::OSC));
+        } else {
+            assert_eq!(parser.next(*b), Out::None);
+        }
+    }
+    assert_eq!(parser.next(0x1b), Out::None);
+    assert_eq!(
+        parser.next(b'\\'),
+        Out::OSC {
+            payload: "0;t\u{ed}tle".as_bytes().into(),
+            terminator: StringTerminator::ST
+        }
+    );
+
+    parser.cfg.bit8_enabled = true;
+    assert_eq!(parser.next(0x90), Out::C1(C1::DCS));
+    for b in b"1$r\xa0" {
+        assert_eq!(parser.next(*b), Out::None);
+    }
+    assert_eq!(
+        parser.next(0x9C),
+        Out::DCS {
+            payload: b"1$r\xa0"[..].into(),
+            terminator: StringTerminator::ST
+        }
+    );
+    assert_eq!(parser.next(b'a'), Out::Data('a' as FfiChar));
+
+    for (kind, expected) in [
+        (
+            b'X',
+            Out::SOS {
+                payload: b"data"[..].into(),
+                terminator: StringTerminator::ST,
+            },
+        ),
+        (
+            b'^',
+            Out::PM {
+                payload: b"data"[..].into(),
+                terminator: StringTerminator::ST,
+            },
+        ),
+        (
+            b'_',
+            Out::APC {
+                payload: b"data"[..].into(),
+                terminator: StringTerminator::ST,
+            },
+        ),
+    ] {
+        parser.next(0x1b);
+        parser.next(kind);
+        for b in b"da\nta" {
+            if *b == b'\n' {
+                assert_eq!(parser.next(*b), Out::C0(C0::LF));
+            } else {
+                assert_eq!(parser.next(*b), Out::None);
+            }
+        }
+        assert_eq!(parser.next(0x1b), Out::None);
+        assert_eq!(parser.next(b'\\'), expected);
+    }
+
+    parser.next(0x1b);
+    parser.next(b']');
+    for _ in 0..17 {
+        assert_eq!(parser.next(b'a'), Out::None);
+    }
+    assert_eq!(parser.next(0x9C), Out::StringSequenceTooLarge);
+
+    parser.next(0x1b);
+    parser.next(b']');
+    parser.next(b'a');
+    assert_eq!(
+        parser.next(0x18),
+        Out::StringAborted {
+            kind: StringKind::Os,
+            cause: StringAbort::CAN
+        }
+    );
+    assert_eq!(parser.next(b'b'), Out::Data('b' as FfiChar));
+
+    parser.next(0x1b);
+    parser.next(b']');
+    parser.next(b'a');
+    assert_eq!(
+        parser.next(0x07),
+        Out::OSC {
+            payload: b"a"[..].into(),
+            terminator: StringTerminator::BEL
         }
+    );
+}
+
+#[test]
+pub fn string_end() {
+    use crate::*;
+
+    let mut parser = SizedAnsiParser::<16>::new();
+
+    fn start(parser: &mut SizedAnsiParser<16>, kind: u8) {
+        parser.next(0x1b);
+        parser.next(kind);
+        assert_ne!(parser.next(b'x'), Out::None);
     }
 
-    invalid_sequence(&[0b11000000, 0]);
+    let kinds = [
+        (b'P', StringKind::DeviceControl),
+        (b'X', StringKind::Regular),
+        (b'^', StringKind::Privacy),
+        (b'_', StringKind::ApplicationProgramCommand),
+        (b']', StringKind::Os),
+    ];
+    for (intro, kind) in kinds {
+        start(&mut parser, intro);
+        assert_eq!(parser.next(0x1b), Out::None);
+        assert_eq!(
+            parser.next(b'\\'),
+            Out::StringEnd {
+                kind,
+                terminator: StringTerminator::ST
+            }
+        );
+
+        start(&mut parser, intro);
+        let bel = parser.next(0x07);
+        if kind == StringKind::Os {
+            assert_eq!(
+                bel,
+                Out::StringEnd {
+                    kind,
+                    terminator: StringTerminator::BEL
+                }
+            );
+        } else {
+            assert_eq!(bel, Out::C0(C0::BEL));
+            assert_eq!(
+                parser.next(0x1A),
+                Out::StringAborted {
+                    kind,
+                    cause: StringAbort::SUB
+                }
+            );
+        }
+
+        start(&mut parser, intro);
+        assert_eq!(
+            parser.next(0x18),
+            Out::StringAborted {
+                kind,
+                cause: StringAbort::CAN
+            }
+        );
+
+        parser.cfg.bit8_enabled = true;
+        start(&mut parser, intro);
+        assert_eq!(
+            parser.next(0x9C),
+            Out::StringEnd {
+                kind,
+                terminator: StringTerminator::ST
+            }
+        );
+        start(&mut parser, intro);
+        assert_eq!(
+            parser.feed(b"\x9b1m"),
+            Feed {
+                consumed: 0,
+                out: Out::StringAborted {
+                    kind,
+                    cause: StringAbort::Interrupted
+                }
+            }
+        );
+        assert_eq!(parser.next(0x9b), Out::None);
+        parser.cfg.bit8_enabled = false;
+
+        // the sequence which interrupted the string is still parsed
+        start(&mut parser, intro);
+        assert_eq!(
+            parser.feed(b"\x1b[1m"),
+            Feed {
+                consumed: 1,
+                out: Out::StringAborted {
+                    kind,
+                    cause: StringAbort::Interrupted
+                }
+            }
+        );
+        assert_eq!(
+            parser.feed(b"[1m").out,
+            Out::CSI(CSI::new(b"1m"[..].into()))
+        );
+
+        start(&mut parser, intro);
+        parser.next(0x1b);
+        assert_eq!(parser.next(b'7'), Out::Fp(Fp::DECSC));
+        assert_eq!(parser.next(b'x'), Out::Data('x' as FfiChar));
+    }
+}
+
+#[test]
+pub fn csi_pass_through_c0() {
+    use crate::*;
+
+    let mut parser = SizedAnsiParser::<16>::new();
+    parser.cfg.csi_pass_through_c0 = true;
+
+    assert_eq!(parser.next(0x1b), Out::None);
+    assert_eq!(parser.next(b'\r'), Out::C0(C0::CR));
+    assert_eq!(parser.next(b'['), Out::None);
+    assert_eq!(parser.next(b'1'), Out::None);
+    assert_eq!(parser.next(0x08), Out::C0(C0::BS));
+    assert_eq!(parser.next(b'0'), Out::None);
+    assert_eq!(parser.next(b' '), Out::None);
+    assert_eq!(parser.next(b'\n'), Out::C0(C0::LF));
+    assert_eq!(parser.next(b'q'), Out::CSI(CSI::new(b"10 q"[..].into())));
+
+    assert_eq!(parser.next(0x1b), Out::None);
+    assert_eq!(parser.next(b'#'), Out::None);
+    assert_eq!(parser.next(0x0b), Out::C0(C0::VT));
+    assert_eq!(parser.next(b'8'), Out::nF(b"#8"[..].into()));
+
+    assert_eq!(parser.next(0x1b), Out::None);
+    assert_eq!(parser.next(b'['), Out::None);
+    assert_eq!(parser.next(b' '), Out::None);
+    assert_eq!(parser.next(b'1'), Out::None);
+    assert_eq!(parser.next(0x07), Out::C0(C0::BEL));
+    assert_eq!(parser.next(b'm'), Out::None);
+
+    parser.cfg.csi_pass_through_c0 = false;
+
+    assert_eq!(parser.next(0x1b), Out::None);
+    assert_eq!(parser.next(b'\r'), Out::InvalidEscapeByte(b'\r'));
+
+    assert_eq!(parser.next(0x1b), Out::None);
+    assert_eq!(parser.next(b'['), Out::None);
+    assert_eq!(parser.next(b'1'), Out::None);
+    assert_eq!(parser.next(0x08), Out::None);
+    assert_eq!(parser.next(b'A'), Out::None);
+    assert_eq!(parser.next(b'A'), Out::Data('A' as FfiChar));
+
+    assert_eq!(parser.next(0x1b), Out::None);
+    assert_eq!(parser.next(b'#'), Out::None);
+    assert_eq!(parser.next(0x0b), Out::nFInvalidSequence);
+}
+
+#[test]
+pub fn nf_sequences() {
+    use crate::*;
+
+    fn parse<const CAP: usize>(parser: &mut SizedAnsiParser<CAP>, input: &[u8]) -> OwnedOut {
+        let (last, rest) = input.split_last().unwrap();
+        for b in rest {
+            assert_eq!(parser.next(*b), Out::None);
+        }
+        parser.next(*last).into()
+    }
+
+    // the final byte is part of the sequence
+    let mut parser = SizedAnsiParser::<4>::new();
+    assert_eq!(parse(&mut parser, b"\x1b#8"), OwnedOut::nF(b"#8".to_vec()));
+    assert_eq!(
+        parse(&mut parser, b"\x1b !#8"),
+        OwnedOut::nF(b" !#8".to_vec())
+    );
+
+    // overflowing on the final byte is an overflow like any other
+    parser.cfg.nf_silent_sequence_overflow = false;
+    assert_eq!(
+        parse(&mut parser, b"\x1b !#8"),
+        OwnedOut::nF(b" !#8".to_vec())
+    );
+    assert_eq!(
+        parse(&mut parser, b"\x1b !\"#8"),
+        OwnedOut::nFSequenceTooLarge
+    );
+    assert_eq!(
+        parse(&mut parser, b"\x1b !\"$#8"),
+        OwnedOut::nFSequenceTooLarge
+    );
+    assert_eq!(parse(&mut parser, b"\x1b#8"), OwnedOut::nF(b"#8".to_vec()));
+}
+
+#[test]
+pub fn transition_table() {
+    use crate::*;
+    use std::vec::Vec;
+
+    struct Rng(u64);
+    impl Rng {
+        fn next(&mut self, bound: u32) -> u32 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % bound as u64) as u32
+        }
+    }
+
+    // bytes which start, end or sit on the edge of a sequence are picked more often
+    const INTERESTING: &[u8] = b"\x07\x18\x1a\x1b\x20\x2f\x30\x3b\x3f\x40P[\\]^_X\x7e\x7f\x80\x90\x9b\x9c\x9d\x9f\xc3\xe2\xf0";
+
+    let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+    for _ in 0..400 {
+        let mut table = SizedAnsiParser::<24>::new();
+        let flags = rng.next(1 << 13);
+        let flag = |bit: u32| flags & (1 << bit) != 0;
+        table.cfg.bit8_enabled = flag(0);
+        table.cfg.del_special = flag(1);
+        table.cfg.space_special = flag(2);
+        table.cfg.csi_silent_intermediate_overflow = flag(3);
+        table.cfg.csi_pass_through_c0 = flag(4);
+        table.cfg.nf_silent_sequence_overflow = flag(5);
+        table.cfg.utf8 = flag(6);
+        table.cfg.utf8_replacement = flag(7);
+        table.cfg.string_pass_through_c0 = flag(8);
+        table.cfg.utf8_strings = flag(9);
+        table.cfg.buffer_strings = flag(10);
+        table.cfg.csi_params = flag(11);
+        table.cfg.encoding = if flag(12) {
+            Encoding::Cp437
+        } else {
+            Encoding::Latin1
+        };
+        table.cfg.csi_overflow = [
+            CsiOverflow::Reject,
+            CsiOverflow::KeepFirst,
+            CsiOverflow::KeepLast,
+        ][rng.next(3) as usize];
+        table.cfg.max_immediate_count = rng.next(4) as usize;
+        let reference: &mut UnsizedAnsiParser = &mut SizedAnsiParser::<24>::new();
+        reference.cfg = table.cfg;
+
+        let input: Vec<u8> = (0..512)
+            .map(|_| match rng.next(4) {
+                0 => INTERESTING[rng.next(INTERESTING.len() as u32) as usize],
+                1 => rng.next(0x100) as u8,
+                _ => rng.next(0x60) as u8 + 0x20,
+            })
+            .collect();
+
+        let table: &mut UnsizedAnsiParser = &mut table;
+        for (i, byte) in input.iter().enumerate() {
+            assert_eq!(
+                table.step_events(*byte, false),
+                reference.step_events(*byte, true),
+                "{:?} {:x?}",
+                table.cfg,
+                &input[..=i]
+            );
+        }
+        assert_eq!(
+            format!("{:?}", table.state),
+            format!("{:?}", reference.state),
+            "{:?} {input:x?}",
+            table.cfg
+        );
+        assert_eq!(table.buffer.bytes(), reference.buffer.bytes());
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Vt500Action {
+    None,
+    Print,
+    Execute,
+    EscDispatch,
+    CsiDispatch,
+    Put,
+    Start,
+    End,
+}
+
+/// The transitions from Paul Williams' VT500 parser diagram
+fn vt500_reference(state: crate::Vt500State, byte: u8) -> (Vt500Action, crate::Vt500State) {
+    use crate::Vt500State as S;
+    use Vt500Action as A;
+
+    match byte {
+        0x18 | 0x1A | 0x80..=0x8F | 0x91..=0x97 | 0x99 | 0x9A => return (A::Execute, S::Ground),
+        0x9C => return (A::End, S::Ground),
+        0x1B => return (A::None, S::Escape),
+        0x90 => return (A::Start, S::DcsEntry),
+        0x9B => return (A::None, S::CsiEntry),
+        0x9D => return (A::Start, S::OscString),
+        0x98 | 0x9E | 0x9F => return (A::Start, S::SosPmApcString),
+        _ => {}
+    }
+    let b = if byte >= 0xA0 { byte - 0x80 } else { byte };
+    let c0 = matches!(b, 0x00..=0x17 | 0x19 | 0x1C..=0x1F);
+
+    match state {
+        S::Ground if c0 => (A::Execute, S::Ground),
+        S::Ground => (A::Print, S::Ground),
+
+        S::Escape if c0 => (A::Execute, S::Escape),
+        S::Escape => match b {
+            0x7F => (A::None, S::Escape),
+            0x20..=0x2F => (A::None, S::EscapeIntermediate),
+            0x50 => (A::Start, S::DcsEntry),
+            0x5B => (A::None, S::CsiEntry),
+            0x5D => (A::Start, S::OscString),
+            0x58 | 0x5E | 0x5F => (A::Start, S::SosPmApcString),
+            _ => (A::EscDispatch, S::Ground),
+        },
+        S::EscapeIntermediate if c0 => (A::Execute, S::EscapeIntermediate),
+        S::EscapeIntermediate => match b {
+            0x20..=0x2F | 0x7F => (A::None, S::EscapeIntermediate),
+            _ => (A::EscDispatch, S::Ground),
+        },
+
+        S::CsiEntry if c0 => (A::Execute, S::CsiEntry),
+        S::CsiEntry => match b {
+            0x7F => (A::None, S::CsiEntry),
+            0x20..=0x2F => (A::None, S::CsiIntermediate),
+            0x3A => (A::None, S::CsiIgnore),
+            0x30..=0x3F => (A::None, S::CsiParam),
+            _ => (A::CsiDispatch, S::Ground),
+        },
+        S::CsiParam if c0 => (A::Execute, S::CsiParam),
+        S::CsiParam => match b {
+            0x30..=0x39 | 0x3B | 0x7F => (A::None, S::CsiParam),
+            0x3A | 0x3C..=0x3F => (A::None, S::CsiIgnore),
+            0x20..=0x2F => (A::None, S::CsiIntermediate),
+            _ => (A::CsiDispatch, S::Ground),
+        },
+        S::CsiIntermediate if c0 => (A::Execute, S::CsiIntermediate),
+        S::CsiIntermediate => match b {
+            0x20..=0x2F | 0x7F => (A::None, S::CsiIntermediate),
+            0x30..=0x3F => (A::None, S::CsiIgnore),
+            _ => (A::CsiDispatch, S::Ground),
+        },
+        S::CsiIgnore if c0 => (A::Execute, S::CsiIgnore),
+        S::CsiIgnore => match b {
+            0x40..=0x7E => (A::None, S::Ground),
+            _ => (A::None, S::CsiIgnore),
+        },
+
+        S::DcsEntry if c0 => (A::None, S::DcsEntry),
+        S::DcsEntry => match b {
+            0x7F => (A::None, S::DcsEntry),
+            0x20..=0x2F => (A::Put, S::DcsIntermediate),
+            0x3A => (A::None, S::DcsIgnore),
+            0x30..=0x3F => (A::Put, S::DcsParam),
+            _ => (A::Put, S::DcsPassthrough),
+        },
+        S::DcsParam if c0 => (A::None, S::DcsParam),
+        S::DcsParam => match b {
+            0x7F => (A::None, S::DcsParam),
+            0x30..=0x39 | 0x3B => (A::Put, S::DcsParam),
+            0x3A | 0x3C..=0x3F => (A::None, S::DcsIgnore),
+            0x20..=0x2F => (A::Put, S::DcsIntermediate),
+            _ => (A::Put, S::DcsPassthrough),
+        },
+        S::DcsIntermediate if c0 => (A::None, S::DcsIntermediate),
+        S::DcsIntermediate => match b {
+            0x7F => (A::None, S::DcsIntermediate),
+            0x20..=0x2F => (A::Put, S::DcsIntermediate),
+            0x30..=0x3F => (A::None, S::DcsIgnore),
+            _ => (A::Put, S::DcsPassthrough),
+        },
+        S::DcsPassthrough => match b {
+            0x7F => (A::None, S::DcsPassthrough),
+            _ => (A::Put, S::DcsPassthrough),
+        },
+        S::DcsIgnore => (A::None, S::DcsIgnore),
+
+        S::OscString if c0 => (A::None, S::OscString),
+        S::OscString => (A::Put, S::OscString),
+        S::SosPmApcString => (A::None, S::SosPmApcString),
+    }
+}
+
+#[test]
+pub fn vt500_transitions() {
+    use crate::Vt500State as S;
+    use crate::*;
+
+    let states: [(S, &[u8], Option<StringKind>); 14] = [
+        (S::Ground, b"", None),
+        (S::Escape, b"\x1b", None),
+        (S::EscapeIntermediate, b"\x1b ", None),
+        (S::CsiEntry, b"\x1b[", None),
+        (S::CsiParam, b"\x1b[0", None),
+        (S::CsiIntermediate, b"\x1b[ ", None),
+        (S::CsiIgnore, b"\x1b[:", None),
+        (S::DcsEntry, b"\x1bP", Some(StringKind::DeviceControl)),
+        (S::DcsParam, b"\x1bP0", Some(StringKind::DeviceControl)),
+        (
+            S::DcsIntermediate,
+            b"\x1bP ",
+            Some(StringKind::DeviceControl),
+        ),
+        (
+            S::DcsPassthrough,
+            b"\x1bP@",
+            Some(StringKind::DeviceControl),
+        ),
+        (S::DcsIgnore, b"\x1bP:", Some(StringKind::DeviceControl)),
+        (S::OscString, b"\x1b]", Some(StringKind::Os)),
+        (S::SosPmApcString, b"\x1bX", Some(StringKind::Regular)),
+    ];
+
+    let mut parser = SizedVt500Parser::<16>::new();
+    for (state, prefix, string) in states {
+        for byte in 0..=255u8 {
+            parser.reset();
+            for b in prefix {
+                parser.next(*b);
+            }
+            assert_eq!(parser.state(), state);
+
+            let (action, next) = vt500_reference(state, byte);
+            // GR bytes act like their GL counterparts and C1 bytes like ESC Fe
+            let gl = if byte >= 0xA0 { byte - 0x80 } else { byte };
+            let fe = if (0x80..0xA0).contains(&byte) {
+                byte - 0x40
+            } else {
+                gl
+            };
+            // the intermediates or parameters collected by the prefix, then the final byte
+            let collected = if state == S::EscapeIntermediate {
+                &prefix[1..]
+            } else {
+                prefix.get(2..).unwrap_or(&[])
+            };
+            let sequence: std::vec::Vec<u8> = collected.iter().copied().chain([gl]).collect();
+            let expected = match (action, string) {
+                // leaving a string reports the abort when the byte has no event of its own
+                (Vt500Action::None, Some(kind)) if byte == 0x9B => Out::StringAborted {
+                    kind,
+                    cause: StringAbort::Interrupted,
+                },
+                (Vt500Action::None, _) => Out::None,
+                (Vt500Action::Print, _) => Out::Data(byte as FfiChar),
+                (Vt500Action::Execute, _) if byte >= 0x80 => Out::C1(C1::try_from(fe).unwrap()),
+                (Vt500Action::Execute, _) => Out::C0(C0::try_from(byte).unwrap()),
+                (Vt500Action::EscDispatch, _) if state == S::EscapeIntermediate => {
+                    Out::nF(sequence[..].into())
+                }
+                (Vt500Action::EscDispatch, _) => match gl {
+                    0x30..=0x3F => Out::Fp(Fp::try_from(gl).unwrap()),
+                    0x40..=0x5F => Out::C1(C1::try_from(gl).unwrap()),
+                    _ => Out::Fs(Fs::try_from(gl).unwrap()),
+                },
+                (Vt500Action::CsiDispatch, _) => Out::CSI(CSI::new(&sequence[..])),
+                (Vt500Action::Put, Some(StringKind::Os)) => Out::OSData(byte as FfiChar),
+                (Vt500Action::Put, _) => Out::DCSData(byte as FfiChar),
+                (Vt500Action::Start, _) => Out::C1(C1::try_from(fe).unwrap()),
+                (Vt500Action::End, Some(kind)) => Out::StringEnd {
+                    kind,
+                    terminator: StringTerminator::ST,
+                },
+                (Vt500Action::End, None) => Out::None,
+            };
+            assert_eq!(
+                parser.next(byte),
+                expected,
+                "{state:?} {byte:#04x} {action:?}"
+            );
+            assert_eq!(parser.state(), next, "{state:?} {byte:#04x}");
+        }
+    }
+}
+
+#[test]
+pub fn vt500_sequences() {
+    use crate::*;
+
+    let mut parser = SizedVt500Parser::<8>::new();
+
+    assert_eq!(
+        parser.feed(b"\x1b[?1;2$p").out,
+        Out::CSI(CSI::new(b"?1;2$p"[..].into()))
+    );
+    assert_eq!(
+        parser.feed(b"\x9b1m").out,
+        Out::CSI(CSI::new(b"1m"[..].into()))
+    );
+    assert_eq!(parser.feed(b"\x1b(0").out, Out::nF(b"(0"[..].into()));
+    assert_eq!(
+        parser.feed(b"\x1b[1;2;3;4;5m").out,
+        Out::CSISequenceTooLarge
+    );
+    assert_eq!(parser.feed(b"\x1b[1\x7f\x08m").out, Out::C0(C0::BS));
+    assert_eq!(parser.feed(b"m").out, Out::CSI(CSI::new(b"1m"[..].into())));
+
+    assert_eq!(parser.feed(b"\x1b]0;a").out, Out::C1(C1::OSC));
+    assert_eq!(
+        parser.feed(b"\x1b\\").out,
+        Out::StringEnd {
+            kind: StringKind::Os,
+            terminator: StringTerminator::ST
+        }
+    );
+    assert_eq!(parser.state(), Vt500State::Ground);
+
+    // leaving a string some other way aborts it and still acts on the byte
+    parser.next(0x90);
+    assert_eq!(
+        parser.feed(b"\x18"),
+        Feed {
+            consumed: 0,
+            out: Out::StringAborted {
+                kind: StringKind::DeviceControl,
+                cause: StringAbort::Interrupted
+            }
+        }
+    );
+    assert_eq!(parser.feed(b"\x18").out, Out::C0(C0::CAN));
+
+    parser.next(0x9d);
+    parser.next(0x1b);
+    assert_eq!(
+        parser.feed(b"[1m"),
+        Feed {
+            consumed: 0,
+            out: Out::StringAborted {
+                kind: StringKind::Os,
+                cause: StringAbort::Interrupted
+            }
+        }
+    );
+    assert_eq!(
+        parser.feed(b"[1m").out,
+        Out::CSI(CSI::new(b"1m"[..].into()))
+    );
+}
+
+#[derive(Default)]
+struct Recorder(std::vec::Vec<std::string::String>);
+
+impl crate::Perform for Recorder {
+    fn print(&mut self, c: char) {
+        self.0.push(format!("print {c}"));
+    }
+
+    fn execute(&mut self, byte: u8) {
+        self.0.push(format!("execute {byte:#04x}"));
+    }
+
+    fn designate(&mut self, slot: crate::CharsetSlot, charset: crate::Charset) {
+        self.0.push(format!("designate {slot:?} {charset:?}"));
+    }
+
+    fn csi_dispatch(&mut self, csi: crate::KnownCSI<'_>) {
+        self.0.push(format!("csi {csi:?}"));
+    }
+
+    fn esc_dispatch(&mut self, intermediates: &[u8], byte: u8) {
+        self.0
+            .push(format!("esc {intermediates:?} {}", byte as char));
+    }
+
+    fn osc_dispatch(&mut self, params: &[&[u8]], bell_terminated: bool) {
+        let params: std::vec::Vec<_> = params
+            .iter()
+            .map(|p| std::str::from_utf8(p).unwrap())
+            .collect();
+        self.0.push(format!("osc {params:?} {bell_terminated}"));
+    }
+
+    fn osc_start(&mut self) {
+        self.0.push("osc_start".into());
+    }
+
+    fn osc_put(&mut self, c: char) {
+        self.0.push(format!("osc_put {c}"));
+    }
+
+    fn osc_end(&mut self) {
+        self.0.push("osc_end".into());
+    }
+
+    fn hook(&mut self, header: crate::CSI<'_>) {
+        let params = header.params().map(|p| p.param(0));
+        self.0.push(format!(
+            "hook {} {params:?}",
+            std::str::from_utf8(header.bytes()).unwrap()
+        ));
+    }
+
+    fn put(&mut self, c: char) {
+        self.0.push(format!("put {c}"));
+    }
+
+    fn unhook(&mut self) {
+        self.0.push("unhook".into());
+    }
+
+    fn dcs_dispatch(&mut self, header: crate::CSI<'_>, data: &[u8]) {
+        self.0.push(format!(
+            "dcs {} {}",
+            std::str::from_utf8(header.bytes()).unwrap(),
+            std::str::from_utf8(data).unwrap()
+        ));
+    }
+
+    fn other(&mut self, out: crate::Out<'_>) {
+        self.0.push(format!("other {out:?}"));
+    }
+}
+
+#[test]
+pub fn perform() {
+    use crate::*;
+
+    let mut parser = SizedAnsiParser::<16>::new();
+    parser.cfg.designations = true;
+    let mut recorder = Recorder::default();
+    parser.advance(
+        &mut recorder,
+        b"a b\r\n\x1b[2A\x1b7\x1b(B\x1bPq#\x1b\\\x1bD\x1b]0;t\x07\x1b]2\x18",
+    );
+    assert_eq!(
+        recorder.0,
+        [
+            "print a",
+            "print  ",
+            "print b",
+            "execute 0x0d",
+            "execute 0x0a",
+            "csi CursorUp(2)",
+            "esc [] 7",
+            "designate G0 Ascii",
+            "hook q None",
+            "put #",
+            "unhook",
+            "execute 0x84",
+            "osc_start",
+            "osc_put 0",
+            "osc_put ;",
+            "osc_put t",
+            "osc_end",
+            "osc_start",
+            "osc_put 2",
+            "osc_end",
+        ]
+    );
+
+    // split input continues where it left off
+    let mut recorder = Recorder::default();
+    parser.cfg.buffer_strings = true;
+    parser.cfg.text_runs = true;
+    parser.advance(&mut recorder, b"hi\x1b]0;ti");
+    parser.advance(&mut recorder, b"tle\x07\x1b[");
+    parser.advance(&mut recorder, b"m\x7f\x1bPq\x18");
+    assert_eq!(
+        recorder.0,
+        [
+            "print h",
+            "print i",
+            "other C1(OSC)",
+            r#"osc ["0", "title"] true"#,
+            "csi SelectGraphicRendition([Reset])",
+            "other DEL",
+            "other C1(DCS)",
+            "other StringAborted { kind: DeviceControl, cause: CAN }",
+        ]
+    );
+
+    // the OSC params are split and the DCS header is parsed off the payload
+    let mut recorder = Recorder::default();
+    parser.cfg.csi_params = true;
+    parser.advance(
+        &mut recorder,
+        b"\x1b]8;;a;b\x1b\\\x1bP1$r0m\x1b\\\x1bP1;\x1b\\",
+    );
+    assert_eq!(
+        recorder.0,
+        [
+            "other C1(OSC)",
+            r#"osc ["8", "", "a", "b"] false"#,
+            "other C1(DCS)",
+            "dcs 1$r 0m",
+            "other C1(DCS)",
+            r#"other DCS { payload: [49, 59], terminator: ST }"#,
+        ]
+    );
+
+    // a streamed DCS header can be split across calls and snapshots
+    let mut recorder = Recorder::default();
+    parser.cfg.buffer_strings = false;
+    parser.advance(&mut recorder, b"\x1bP1");
+    let mut resumed = SizedAnsiParser::<16>::new();
+    resumed.cfg = parser.cfg;
+    resumed.restore(parser.snapshot().as_bytes()).unwrap();
+    resumed.advance(&mut recorder, b"$qm\x1b\\\x1bP1$1q\x1b\\");
+    let mut expected = vec![
+        "hook 1$q Some(Some(1))".into(),
+        "put m".into(),
+        "unhook".into(),
+    ];
+    // the malformed header is handed over byte by byte
+    expected.extend(
+        "1$1q"
+            .chars()
+            .map(|c| format!("other {:?}", Out::DCSData(c.into()))),
+    );
+    assert_eq!(recorder.0, expected);
+}
+
+#[test]
+pub fn encodings() {
+    use crate::*;
+
+    for b in 0..=0xFF {
+        assert_eq!(Encoding::Latin1.decode(b), b as FfiChar);
+    }
+    for b in 0..0x80 {
+        assert_eq!(Encoding::Cp437.decode(b), b as FfiChar);
+        assert_eq!(Encoding::Windows1252.decode(b), b as FfiChar);
+        assert_eq!(Encoding::Latin9.decode(b), b as FfiChar);
+    }
+    assert_eq!(Encoding::Cp437.decode(0x80), 'Ç' as FfiChar);
+    assert_eq!(Encoding::Cp437.decode(0xB0), '░' as FfiChar);
+    assert_eq!(Encoding::Cp437.decode(0xC9), '╔' as FfiChar);
+    assert_eq!(Encoding::Cp437.decode(0xFF), '\u{A0}' as FfiChar);
+    assert_eq!(Encoding::Windows1252.decode(0x80), '€' as FfiChar);
+    assert_eq!(Encoding::Windows1252.decode(0x81), '\u{81}' as FfiChar);
+    assert_eq!(Encoding::Windows1252.decode(0x93), '“' as FfiChar);
+    assert_eq!(Encoding::Windows1252.decode(0xE9), 'é' as FfiChar);
+    assert_eq!(Encoding::Latin9.decode(0xA4), '€' as FfiChar);
+    assert_eq!(Encoding::Latin9.decode(0xBE), 'Ÿ' as FfiChar);
+    assert_eq!(Encoding::Latin9.decode(0xA5), '¥' as FfiChar);
+
+    static TABLE: [FfiChar; 128] = [b'?' as FfiChar; 128];
+    assert_eq!(Encoding::Custom(&TABLE).decode(0x41), 'A' as FfiChar);
+    assert_eq!(Encoding::Custom(&TABLE).decode(0xC8), '?' as FfiChar);
+
+    let mut parser = SizedAnsiParser::<16>::new();
+    parser.cfg.utf8 = false;
+    parser.cfg.utf8_strings = false;
+    parser.cfg.encoding = Encoding::Cp437;
+    assert_eq!(parser.next(b'a'), Out::Data('a' as FfiChar));
+    assert_eq!(parser.next(0xDB), Out::Data('█' as FfiChar));
+    assert_eq!(parser.next(0x1b), Out::None);
+    assert_eq!(parser.next(b']'), Out::C1(C1::OSC));
+    assert_eq!(parser.next(0x9C), Out::OSData('£' as FfiChar));
+
+    // bytes taken by C1 controls are not decoded
+    parser.cfg.bit8_enabled = true;
+    parser.cfg.encoding = Encoding::Windows1252;
+    assert_eq!(
+        parser.next(0x9C),
+        Out::StringEnd {
+            kind: StringKind::Os,
+            terminator: StringTerminator::ST
+        }
+    );
+    assert_eq!(parser.next(0x85), Out::C1(C1::NEL));
+    assert_eq!(parser.next(0xA9), Out::Data('©' as FfiChar));
+}
+
+#[test]
+pub fn charsets() {
+    use crate::*;
+
+    let mut parser = SizedAnsiParser::<16>::new();
+    let mut charsets = Charsets::new();
+
+    // designations stay nF sequences unless they are asked for
+    assert_eq!(parser.feed(b"\x1b(0").out, Out::nF(b"(0"[..].into()));
+    parser.cfg.designations = true;
+
+    fn translate(
+        parser: &mut SizedAnsiParser<16>,
+        charsets: &mut Charsets,
+        input: &[u8],
+    ) -> std::string::String {
+        let mut string = std::string::String::new();
+        let mut input = input;
+        while !input.is_empty() {
+            let Feed { consumed, out } = parser.feed(input);
+            input = &input[consumed..];
+            for out in charsets.translate(out) {
+                match out {
+                    Out::Data(c) => string.push(char::try_from(c).unwrap()),
+                    Out::Text(text) => string.push_str(&text),
+                    Out::SP => string.push(' '),
+                    _ => {}
+                }
+            }
+        }
+        string
+    }
+
+    assert_eq!(
+        parser.feed(b"\x1b(0").out,
+        Out::Designate {
+            slot: CharsetSlot::G0,
+            charset: Charset::DecSpecialGraphics,
+            bytes: b"(0"[..].into()
+        }
+    );
+    assert_eq!(
+        parser.feed(b"\x1b-A").out,
+        Out::Designate {
+            slot: CharsetSlot::G1,
+            charset: Charset::Latin1Supplemental,
+            bytes: b"-A"[..].into()
+        }
+    );
+    assert_eq!(
+        parser.feed(b"\x1b(%6").out,
+        Out::Designate {
+            slot: CharsetSlot::G0,
+            charset: Charset::Portuguese,
+            bytes: b"(%6"[..].into()
+        }
+    );
+    assert_eq!(parser.feed(b"\x1b(~").out, Out::nF(b"(~"[..].into()));
+    assert_eq!(parser.feed(b"\x1b-0").out, Out::nF(b"-0"[..].into()));
+
+    assert_eq!(
+        translate(&mut parser, &mut charsets, b"\x1b(0lqk\x1b(Bx"),
+        "┌─┐x"
+    );
+    assert_eq!(
+        translate(&mut parser, &mut charsets, b"\x1b)0a\x0eaq a\x0fa"),
+        "a▒─ ▒a"
+    );
+    assert_eq!(
+        translate(&mut parser, &mut charsets, b"\x1b*A\x1bN##"),
+        "£#"
+    );
+    assert_eq!(
+        translate(&mut parser, &mut charsets, b"\x1b+K\x1bo[\x1b(B\x0f[~"),
+        "Ä[~"
+    );
+    assert_eq!(translate(&mut parser, &mut charsets, b"\x1b(<(W}"), "¤Œÿ");
+    assert_eq!(
+        translate(&mut parser, &mut charsets, b"\x1b(=#_\x1b(B"),
+        "ùè"
+    );
+
+    // text runs are split up where they need translating
+    parser.cfg.text_runs = true;
+    parser.cfg.space_special = false;
+    assert_eq!(
+        translate(&mut parser, &mut charsets, b"\x1b(0lqqk abc\x1b(B lqk"),
+        "┌──┐ ▒␉␌ lqk"
+    );
+    let mut shifted = Charsets::new();
+    shifted.single_shift = FfiOption::Some(CharsetSlot::G2);
+    shifted.g[2] = Charset::DecSpecialGraphics;
+    let text = Out::Text("qq"[..].into());
+    assert_eq!(
+        shifted.translate(text).collect::<std::vec::Vec<_>>(),
+        [Out::Data('─'.into()), Out::Text("q"[..].into())]
+    );
+    assert_eq!(
+        shifted.translate(text).collect::<std::vec::Vec<_>>(),
+        [text]
+    );
+
+    // GR is only used for data in 0xA0..=0xFF when enabled
+    parser.cfg.text_runs = false;
+    parser.cfg.space_special = true;
+    parser.cfg.utf8 = false;
+    assert_eq!(translate(&mut parser, &mut charsets, b"\x1b*0\xe1"), "á");
+    charsets.use_gr = true;
+    assert_eq!(
+        translate(&mut parser, &mut charsets, b"\xe1\x1b-A\x1b~\xe1\x1bO\xdb"),
+        "▒áÄ"
+    );
+}
+
+#[test]
+fn grapheme_break_test() {
+    use crate::*;
+    let mut graphemes = SizedGraphemes::<64>::new();
+
+    for line in include_str!("grapheme_break_test.txt").lines() {
+        if line.starts_with('#') {
+            continue;
+        }
+        let mut expected = std::vec::Vec::new();
+        let mut chars = std::string::String::new();
+        for token in line.split_whitespace() {
+            match token {
+                "÷" if !chars.is_empty() => expected.push(core::mem::take(&mut chars)),
+                "÷" | "×" => {}
+                hex => chars.push(char::from_u32(u32::from_str_radix(hex, 16).unwrap()).unwrap()),
+            }
+        }
+
+        let mut clusters = std::vec::Vec::new();
+        for c in expected.concat().chars() {
+            if let Some(cluster) = graphemes.push(c) {
+                clusters.push(std::string::String::from(&*cluster.text));
+            }
+        }
+        if let Some(cluster) = graphemes.flush() {
+            clusters.push(std::string::String::from(&*cluster.text));
+        }
+        assert_eq!(clusters, expected, "{line}");
+    }
+}
+
+#[test]
+fn grapheme_width() {
+    use crate::*;
+
+    assert_eq!(char_width('a'), 1);
+    assert_eq!(char_width('中'), 2);
+    assert_eq!(char_width('\u{301}'), 0);
+    assert_eq!(char_width('\u{200B}'), 0);
+    assert_eq!(char_width('😀'), 2);
+
+    fn clusters(
+        graphemes: &mut UnsizedGraphemes,
+        parser: &mut UnsizedAnsiParser,
+        input: &[u8],
+    ) -> std::vec::Vec<(std::string::String, u8)> {
+        let mut clusters = std::vec::Vec::new();
+        let mut input = input;
+        while !input.is_empty() {
+            let Feed { consumed, mut out } = parser.feed(input);
+            input = &input[consumed..];
+            while let Some(cluster) = graphemes.next(&mut out) {
+                clusters.push((std::string::String::from(&*cluster.text), cluster.width));
+            }
+        }
+        if let Some(cluster) = graphemes.flush() {
+            clusters.push((std::string::String::from(&*cluster.text), cluster.width));
+        }
+        clusters
+    }
+
+    let graphemes: &mut UnsizedGraphemes = &mut SizedGraphemes::<16>::new();
+    let parser: &mut UnsizedAnsiParser = &mut SizedAnsiParser::<16>::new();
+    let owned = |clusters: &[(&str, u8)]| {
+        clusters
+            .iter()
+            .map(|(text, width)| (std::string::String::from(*text), *width))
+            .collect::<std::vec::Vec<_>>()
+    };
+
+    assert_eq!(
+        clusters(graphemes, parser, "e\u{301}中\r\n".as_bytes()),
+        owned(&[("e\u{301}", 1), ("中", 2)])
+    );
+    assert_eq!(
+        clusters(graphemes, parser, "👨\u{200D}👩🇨🇦🇫".as_bytes()),
+        owned(&[("👨\u{200D}👩", 2), ("🇨🇦", 2), ("🇫", 1)])
+    );
+    assert_eq!(
+        clusters(graphemes, parser, "❤\u{FE0F}❤".as_bytes()),
+        owned(&[("❤\u{FE0F}", 2), ("❤", 1)])
+    );
+    // escape sequences end the current cluster
+    assert_eq!(
+        clusters(graphemes, parser, "a\x1b[m\u{301}".as_bytes()),
+        owned(&[("a", 1), ("\u{301}", 0)])
+    );
+    // code points past the buffer are dropped from the text, they still count
+    // towards the width and the segmentation
+    let marks = "\u{301}".repeat(9);
+    assert_eq!(
+        clusters(graphemes, parser, format!("a{marks}b").as_bytes()),
+        owned(&[(&format!("a{}", &marks[..14]), 1), ("b", 1)])
+    );
+    let marks = "\u{301}".repeat(6);
+    assert_eq!(
+        clusters(graphemes, parser, format!("❤{marks}\u{FE0F}x").as_bytes()),
+        owned(&[(&format!("❤{marks}"), 2), ("x", 1)])
+    );
+
+    // DEC private mode 2027 toggles clustering
+    assert_eq!(
+        clusters(graphemes, parser, "\x1b[?25;2027le\u{301}🇨🇦".as_bytes()),
+        owned(&[("e", 1), ("\u{301}", 0), ("🇨", 1), ("🇦", 1)])
+    );
+    assert!(!graphemes.clustering);
+    assert_eq!(
+        clusters(
+            graphemes,
+            parser,
+            "\x1b[2027he\u{301}\x1b[?2027he\u{301}".as_bytes()
+        ),
+        owned(&[("e", 1), ("\u{301}", 0), ("e\u{301}", 1)])
+    );
+    assert!(graphemes.clustering);
+
+    // text runs are clustered the same as single characters
+    parser.cfg.text_runs = true;
+    parser.cfg.space_special = false;
+    assert_eq!(
+        clusters(graphemes, parser, "e\u{301}中 🇨🇦🇫\r\n".as_bytes()),
+        owned(&[("e\u{301}", 1), ("中", 2), (" ", 1), ("🇨🇦", 2), ("🇫", 1)])
+    );
+}
+
+#[test]
+fn csi_params() {
+    use crate::*;
+    use std::vec::Vec;
+
+    fn decoded(csi: CSI<'_>) -> Vec<CSIPart> {
+        let params = csi.params().expect("decoded parameters");
+        let mut parts = Vec::new();
+        for i in 0..params.param_count() {
+            parts.push(CSIPart::Param(params.param(i)));
+            parts.extend(params.subparams(i).iter().map(|v| CSIPart::SubParam(*v)));
+        }
+        parts
+    }
+
+    fn run<'a>(parser: &'a mut UnsizedAnsiParser, input: &[u8]) -> Out<'a> {
+        let (last, rest) = input.split_last().unwrap();
+        for b in rest {
+            parser.next(*b);
+        }
+        parser.next(*last)
+    }
+
+    fn parsed(csi: CSI<'_>) -> Vec<CSIPart> {
+        csi.parser()
+            .filter(|p| matches!(p, CSIPart::Param(_) | CSIPart::SubParam(_)))
+            .collect()
+    }
+
+    let mut parser = SizedAnsiParser::<64>::new();
+    parser.cfg.csi_params = true;
+    for input in [
+        &b"\x1b[m"[..],
+        b"\x1b[;m",
+        b"\x1b[:m",
+        b"\x1b[1;22;333m",
+        b"\x1b[38:2::255:128:0;1m",
+        b"\x1b[?1049;25h",
+        b"\x1b[>0;1 q",
+        b"\x1b[1?2;3m",
+        b"\x1b[??5;m",
+        b"\x1b[65535;65536m",
+    ] {
+        let Out::CSI(csi) = run(&mut parser, input) else {
+            panic!("{input:?}")
+        };
+        assert_eq!(decoded(csi), parsed(csi), "{input:?}");
+    }
+
+    let Out::CSI(csi) = run(&mut parser, b"\x1b[?1;2:3:4;5 $p") else {
+        panic!()
+    };
+    assert_eq!(csi.private_marker(), Some(b'?'));
+    assert_eq!(csi.intermediates(), b" $");
+    assert_eq!(csi.final_byte(), Some(b'p'));
+    let params = csi.params().unwrap();
+    assert_eq!(params.param_count(), 3);
+    assert_eq!(params.param(1), FfiOption::Some(2));
+    assert_eq!(
+        params.subparams(1),
+        [FfiOption::Some(3), FfiOption::Some(4)]
+    );
+    assert_eq!(params.subparams(2), []);
+    assert_eq!(params.param(3), FfiOption::None);
+
+    // only what is left of a truncated sequence is decoded
+    let mut parser = SizedAnsiParser::<8>::new();
+    parser.cfg.csi_params = true;
+    parser.cfg.csi_overflow = CsiOverflow::KeepLast;
+    let Out::CSITruncated { csi, .. } = run(&mut parser, b"\x1b[1;2:3;4;5;6m") else {
+        panic!()
+    };
+    assert_eq!(decoded(csi), parsed(csi));
+    assert_eq!(csi.params().unwrap().param(0), FfiOption::Some(4));
 
-    invalid_sequence(&[0b11100000, 0b10000000, 0]);
-    invalid_sequence(&[0b11100000, 0]);
+    // parameters past the limit are dropped
+    let mut parser = SizedAnsiParser::<128>::new();
+    parser.cfg.csi_params = true;
+    let Out::CSI(csi) = run(&mut parser, &[&b"\x1b["[..], &[b';'; 40], b"1m"].concat()) else {
+        panic!()
+    };
+    let params = csi.params().unwrap();
+    assert!(params.overflowed());
+    assert_eq!(params.param_count(), CSI_MAX_PARAMS);
 
-    invalid_sequence(&[0b11110000, 0b10000000, 0b10000000, 0]);
-    invalid_sequence(&[0b11110000, 0b10000000, 0]);
-    invalid_sequence(&[0b11110000, 0]);
+    // enabling decoding in the middle of a sequence
+    parser.cfg.csi_params = false;
+    assert_eq!(run(&mut parser, b"\x1b[4;5"), Out::None);
+    parser.cfg.csi_params = true;
+    let Out::CSI(csi) = run(&mut parser, b":6m") else {
+        panic!()
+    };
+    assert_eq!(decoded(csi), parsed(csi));
 
-    invalid_sequence(&[0b11111000, 0b10000000, 0b10000000, 0b10000000, 0]);
-    invalid_sequence(&[0b11111000, 0b10000000, 0b10000000, 0]);
-    invalid_sequence(&[0b11111000, 0b10000000, 0]);
-    invalid_sequence(&[0b11111000, 0]);
+    parser.cfg.csi_params = false;
+    let Out::CSI(csi) = run(&mut parser, b"\x1b[1m") else {
+        panic!()
+    };
+    assert!(csi.params().is_none());
+    assert_eq!(csi, CSI::new(b"1m"));
 
-    invalid_sequence(&[
-        0b11111100, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0,
-    ]);
-    invalid_sequence(&[0b11111100, 0b10000000, 0b10000000, 0b10000000, 0]);
-    invalid_sequence(&[0b11111100, 0b10000000, 0b10000000, 0]);
-    invalid_sequence(&[0b11111100, 0b10000000, 0]);
-    invalid_sequence(&[0b11111100, 0]);
+    // parameters are not part of a snapshot and decoded again after a restore
+    parser.cfg.csi_params = true;
+    assert_eq!(run(&mut parser, b"\x1b[1;2"), Out::None);
+    let snapshot = parser.snapshot();
+    let Out::CSI(csi) = run(&mut parser, b"\x1b[9;9m") else {
+        panic!()
+    };
+    assert_eq!(csi.params().unwrap().param(0), FfiOption::Some(9));
+    parser.restore(snapshot.as_bytes()).unwrap();
+    let Out::CSI(csi) = parser.next(b'm') else {
+        panic!()
+    };
+    assert_eq!(csi.params().unwrap().param(0), FfiOption::Some(1));
+    assert_eq!(decoded(csi), parsed(csi));
 }
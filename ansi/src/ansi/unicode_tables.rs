@@ -0,0 +1,2392 @@
+// Unicode 16.0.0 tables derived from the Grapheme_Cluster_Break, Extended_Pictographic,
+// Indic_Conjunct_Break and East_Asian_Width properties. Ranges are sorted and inclusive.
+
+use super::grapheme::GraphemeBreak as G;
+
+pub(super) const GRAPHEME_BREAK: &[(u32, u32, G)] = &[
+    (0x0, 0x9, G::Control),
+    (0xA, 0xA, G::Lf),
+    (0xB, 0xC, G::Control),
+    (0xD, 0xD, G::Cr),
+    (0xE, 0x1F, G::Control),
+    (0x7F, 0x9F, G::Control),
+    (0xA9, 0xA9, G::ExtendedPictographic),
+    (0xAD, 0xAD, G::Control),
+    (0xAE, 0xAE, G::ExtendedPictographic),
+    (0x300, 0x36F, G::Extend),
+    (0x483, 0x489, G::Extend),
+    (0x591, 0x5BD, G::Extend),
+    (0x5BF, 0x5BF, G::Extend),
+    (0x5C1, 0x5C2, G::Extend),
+    (0x5C4, 0x5C5, G::Extend),
+    (0x5C7, 0x5C7, G::Extend),
+    (0x600, 0x605, G::Prepend),
+    (0x610, 0x61A, G::Extend),
+    (0x61C, 0x61C, G::Control),
+    (0x64B, 0x65F, G::Extend),
+    (0x670, 0x670, G::Extend),
+    (0x6D6, 0x6DC, G::Extend),
+    (0x6DD, 0x6DD, G::Prepend),
+    (0x6DF, 0x6E4, G::Extend),
+    (0x6E7, 0x6E8, G::Extend),
+    (0x6EA, 0x6ED, G::Extend),
+    (0x70F, 0x70F, G::Prepend),
+    (0x711, 0x711, G::Extend),
+    (0x730, 0x74A, G::Extend),
+    (0x7A6, 0x7B0, G::Extend),
+    (0x7EB, 0x7F3, G::Extend),
+    (0x7FD, 0x7FD, G::Extend),
+    (0x816, 0x819, G::Extend),
+    (0x81B, 0x823, G::Extend),
+    (0x825, 0x827, G::Extend),
+    (0x829, 0x82D, G::Extend),
+    (0x859, 0x85B, G::Extend),
+    (0x890, 0x891, G::Prepend),
+    (0x897, 0x89F, G::Extend),
+    (0x8CA, 0x8E1, G::Extend),
+    (0x8E2, 0x8E2, G::Prepend),
+    (0x8E3, 0x902, G::Extend),
+    (0x903, 0x903, G::SpacingMark),
+    (0x915, 0x939, G::InCBConsonant),
+    (0x93A, 0x93A, G::Extend),
+    (0x93B, 0x93B, G::SpacingMark),
+    (0x93C, 0x93C, G::Extend),
+    (0x93E, 0x940, G::SpacingMark),
+    (0x941, 0x948, G::Extend),
+    (0x949, 0x94C, G::SpacingMark),
+    (0x94D, 0x94D, G::Extend),
+    (0x94E, 0x94F, G::SpacingMark),
+    (0x951, 0x957, G::Extend),
+    (0x958, 0x95F, G::InCBConsonant),
+    (0x962, 0x963, G::Extend),
+    (0x978, 0x97F, G::InCBConsonant),
+    (0x981, 0x981, G::Extend),
+    (0x982, 0x983, G::SpacingMark),
+    (0x995, 0x9A8, G::InCBConsonant),
+    (0x9AA, 0x9B0, G::InCBConsonant),
+    (0x9B2, 0x9B2, G::InCBConsonant),
+    (0x9B6, 0x9B9, G::InCBConsonant),
+    (0x9BC, 0x9BC, G::Extend),
+    (0x9BE, 0x9BE, G::Extend),
+    (0x9BF, 0x9C0, G::SpacingMark),
+    (0x9C1, 0x9C4, G::Extend),
+    (0x9C7, 0x9C8, G::SpacingMark),
+    (0x9CB, 0x9CC, G::SpacingMark),
+    (0x9CD, 0x9CD, G::Extend),
+    (0x9D7, 0x9D7, G::Extend),
+    (0x9DC, 0x9DD, G::InCBConsonant),
+    (0x9DF, 0x9DF, G::InCBConsonant),
+    (0x9E2, 0x9E3, G::Extend),
+    (0x9F0, 0x9F1, G::InCBConsonant),
+    (0x9FE, 0x9FE, G::Extend),
+    (0xA01, 0xA02, G::Extend),
+    (0xA03, 0xA03, G::SpacingMark),
+    (0xA3C, 0xA3C, G::Extend),
+    (0xA3E, 0xA40, G::SpacingMark),
+    (0xA41, 0xA42, G::Extend),
+    (0xA47, 0xA48, G::Extend),
+    (0xA4B, 0xA4D, G::Extend),
+    (0xA51, 0xA51, G::Extend),
+    (0xA70, 0xA71, G::Extend),
+    (0xA75, 0xA75, G::Extend),
+    (0xA81, 0xA82, G::Extend),
+    (0xA83, 0xA83, G::SpacingMark),
+    (0xA95, 0xAA8, G::InCBConsonant),
+    (0xAAA, 0xAB0, G::InCBConsonant),
+    (0xAB2, 0xAB3, G::InCBConsonant),
+    (0xAB5, 0xAB9, G::InCBConsonant),
+    (0xABC, 0xABC, G::Extend),
+    (0xABE, 0xAC0, G::SpacingMark),
+    (0xAC1, 0xAC5, G::Extend),
+    (0xAC7, 0xAC8, G::Extend),
+    (0xAC9, 0xAC9, G::SpacingMark),
+    (0xACB, 0xACC, G::SpacingMark),
+    (0xACD, 0xACD, G::Extend),
+    (0xAE2, 0xAE3, G::Extend),
+    (0xAF9, 0xAF9, G::InCBConsonant),
+    (0xAFA, 0xAFF, G::Extend),
+    (0xB01, 0xB01, G::Extend),
+    (0xB02, 0xB03, G::SpacingMark),
+    (0xB15, 0xB28, G::InCBConsonant),
+    (0xB2A, 0xB30, G::InCBConsonant),
+    (0xB32, 0xB33, G::InCBConsonant),
+    (0xB35, 0xB39, G::InCBConsonant),
+    (0xB3C, 0xB3C, G::Extend),
+    (0xB3E, 0xB3F, G::Extend),
+    (0xB40, 0xB40, G::SpacingMark),
+    (0xB41, 0xB44, G::Extend),
+    (0xB47, 0xB48, G::SpacingMark),
+    (0xB4B, 0xB4C, G::SpacingMark),
+    (0xB4D, 0xB4D, G::Extend),
+    (0xB55, 0xB57, G::Extend),
+    (0xB5C, 0xB5D, G::InCBConsonant),
+    (0xB5F, 0xB5F, G::InCBConsonant),
+    (0xB62, 0xB63, G::Extend),
+    (0xB71, 0xB71, G::InCBConsonant),
+    (0xB82, 0xB82, G::Extend),
+    (0xBBE, 0xBBE, G::Extend),
+    (0xBBF, 0xBBF, G::SpacingMark),
+    (0xBC0, 0xBC0, G::Extend),
+    (0xBC1, 0xBC2, G::SpacingMark),
+    (0xBC6, 0xBC8, G::SpacingMark),
+    (0xBCA, 0xBCC, G::SpacingMark),
+    (0xBCD, 0xBCD, G::Extend),
+    (0xBD7, 0xBD7, G::Extend),
+    (0xC00, 0xC00, G::Extend),
+    (0xC01, 0xC03, G::SpacingMark),
+    (0xC04, 0xC04, G::Extend),
+    (0xC15, 0xC28, G::InCBConsonant),
+    (0xC2A, 0xC39, G::InCBConsonant),
+    (0xC3C, 0xC3C, G::Extend),
+    (0xC3E, 0xC40, G::Extend),
+    (0xC41, 0xC44, G::SpacingMark),
+    (0xC46, 0xC48, G::Extend),
+    (0xC4A, 0xC4D, G::Extend),
+    (0xC55, 0xC56, G::Extend),
+    (0xC58, 0xC5A, G::InCBConsonant),
+    (0xC62, 0xC63, G::Extend),
+    (0xC81, 0xC81, G::Extend),
+    (0xC82, 0xC83, G::SpacingMark),
+    (0xCBC, 0xCBC, G::Extend),
+    (0xCBE, 0xCBE, G::SpacingMark),
+    (0xCBF, 0xCC0, G::Extend),
+    (0xCC1, 0xCC1, G::SpacingMark),
+    (0xCC2, 0xCC2, G::Extend),
+    (0xCC3, 0xCC4, G::SpacingMark),
+    (0xCC6, 0xCC8, G::Extend),
+    (0xCCA, 0xCCD, G::Extend),
+    (0xCD5, 0xCD6, G::Extend),
+    (0xCE2, 0xCE3, G::Extend),
+    (0xCF3, 0xCF3, G::SpacingMark),
+    (0xD00, 0xD01, G::Extend),
+    (0xD02, 0xD03, G::SpacingMark),
+    (0xD15, 0xD3A, G::InCBConsonant),
+    (0xD3B, 0xD3C, G::Extend),
+    (0xD3E, 0xD3E, G::Extend),
+    (0xD3F, 0xD40, G::SpacingMark),
+    (0xD41, 0xD44, G::Extend),
+    (0xD46, 0xD48, G::SpacingMark),
+    (0xD4A, 0xD4C, G::SpacingMark),
+    (0xD4D, 0xD4D, G::Extend),
+    (0xD4E, 0xD4E, G::Prepend),
+    (0xD57, 0xD57, G::Extend),
+    (0xD62, 0xD63, G::Extend),
+    (0xD81, 0xD81, G::Extend),
+    (0xD82, 0xD83, G::SpacingMark),
+    (0xDCA, 0xDCA, G::Extend),
+    (0xDCF, 0xDCF, G::Extend),
+    (0xDD0, 0xDD1, G::SpacingMark),
+    (0xDD2, 0xDD4, G::Extend),
+    (0xDD6, 0xDD6, G::Extend),
+    (0xDD8, 0xDDE, G::SpacingMark),
+    (0xDDF, 0xDDF, G::Extend),
+    (0xDF2, 0xDF3, G::SpacingMark),
+    (0xE31, 0xE31, G::Extend),
+    (0xE33, 0xE33, G::SpacingMark),
+    (0xE34, 0xE3A, G::Extend),
+    (0xE47, 0xE4E, G::Extend),
+    (0xEB1, 0xEB1, G::Extend),
+    (0xEB3, 0xEB3, G::SpacingMark),
+    (0xEB4, 0xEBC, G::Extend),
+    (0xEC8, 0xECE, G::Extend),
+    (0xF18, 0xF19, G::Extend),
+    (0xF35, 0xF35, G::Extend),
+    (0xF37, 0xF37, G::Extend),
+    (0xF39, 0xF39, G::Extend),
+    (0xF3E, 0xF3F, G::SpacingMark),
+    (0xF71, 0xF7E, G::Extend),
+    (0xF7F, 0xF7F, G::SpacingMark),
+    (0xF80, 0xF84, G::Extend),
+    (0xF86, 0xF87, G::Extend),
+    (0xF8D, 0xF97, G::Extend),
+    (0xF99, 0xFBC, G::Extend),
+    (0xFC6, 0xFC6, G::Extend),
+    (0x102D, 0x1030, G::Extend),
+    (0x1031, 0x1031, G::SpacingMark),
+    (0x1032, 0x1037, G::Extend),
+    (0x1039, 0x103A, G::Extend),
+    (0x103B, 0x103C, G::SpacingMark),
+    (0x103D, 0x103E, G::Extend),
+    (0x1056, 0x1057, G::SpacingMark),
+    (0x1058, 0x1059, G::Extend),
+    (0x105E, 0x1060, G::Extend),
+    (0x1071, 0x1074, G::Extend),
+    (0x1082, 0x1082, G::Extend),
+    (0x1084, 0x1084, G::SpacingMark),
+    (0x1085, 0x1086, G::Extend),
+    (0x108D, 0x108D, G::Extend),
+    (0x109D, 0x109D, G::Extend),
+    (0x1100, 0x115F, G::L),
+    (0x1160, 0x11A7, G::V),
+    (0x11A8, 0x11FF, G::T),
+    (0x135D, 0x135F, G::Extend),
+    (0x1712, 0x1715, G::Extend),
+    (0x1732, 0x1734, G::Extend),
+    (0x1752, 0x1753, G::Extend),
+    (0x1772, 0x1773, G::Extend),
+    (0x17B4, 0x17B5, G::Extend),
+    (0x17B6, 0x17B6, G::SpacingMark),
+    (0x17B7, 0x17BD, G::Extend),
+    (0x17BE, 0x17C5, G::SpacingMark),
+    (0x17C6, 0x17C6, G::Extend),
+    (0x17C7, 0x17C8, G::SpacingMark),
+    (0x17C9, 0x17D3, G::Extend),
+    (0x17DD, 0x17DD, G::Extend),
+    (0x180B, 0x180D, G::Extend),
+    (0x180E, 0x180E, G::Control),
+    (0x180F, 0x180F, G::Extend),
+    (0x1885, 0x1886, G::Extend),
+    (0x18A9, 0x18A9, G::Extend),
+    (0x1920, 0x1922, G::Extend),
+    (0x1923, 0x1926, G::SpacingMark),
+    (0x1927, 0x1928, G::Extend),
+    (0x1929, 0x192B, G::SpacingMark),
+    (0x1930, 0x1931, G::SpacingMark),
+    (0x1932, 0x1932, G::Extend),
+    (0x1933, 0x1938, G::SpacingMark),
+    (0x1939, 0x193B, G::Extend),
+    (0x1A17, 0x1A18, G::Extend),
+    (0x1A19, 0x1A1A, G::SpacingMark),
+    (0x1A1B, 0x1A1B, G::Extend),
+    (0x1A55, 0x1A55, G::SpacingMark),
+    (0x1A56, 0x1A56, G::Extend),
+    (0x1A57, 0x1A57, G::SpacingMark),
+    (0x1A58, 0x1A5E, G::Extend),
+    (0x1A60, 0x1A60, G::Extend),
+    (0x1A62, 0x1A62, G::Extend),
+    (0x1A65, 0x1A6C, G::Extend),
+    (0x1A6D, 0x1A72, G::SpacingMark),
+    (0x1A73, 0x1A7C, G::Extend),
+    (0x1A7F, 0x1A7F, G::Extend),
+    (0x1AB0, 0x1ACE, G::Extend),
+    (0x1B00, 0x1B03, G::Extend),
+    (0x1B04, 0x1B04, G::SpacingMark),
+    (0x1B34, 0x1B3D, G::Extend),
+    (0x1B3E, 0x1B41, G::SpacingMark),
+    (0x1B42, 0x1B44, G::Extend),
+    (0x1B6B, 0x1B73, G::Extend),
+    (0x1B80, 0x1B81, G::Extend),
+    (0x1B82, 0x1B82, G::SpacingMark),
+    (0x1BA1, 0x1BA1, G::SpacingMark),
+    (0x1BA2, 0x1BA5, G::Extend),
+    (0x1BA6, 0x1BA7, G::SpacingMark),
+    (0x1BA8, 0x1BAD, G::Extend),
+    (0x1BE6, 0x1BE6, G::Extend),
+    (0x1BE7, 0x1BE7, G::SpacingMark),
+    (0x1BE8, 0x1BE9, G::Extend),
+    (0x1BEA, 0x1BEC, G::SpacingMark),
+    (0x1BED, 0x1BED, G::Extend),
+    (0x1BEE, 0x1BEE, G::SpacingMark),
+    (0x1BEF, 0x1BF3, G::Extend),
+    (0x1C24, 0x1C2B, G::SpacingMark),
+    (0x1C2C, 0x1C33, G::Extend),
+    (0x1C34, 0x1C35, G::SpacingMark),
+    (0x1C36, 0x1C37, G::Extend),
+    (0x1CD0, 0x1CD2, G::Extend),
+    (0x1CD4, 0x1CE0, G::Extend),
+    (0x1CE1, 0x1CE1, G::SpacingMark),
+    (0x1CE2, 0x1CE8, G::Extend),
+    (0x1CED, 0x1CED, G::Extend),
+    (0x1CF4, 0x1CF4, G::Extend),
+    (0x1CF7, 0x1CF7, G::SpacingMark),
+    (0x1CF8, 0x1CF9, G::Extend),
+    (0x1DC0, 0x1DFF, G::Extend),
+    (0x200B, 0x200B, G::Control),
+    (0x200C, 0x200C, G::Extend),
+    (0x200D, 0x200D, G::Zwj),
+    (0x200E, 0x200F, G::Control),
+    (0x2028, 0x202E, G::Control),
+    (0x203C, 0x203C, G::ExtendedPictographic),
+    (0x2049, 0x2049, G::ExtendedPictographic),
+    (0x2060, 0x206F, G::Control),
+    (0x20D0, 0x20F0, G::Extend),
+    (0x2122, 0x2122, G::ExtendedPictographic),
+    (0x2139, 0x2139, G::ExtendedPictographic),
+    (0x2194, 0x2199, G::ExtendedPictographic),
+    (0x21A9, 0x21AA, G::ExtendedPictographic),
+    (0x231A, 0x231B, G::ExtendedPictographic),
+    (0x2328, 0x2328, G::ExtendedPictographic),
+    (0x2388, 0x2388, G::ExtendedPictographic),
+    (0x23CF, 0x23CF, G::ExtendedPictographic),
+    (0x23E9, 0x23F3, G::ExtendedPictographic),
+    (0x23F8, 0x23FA, G::ExtendedPictographic),
+    (0x24C2, 0x24C2, G::ExtendedPictographic),
+    (0x25AA, 0x25AB, G::ExtendedPictographic),
+    (0x25B6, 0x25B6, G::ExtendedPictographic),
+    (0x25C0, 0x25C0, G::ExtendedPictographic),
+    (0x25FB, 0x25FE, G::ExtendedPictographic),
+    (0x2600, 0x2605, G::ExtendedPictographic),
+    (0x2607, 0x2612, G::ExtendedPictographic),
+    (0x2614, 0x2685, G::ExtendedPictographic),
+    (0x2690, 0x2705, G::ExtendedPictographic),
+    (0x2708, 0x2712, G::ExtendedPictographic),
+    (0x2714, 0x2714, G::ExtendedPictographic),
+    (0x2716, 0x2716, G::ExtendedPictographic),
+    (0x271D, 0x271D, G::ExtendedPictographic),
+    (0x2721, 0x2721, G::ExtendedPictographic),
+    (0x2728, 0x2728, G::ExtendedPictographic),
+    (0x2733, 0x2734, G::ExtendedPictographic),
+    (0x2744, 0x2744, G::ExtendedPictographic),
+    (0x2747, 0x2747, G::ExtendedPictographic),
+    (0x274C, 0x274C, G::ExtendedPictographic),
+    (0x274E, 0x274E, G::ExtendedPictographic),
+    (0x2753, 0x2755, G::ExtendedPictographic),
+    (0x2757, 0x2757, G::ExtendedPictographic),
+    (0x2763, 0x2767, G::ExtendedPictographic),
+    (0x2795, 0x2797, G::ExtendedPictographic),
+    (0x27A1, 0x27A1, G::ExtendedPictographic),
+    (0x27B0, 0x27B0, G::ExtendedPictographic),
+    (0x27BF, 0x27BF, G::ExtendedPictographic),
+    (0x2934, 0x2935, G::ExtendedPictographic),
+    (0x2B05, 0x2B07, G::ExtendedPictographic),
+    (0x2B1B, 0x2B1C, G::ExtendedPictographic),
+    (0x2B50, 0x2B50, G::ExtendedPictographic),
+    (0x2B55, 0x2B55, G::ExtendedPictographic),
+    (0x2CEF, 0x2CF1, G::Extend),
+    (0x2D7F, 0x2D7F, G::Extend),
+    (0x2DE0, 0x2DFF, G::Extend),
+    (0x302A, 0x302F, G::Extend),
+    (0x3030, 0x3030, G::ExtendedPictographic),
+    (0x303D, 0x303D, G::ExtendedPictographic),
+    (0x3099, 0x309A, G::Extend),
+    (0x3297, 0x3297, G::ExtendedPictographic),
+    (0x3299, 0x3299, G::ExtendedPictographic),
+    (0xA66F, 0xA672, G::Extend),
+    (0xA674, 0xA67D, G::Extend),
+    (0xA69E, 0xA69F, G::Extend),
+    (0xA6F0, 0xA6F1, G::Extend),
+    (0xA802, 0xA802, G::Extend),
+    (0xA806, 0xA806, G::Extend),
+    (0xA80B, 0xA80B, G::Extend),
+    (0xA823, 0xA824, G::SpacingMark),
+    (0xA825, 0xA826, G::Extend),
+    (0xA827, 0xA827, G::SpacingMark),
+    (0xA82C, 0xA82C, G::Extend),
+    (0xA880, 0xA881, G::SpacingMark),
+    (0xA8B4, 0xA8C3, G::SpacingMark),
+    (0xA8C4, 0xA8C5, G::Extend),
+    (0xA8E0, 0xA8F1, G::Extend),
+    (0xA8FF, 0xA8FF, G::Extend),
+    (0xA926, 0xA92D, G::Extend),
+    (0xA947, 0xA951, G::Extend),
+    (0xA952, 0xA952, G::SpacingMark),
+    (0xA953, 0xA953, G::Extend),
+    (0xA960, 0xA97C, G::L),
+    (0xA980, 0xA982, G::Extend),
+    (0xA983, 0xA983, G::SpacingMark),
+    (0xA9B3, 0xA9B3, G::Extend),
+    (0xA9B4, 0xA9B5, G::SpacingMark),
+    (0xA9B6, 0xA9B9, G::Extend),
+    (0xA9BA, 0xA9BB, G::SpacingMark),
+    (0xA9BC, 0xA9BD, G::Extend),
+    (0xA9BE, 0xA9BF, G::SpacingMark),
+    (0xA9C0, 0xA9C0, G::Extend),
+    (0xA9E5, 0xA9E5, G::Extend),
+    (0xAA29, 0xAA2E, G::Extend),
+    (0xAA2F, 0xAA30, G::SpacingMark),
+    (0xAA31, 0xAA32, G::Extend),
+    (0xAA33, 0xAA34, G::SpacingMark),
+    (0xAA35, 0xAA36, G::Extend),
+    (0xAA43, 0xAA43, G::Extend),
+    (0xAA4C, 0xAA4C, G::Extend),
+    (0xAA4D, 0xAA4D, G::SpacingMark),
+    (0xAA7C, 0xAA7C, G::Extend),
+    (0xAAB0, 0xAAB0, G::Extend),
+    (0xAAB2, 0xAAB4, G::Extend),
+    (0xAAB7, 0xAAB8, G::Extend),
+    (0xAABE, 0xAABF, G::Extend),
+    (0xAAC1, 0xAAC1, G::Extend),
+    (0xAAEB, 0xAAEB, G::SpacingMark),
+    (0xAAEC, 0xAAED, G::Extend),
+    (0xAAEE, 0xAAEF, G::SpacingMark),
+    (0xAAF5, 0xAAF5, G::SpacingMark),
+    (0xAAF6, 0xAAF6, G::Extend),
+    (0xABE3, 0xABE4, G::SpacingMark),
+    (0xABE5, 0xABE5, G::Extend),
+    (0xABE6, 0xABE7, G::SpacingMark),
+    (0xABE8, 0xABE8, G::Extend),
+    (0xABE9, 0xABEA, G::SpacingMark),
+    (0xABEC, 0xABEC, G::SpacingMark),
+    (0xABED, 0xABED, G::Extend),
+    (0xAC00, 0xAC00, G::Lv),
+    (0xAC01, 0xAC1B, G::Lvt),
+    (0xAC1C, 0xAC1C, G::Lv),
+    (0xAC1D, 0xAC37, G::Lvt),
+    (0xAC38, 0xAC38, G::Lv),
+    (0xAC39, 0xAC53, G::Lvt),
+    (0xAC54, 0xAC54, G::Lv),
+    (0xAC55, 0xAC6F, G::Lvt),
+    (0xAC70, 0xAC70, G::Lv),
+    (0xAC71, 0xAC8B, G::Lvt),
+    (0xAC8C, 0xAC8C, G::Lv),
+    (0xAC8D, 0xACA7, G::Lvt),
+    (0xACA8, 0xACA8, G::Lv),
+    (0xACA9, 0xACC3, G::Lvt),
+    (0xACC4, 0xACC4, G::Lv),
+    (0xACC5, 0xACDF, G::Lvt),
+    (0xACE0, 0xACE0, G::Lv),
+    (0xACE1, 0xACFB, G::Lvt),
+    (0xACFC, 0xACFC, G::Lv),
+    (0xACFD, 0xAD17, G::Lvt),
+    (0xAD18, 0xAD18, G::Lv),
+    (0xAD19, 0xAD33, G::Lvt),
+    (0xAD34, 0xAD34, G::Lv),
+    (0xAD35, 0xAD4F, G::Lvt),
+    (0xAD50, 0xAD50, G::Lv),
+    (0xAD51, 0xAD6B, G::Lvt),
+    (0xAD6C, 0xAD6C, G::Lv),
+    (0xAD6D, 0xAD87, G::Lvt),
+    (0xAD88, 0xAD88, G::Lv),
+    (0xAD89, 0xADA3, G::Lvt),
+    (0xADA4, 0xADA4, G::Lv),
+    (0xADA5, 0xADBF, G::Lvt),
+    (0xADC0, 0xADC0, G::Lv),
+    (0xADC1, 0xADDB, G::Lvt),
+    (0xADDC, 0xADDC, G::Lv),
+    (0xADDD, 0xADF7, G::Lvt),
+    (0xADF8, 0xADF8, G::Lv),
+    (0xADF9, 0xAE13, G::Lvt),
+    (0xAE14, 0xAE14, G::Lv),
+    (0xAE15, 0xAE2F, G::Lvt),
+    (0xAE30, 0xAE30, G::Lv),
+    (0xAE31, 0xAE4B, G::Lvt),
+    (0xAE4C, 0xAE4C, G::Lv),
+    (0xAE4D, 0xAE67, G::Lvt),
+    (0xAE68, 0xAE68, G::Lv),
+    (0xAE69, 0xAE83, G::Lvt),
+    (0xAE84, 0xAE84, G::Lv),
+    (0xAE85, 0xAE9F, G::Lvt),
+    (0xAEA0, 0xAEA0, G::Lv),
+    (0xAEA1, 0xAEBB, G::Lvt),
+    (0xAEBC, 0xAEBC, G::Lv),
+    (0xAEBD, 0xAED7, G::Lvt),
+    (0xAED8, 0xAED8, G::Lv),
+    (0xAED9, 0xAEF3, G::Lvt),
+    (0xAEF4, 0xAEF4, G::Lv),
+    (0xAEF5, 0xAF0F, G::Lvt),
+    (0xAF10, 0xAF10, G::Lv),
+    (0xAF11, 0xAF2B, G::Lvt),
+    (0xAF2C, 0xAF2C, G::Lv),
+    (0xAF2D, 0xAF47, G::Lvt),
+    (0xAF48, 0xAF48, G::Lv),
+    (0xAF49, 0xAF63, G::Lvt),
+    (0xAF64, 0xAF64, G::Lv),
+    (0xAF65, 0xAF7F, G::Lvt),
+    (0xAF80, 0xAF80, G::Lv),
+    (0xAF81, 0xAF9B, G::Lvt),
+    (0xAF9C, 0xAF9C, G::Lv),
+    (0xAF9D, 0xAFB7, G::Lvt),
+    (0xAFB8, 0xAFB8, G::Lv),
+    (0xAFB9, 0xAFD3, G::Lvt),
+    (0xAFD4, 0xAFD4, G::Lv),
+    (0xAFD5, 0xAFEF, G::Lvt),
+    (0xAFF0, 0xAFF0, G::Lv),
+    (0xAFF1, 0xB00B, G::Lvt),
+    (0xB00C, 0xB00C, G::Lv),
+    (0xB00D, 0xB027, G::Lvt),
+    (0xB028, 0xB028, G::Lv),
+    (0xB029, 0xB043, G::Lvt),
+    (0xB044, 0xB044, G::Lv),
+    (0xB045, 0xB05F, G::Lvt),
+    (0xB060, 0xB060, G::Lv),
+    (0xB061, 0xB07B, G::Lvt),
+    (0xB07C, 0xB07C, G::Lv),
+    (0xB07D, 0xB097, G::Lvt),
+    (0xB098, 0xB098, G::Lv),
+    (0xB099, 0xB0B3, G::Lvt),
+    (0xB0B4, 0xB0B4, G::Lv),
+    (0xB0B5, 0xB0CF, G::Lvt),
+    (0xB0D0, 0xB0D0, G::Lv),
+    (0xB0D1, 0xB0EB, G::Lvt),
+    (0xB0EC, 0xB0EC, G::Lv),
+    (0xB0ED, 0xB107, G::Lvt),
+    (0xB108, 0xB108, G::Lv),
+    (0xB109, 0xB123, G::Lvt),
+    (0xB124, 0xB124, G::Lv),
+    (0xB125, 0xB13F, G::Lvt),
+    (0xB140, 0xB140, G::Lv),
+    (0xB141, 0xB15B, G::Lvt),
+    (0xB15C, 0xB15C, G::Lv),
+    (0xB15D, 0xB177, G::Lvt),
+    (0xB178, 0xB178, G::Lv),
+    (0xB179, 0xB193, G::Lvt),
+    (0xB194, 0xB194, G::Lv),
+    (0xB195, 0xB1AF, G::Lvt),
+    (0xB1B0, 0xB1B0, G::Lv),
+    (0xB1B1, 0xB1CB, G::Lvt),
+    (0xB1CC, 0xB1CC, G::Lv),
+    (0xB1CD, 0xB1E7, G::Lvt),
+    (0xB1E8, 0xB1E8, G::Lv),
+    (0xB1E9, 0xB203, G::Lvt),
+    (0xB204, 0xB204, G::Lv),
+    (0xB205, 0xB21F, G::Lvt),
+    (0xB220, 0xB220, G::Lv),
+    (0xB221, 0xB23B, G::Lvt),
+    (0xB23C, 0xB23C, G::Lv),
+    (0xB23D, 0xB257, G::Lvt),
+    (0xB258, 0xB258, G::Lv),
+    (0xB259, 0xB273, G::Lvt),
+    (0xB274, 0xB274, G::Lv),
+    (0xB275, 0xB28F, G::Lvt),
+    (0xB290, 0xB290, G::Lv),
+    (0xB291, 0xB2AB, G::Lvt),
+    (0xB2AC, 0xB2AC, G::Lv),
+    (0xB2AD, 0xB2C7, G::Lvt),
+    (0xB2C8, 0xB2C8, G::Lv),
+    (0xB2C9, 0xB2E3, G::Lvt),
+    (0xB2E4, 0xB2E4, G::Lv),
+    (0xB2E5, 0xB2FF, G::Lvt),
+    (0xB300, 0xB300, G::Lv),
+    (0xB301, 0xB31B, G::Lvt),
+    (0xB31C, 0xB31C, G::Lv),
+    (0xB31D, 0xB337, G::Lvt),
+    (0xB338, 0xB338, G::Lv),
+    (0xB339, 0xB353, G::Lvt),
+    (0xB354, 0xB354, G::Lv),
+    (0xB355, 0xB36F, G::Lvt),
+    (0xB370, 0xB370, G::Lv),
+    (0xB371, 0xB38B, G::Lvt),
+    (0xB38C, 0xB38C, G::Lv),
+    (0xB38D, 0xB3A7, G::Lvt),
+    (0xB3A8, 0xB3A8, G::Lv),
+    (0xB3A9, 0xB3C3, G::Lvt),
+    (0xB3C4, 0xB3C4, G::Lv),
+    (0xB3C5, 0xB3DF, G::Lvt),
+    (0xB3E0, 0xB3E0, G::Lv),
+    (0xB3E1, 0xB3FB, G::Lvt),
+    (0xB3FC, 0xB3FC, G::Lv),
+    (0xB3FD, 0xB417, G::Lvt),
+    (0xB418, 0xB418, G::Lv),
+    (0xB419, 0xB433, G::Lvt),
+    (0xB434, 0xB434, G::Lv),
+    (0xB435, 0xB44F, G::Lvt),
+    (0xB450, 0xB450, G::Lv),
+    (0xB451, 0xB46B, G::Lvt),
+    (0xB46C, 0xB46C, G::Lv),
+    (0xB46D, 0xB487, G::Lvt),
+    (0xB488, 0xB488, G::Lv),
+    (0xB489, 0xB4A3, G::Lvt),
+    (0xB4A4, 0xB4A4, G::Lv),
+    (0xB4A5, 0xB4BF, G::Lvt),
+    (0xB4C0, 0xB4C0, G::Lv),
+    (0xB4C1, 0xB4DB, G::Lvt),
+    (0xB4DC, 0xB4DC, G::Lv),
+    (0xB4DD, 0xB4F7, G::Lvt),
+    (0xB4F8, 0xB4F8, G::Lv),
+    (0xB4F9, 0xB513, G::Lvt),
+    (0xB514, 0xB514, G::Lv),
+    (0xB515, 0xB52F, G::Lvt),
+    (0xB530, 0xB530, G::Lv),
+    (0xB531, 0xB54B, G::Lvt),
+    (0xB54C, 0xB54C, G::Lv),
+    (0xB54D, 0xB567, G::Lvt),
+    (0xB568, 0xB568, G::Lv),
+    (0xB569, 0xB583, G::Lvt),
+    (0xB584, 0xB584, G::Lv),
+    (0xB585, 0xB59F, G::Lvt),
+    (0xB5A0, 0xB5A0, G::Lv),
+    (0xB5A1, 0xB5BB, G::Lvt),
+    (0xB5BC, 0xB5BC, G::Lv),
+    (0xB5BD, 0xB5D7, G::Lvt),
+    (0xB5D8, 0xB5D8, G::Lv),
+    (0xB5D9, 0xB5F3, G::Lvt),
+    (0xB5F4, 0xB5F4, G::Lv),
+    (0xB5F5, 0xB60F, G::Lvt),
+    (0xB610, 0xB610, G::Lv),
+    (0xB611, 0xB62B, G::Lvt),
+    (0xB62C, 0xB62C, G::Lv),
+    (0xB62D, 0xB647, G::Lvt),
+    (0xB648, 0xB648, G::Lv),
+    (0xB649, 0xB663, G::Lvt),
+    (0xB664, 0xB664, G::Lv),
+    (0xB665, 0xB67F, G::Lvt),
+    (0xB680, 0xB680, G::Lv),
+    (0xB681, 0xB69B, G::Lvt),
+    (0xB69C, 0xB69C, G::Lv),
+    (0xB69D, 0xB6B7, G::Lvt),
+    (0xB6B8, 0xB6B8, G::Lv),
+    (0xB6B9, 0xB6D3, G::Lvt),
+    (0xB6D4, 0xB6D4, G::Lv),
+    (0xB6D5, 0xB6EF, G::Lvt),
+    (0xB6F0, 0xB6F0, G::Lv),
+    (0xB6F1, 0xB70B, G::Lvt),
+    (0xB70C, 0xB70C, G::Lv),
+    (0xB70D, 0xB727, G::Lvt),
+    (0xB728, 0xB728, G::Lv),
+    (0xB729, 0xB743, G::Lvt),
+    (0xB744, 0xB744, G::Lv),
+    (0xB745, 0xB75F, G::Lvt),
+    (0xB760, 0xB760, G::Lv),
+    (0xB761, 0xB77B, G::Lvt),
+    (0xB77C, 0xB77C, G::Lv),
+    (0xB77D, 0xB797, G::Lvt),
+    (0xB798, 0xB798, G::Lv),
+    (0xB799, 0xB7B3, G::Lvt),
+    (0xB7B4, 0xB7B4, G::Lv),
+    (0xB7B5, 0xB7CF, G::Lvt),
+    (0xB7D0, 0xB7D0, G::Lv),
+    (0xB7D1, 0xB7EB, G::Lvt),
+    (0xB7EC, 0xB7EC, G::Lv),
+    (0xB7ED, 0xB807, G::Lvt),
+    (0xB808, 0xB808, G::Lv),
+    (0xB809, 0xB823, G::Lvt),
+    (0xB824, 0xB824, G::Lv),
+    (0xB825, 0xB83F, G::Lvt),
+    (0xB840, 0xB840, G::Lv),
+    (0xB841, 0xB85B, G::Lvt),
+    (0xB85C, 0xB85C, G::Lv),
+    (0xB85D, 0xB877, G::Lvt),
+    (0xB878, 0xB878, G::Lv),
+    (0xB879, 0xB893, G::Lvt),
+    (0xB894, 0xB894, G::Lv),
+    (0xB895, 0xB8AF, G::Lvt),
+    (0xB8B0, 0xB8B0, G::Lv),
+    (0xB8B1, 0xB8CB, G::Lvt),
+    (0xB8CC, 0xB8CC, G::Lv),
+    (0xB8CD, 0xB8E7, G::Lvt),
+    (0xB8E8, 0xB8E8, G::Lv),
+    (0xB8E9, 0xB903, G::Lvt),
+    (0xB904, 0xB904, G::Lv),
+    (0xB905, 0xB91F, G::Lvt),
+    (0xB920, 0xB920, G::Lv),
+    (0xB921, 0xB93B, G::Lvt),
+    (0xB93C, 0xB93C, G::Lv),
+    (0xB93D, 0xB957, G::Lvt),
+    (0xB958, 0xB958, G::Lv),
+    (0xB959, 0xB973, G::Lvt),
+    (0xB974, 0xB974, G::Lv),
+    (0xB975, 0xB98F, G::Lvt),
+    (0xB990, 0xB990, G::Lv),
+    (0xB991, 0xB9AB, G::Lvt),
+    (0xB9AC, 0xB9AC, G::Lv),
+    (0xB9AD, 0xB9C7, G::Lvt),
+    (0xB9C8, 0xB9C8, G::Lv),
+    (0xB9C9, 0xB9E3, G::Lvt),
+    (0xB9E4, 0xB9E4, G::Lv),
+    (0xB9E5, 0xB9FF, G::Lvt),
+    (0xBA00, 0xBA00, G::Lv),
+    (0xBA01, 0xBA1B, G::Lvt),
+    (0xBA1C, 0xBA1C, G::Lv),
+    (0xBA1D, 0xBA37, G::Lvt),
+    (0xBA38, 0xBA38, G::Lv),
+    (0xBA39, 0xBA53, G::Lvt),
+    (0xBA54, 0xBA54, G::Lv),
+    (0xBA55, 0xBA6F, G::Lvt),
+    (0xBA70, 0xBA70, G::Lv),
+    (0xBA71, 0xBA8B, G::Lvt),
+    (0xBA8C, 0xBA8C, G::Lv),
+    (0xBA8D, 0xBAA7, G::Lvt),
+    (0xBAA8, 0xBAA8, G::Lv),
+    (0xBAA9, 0xBAC3, G::Lvt),
+    (0xBAC4, 0xBAC4, G::Lv),
+    (0xBAC5, 0xBADF, G::Lvt),
+    (0xBAE0, 0xBAE0, G::Lv),
+    (0xBAE1, 0xBAFB, G::Lvt),
+    (0xBAFC, 0xBAFC, G::Lv),
+    (0xBAFD, 0xBB17, G::Lvt),
+    (0xBB18, 0xBB18, G::Lv),
+    (0xBB19, 0xBB33, G::Lvt),
+    (0xBB34, 0xBB34, G::Lv),
+    (0xBB35, 0xBB4F, G::Lvt),
+    (0xBB50, 0xBB50, G::Lv),
+    (0xBB51, 0xBB6B, G::Lvt),
+    (0xBB6C, 0xBB6C, G::Lv),
+    (0xBB6D, 0xBB87, G::Lvt),
+    (0xBB88, 0xBB88, G::Lv),
+    (0xBB89, 0xBBA3, G::Lvt),
+    (0xBBA4, 0xBBA4, G::Lv),
+    (0xBBA5, 0xBBBF, G::Lvt),
+    (0xBBC0, 0xBBC0, G::Lv),
+    (0xBBC1, 0xBBDB, G::Lvt),
+    (0xBBDC, 0xBBDC, G::Lv),
+    (0xBBDD, 0xBBF7, G::Lvt),
+    (0xBBF8, 0xBBF8, G::Lv),
+    (0xBBF9, 0xBC13, G::Lvt),
+    (0xBC14, 0xBC14, G::Lv),
+    (0xBC15, 0xBC2F, G::Lvt),
+    (0xBC30, 0xBC30, G::Lv),
+    (0xBC31, 0xBC4B, G::Lvt),
+    (0xBC4C, 0xBC4C, G::Lv),
+    (0xBC4D, 0xBC67, G::Lvt),
+    (0xBC68, 0xBC68, G::Lv),
+    (0xBC69, 0xBC83, G::Lvt),
+    (0xBC84, 0xBC84, G::Lv),
+    (0xBC85, 0xBC9F, G::Lvt),
+    (0xBCA0, 0xBCA0, G::Lv),
+    (0xBCA1, 0xBCBB, G::Lvt),
+    (0xBCBC, 0xBCBC, G::Lv),
+    (0xBCBD, 0xBCD7, G::Lvt),
+    (0xBCD8, 0xBCD8, G::Lv),
+    (0xBCD9, 0xBCF3, G::Lvt),
+    (0xBCF4, 0xBCF4, G::Lv),
+    (0xBCF5, 0xBD0F, G::Lvt),
+    (0xBD10, 0xBD10, G::Lv),
+    (0xBD11, 0xBD2B, G::Lvt),
+    (0xBD2C, 0xBD2C, G::Lv),
+    (0xBD2D, 0xBD47, G::Lvt),
+    (0xBD48, 0xBD48, G::Lv),
+    (0xBD49, 0xBD63, G::Lvt),
+    (0xBD64, 0xBD64, G::Lv),
+    (0xBD65, 0xBD7F, G::Lvt),
+    (0xBD80, 0xBD80, G::Lv),
+    (0xBD81, 0xBD9B, G::Lvt),
+    (0xBD9C, 0xBD9C, G::Lv),
+    (0xBD9D, 0xBDB7, G::Lvt),
+    (0xBDB8, 0xBDB8, G::Lv),
+    (0xBDB9, 0xBDD3, G::Lvt),
+    (0xBDD4, 0xBDD4, G::Lv),
+    (0xBDD5, 0xBDEF, G::Lvt),
+    (0xBDF0, 0xBDF0, G::Lv),
+    (0xBDF1, 0xBE0B, G::Lvt),
+    (0xBE0C, 0xBE0C, G::Lv),
+    (0xBE0D, 0xBE27, G::Lvt),
+    (0xBE28, 0xBE28, G::Lv),
+    (0xBE29, 0xBE43, G::Lvt),
+    (0xBE44, 0xBE44, G::Lv),
+    (0xBE45, 0xBE5F, G::Lvt),
+    (0xBE60, 0xBE60, G::Lv),
+    (0xBE61, 0xBE7B, G::Lvt),
+    (0xBE7C, 0xBE7C, G::Lv),
+    (0xBE7D, 0xBE97, G::Lvt),
+    (0xBE98, 0xBE98, G::Lv),
+    (0xBE99, 0xBEB3, G::Lvt),
+    (0xBEB4, 0xBEB4, G::Lv),
+    (0xBEB5, 0xBECF, G::Lvt),
+    (0xBED0, 0xBED0, G::Lv),
+    (0xBED1, 0xBEEB, G::Lvt),
+    (0xBEEC, 0xBEEC, G::Lv),
+    (0xBEED, 0xBF07, G::Lvt),
+    (0xBF08, 0xBF08, G::Lv),
+    (0xBF09, 0xBF23, G::Lvt),
+    (0xBF24, 0xBF24, G::Lv),
+    (0xBF25, 0xBF3F, G::Lvt),
+    (0xBF40, 0xBF40, G::Lv),
+    (0xBF41, 0xBF5B, G::Lvt),
+    (0xBF5C, 0xBF5C, G::Lv),
+    (0xBF5D, 0xBF77, G::Lvt),
+    (0xBF78, 0xBF78, G::Lv),
+    (0xBF79, 0xBF93, G::Lvt),
+    (0xBF94, 0xBF94, G::Lv),
+    (0xBF95, 0xBFAF, G::Lvt),
+    (0xBFB0, 0xBFB0, G::Lv),
+    (0xBFB1, 0xBFCB, G::Lvt),
+    (0xBFCC, 0xBFCC, G::Lv),
+    (0xBFCD, 0xBFE7, G::Lvt),
+    (0xBFE8, 0xBFE8, G::Lv),
+    (0xBFE9, 0xC003, G::Lvt),
+    (0xC004, 0xC004, G::Lv),
+    (0xC005, 0xC01F, G::Lvt),
+    (0xC020, 0xC020, G::Lv),
+    (0xC021, 0xC03B, G::Lvt),
+    (0xC03C, 0xC03C, G::Lv),
+    (0xC03D, 0xC057, G::Lvt),
+    (0xC058, 0xC058, G::Lv),
+    (0xC059, 0xC073, G::Lvt),
+    (0xC074, 0xC074, G::Lv),
+    (0xC075, 0xC08F, G::Lvt),
+    (0xC090, 0xC090, G::Lv),
+    (0xC091, 0xC0AB, G::Lvt),
+    (0xC0AC, 0xC0AC, G::Lv),
+    (0xC0AD, 0xC0C7, G::Lvt),
+    (0xC0C8, 0xC0C8, G::Lv),
+    (0xC0C9, 0xC0E3, G::Lvt),
+    (0xC0E4, 0xC0E4, G::Lv),
+    (0xC0E5, 0xC0FF, G::Lvt),
+    (0xC100, 0xC100, G::Lv),
+    (0xC101, 0xC11B, G::Lvt),
+    (0xC11C, 0xC11C, G::Lv),
+    (0xC11D, 0xC137, G::Lvt),
+    (0xC138, 0xC138, G::Lv),
+    (0xC139, 0xC153, G::Lvt),
+    (0xC154, 0xC154, G::Lv),
+    (0xC155, 0xC16F, G::Lvt),
+    (0xC170, 0xC170, G::Lv),
+    (0xC171, 0xC18B, G::Lvt),
+    (0xC18C, 0xC18C, G::Lv),
+    (0xC18D, 0xC1A7, G::Lvt),
+    (0xC1A8, 0xC1A8, G::Lv),
+    (0xC1A9, 0xC1C3, G::Lvt),
+    (0xC1C4, 0xC1C4, G::Lv),
+    (0xC1C5, 0xC1DF, G::Lvt),
+    (0xC1E0, 0xC1E0, G::Lv),
+    (0xC1E1, 0xC1FB, G::Lvt),
+    (0xC1FC, 0xC1FC, G::Lv),
+    (0xC1FD, 0xC217, G::Lvt),
+    (0xC218, 0xC218, G::Lv),
+    (0xC219, 0xC233, G::Lvt),
+    (0xC234, 0xC234, G::Lv),
+    (0xC235, 0xC24F, G::Lvt),
+    (0xC250, 0xC250, G::Lv),
+    (0xC251, 0xC26B, G::Lvt),
+    (0xC26C, 0xC26C, G::Lv),
+    (0xC26D, 0xC287, G::Lvt),
+    (0xC288, 0xC288, G::Lv),
+    (0xC289, 0xC2A3, G::Lvt),
+    (0xC2A4, 0xC2A4, G::Lv),
+    (0xC2A5, 0xC2BF, G::Lvt),
+    (0xC2C0, 0xC2C0, G::Lv),
+    (0xC2C1, 0xC2DB, G::Lvt),
+    (0xC2DC, 0xC2DC, G::Lv),
+    (0xC2DD, 0xC2F7, G::Lvt),
+    (0xC2F8, 0xC2F8, G::Lv),
+    (0xC2F9, 0xC313, G::Lvt),
+    (0xC314, 0xC314, G::Lv),
+    (0xC315, 0xC32F, G::Lvt),
+    (0xC330, 0xC330, G::Lv),
+    (0xC331, 0xC34B, G::Lvt),
+    (0xC34C, 0xC34C, G::Lv),
+    (0xC34D, 0xC367, G::Lvt),
+    (0xC368, 0xC368, G::Lv),
+    (0xC369, 0xC383, G::Lvt),
+    (0xC384, 0xC384, G::Lv),
+    (0xC385, 0xC39F, G::Lvt),
+    (0xC3A0, 0xC3A0, G::Lv),
+    (0xC3A1, 0xC3BB, G::Lvt),
+    (0xC3BC, 0xC3BC, G::Lv),
+    (0xC3BD, 0xC3D7, G::Lvt),
+    (0xC3D8, 0xC3D8, G::Lv),
+    (0xC3D9, 0xC3F3, G::Lvt),
+    (0xC3F4, 0xC3F4, G::Lv),
+    (0xC3F5, 0xC40F, G::Lvt),
+    (0xC410, 0xC410, G::Lv),
+    (0xC411, 0xC42B, G::Lvt),
+    (0xC42C, 0xC42C, G::Lv),
+    (0xC42D, 0xC447, G::Lvt),
+    (0xC448, 0xC448, G::Lv),
+    (0xC449, 0xC463, G::Lvt),
+    (0xC464, 0xC464, G::Lv),
+    (0xC465, 0xC47F, G::Lvt),
+    (0xC480, 0xC480, G::Lv),
+    (0xC481, 0xC49B, G::Lvt),
+    (0xC49C, 0xC49C, G::Lv),
+    (0xC49D, 0xC4B7, G::Lvt),
+    (0xC4B8, 0xC4B8, G::Lv),
+    (0xC4B9, 0xC4D3, G::Lvt),
+    (0xC4D4, 0xC4D4, G::Lv),
+    (0xC4D5, 0xC4EF, G::Lvt),
+    (0xC4F0, 0xC4F0, G::Lv),
+    (0xC4F1, 0xC50B, G::Lvt),
+    (0xC50C, 0xC50C, G::Lv),
+    (0xC50D, 0xC527, G::Lvt),
+    (0xC528, 0xC528, G::Lv),
+    (0xC529, 0xC543, G::Lvt),
+    (0xC544, 0xC544, G::Lv),
+    (0xC545, 0xC55F, G::Lvt),
+    (0xC560, 0xC560, G::Lv),
+    (0xC561, 0xC57B, G::Lvt),
+    (0xC57C, 0xC57C, G::Lv),
+    (0xC57D, 0xC597, G::Lvt),
+    (0xC598, 0xC598, G::Lv),
+    (0xC599, 0xC5B3, G::Lvt),
+    (0xC5B4, 0xC5B4, G::Lv),
+    (0xC5B5, 0xC5CF, G::Lvt),
+    (0xC5D0, 0xC5D0, G::Lv),
+    (0xC5D1, 0xC5EB, G::Lvt),
+    (0xC5EC, 0xC5EC, G::Lv),
+    (0xC5ED, 0xC607, G::Lvt),
+    (0xC608, 0xC608, G::Lv),
+    (0xC609, 0xC623, G::Lvt),
+    (0xC624, 0xC624, G::Lv),
+    (0xC625, 0xC63F, G::Lvt),
+    (0xC640, 0xC640, G::Lv),
+    (0xC641, 0xC65B, G::Lvt),
+    (0xC65C, 0xC65C, G::Lv),
+    (0xC65D, 0xC677, G::Lvt),
+    (0xC678, 0xC678, G::Lv),
+    (0xC679, 0xC693, G::Lvt),
+    (0xC694, 0xC694, G::Lv),
+    (0xC695, 0xC6AF, G::Lvt),
+    (0xC6B0, 0xC6B0, G::Lv),
+    (0xC6B1, 0xC6CB, G::Lvt),
+    (0xC6CC, 0xC6CC, G::Lv),
+    (0xC6CD, 0xC6E7, G::Lvt),
+    (0xC6E8, 0xC6E8, G::Lv),
+    (0xC6E9, 0xC703, G::Lvt),
+    (0xC704, 0xC704, G::Lv),
+    (0xC705, 0xC71F, G::Lvt),
+    (0xC720, 0xC720, G::Lv),
+    (0xC721, 0xC73B, G::Lvt),
+    (0xC73C, 0xC73C, G::Lv),
+    (0xC73D, 0xC757, G::Lvt),
+    (0xC758, 0xC758, G::Lv),
+    (0xC759, 0xC773, G::Lvt),
+    (0xC774, 0xC774, G::Lv),
+    (0xC775, 0xC78F, G::Lvt),
+    (0xC790, 0xC790, G::Lv),
+    (0xC791, 0xC7AB, G::Lvt),
+    (0xC7AC, 0xC7AC, G::Lv),
+    (0xC7AD, 0xC7C7, G::Lvt),
+    (0xC7C8, 0xC7C8, G::Lv),
+    (0xC7C9, 0xC7E3, G::Lvt),
+    (0xC7E4, 0xC7E4, G::Lv),
+    (0xC7E5, 0xC7FF, G::Lvt),
+    (0xC800, 0xC800, G::Lv),
+    (0xC801, 0xC81B, G::Lvt),
+    (0xC81C, 0xC81C, G::Lv),
+    (0xC81D, 0xC837, G::Lvt),
+    (0xC838, 0xC838, G::Lv),
+    (0xC839, 0xC853, G::Lvt),
+    (0xC854, 0xC854, G::Lv),
+    (0xC855, 0xC86F, G::Lvt),
+    (0xC870, 0xC870, G::Lv),
+    (0xC871, 0xC88B, G::Lvt),
+    (0xC88C, 0xC88C, G::Lv),
+    (0xC88D, 0xC8A7, G::Lvt),
+    (0xC8A8, 0xC8A8, G::Lv),
+    (0xC8A9, 0xC8C3, G::Lvt),
+    (0xC8C4, 0xC8C4, G::Lv),
+    (0xC8C5, 0xC8DF, G::Lvt),
+    (0xC8E0, 0xC8E0, G::Lv),
+    (0xC8E1, 0xC8FB, G::Lvt),
+    (0xC8FC, 0xC8FC, G::Lv),
+    (0xC8FD, 0xC917, G::Lvt),
+    (0xC918, 0xC918, G::Lv),
+    (0xC919, 0xC933, G::Lvt),
+    (0xC934, 0xC934, G::Lv),
+    (0xC935, 0xC94F, G::Lvt),
+    (0xC950, 0xC950, G::Lv),
+    (0xC951, 0xC96B, G::Lvt),
+    (0xC96C, 0xC96C, G::Lv),
+    (0xC96D, 0xC987, G::Lvt),
+    (0xC988, 0xC988, G::Lv),
+    (0xC989, 0xC9A3, G::Lvt),
+    (0xC9A4, 0xC9A4, G::Lv),
+    (0xC9A5, 0xC9BF, G::Lvt),
+    (0xC9C0, 0xC9C0, G::Lv),
+    (0xC9C1, 0xC9DB, G::Lvt),
+    (0xC9DC, 0xC9DC, G::Lv),
+    (0xC9DD, 0xC9F7, G::Lvt),
+    (0xC9F8, 0xC9F8, G::Lv),
+    (0xC9F9, 0xCA13, G::Lvt),
+    (0xCA14, 0xCA14, G::Lv),
+    (0xCA15, 0xCA2F, G::Lvt),
+    (0xCA30, 0xCA30, G::Lv),
+    (0xCA31, 0xCA4B, G::Lvt),
+    (0xCA4C, 0xCA4C, G::Lv),
+    (0xCA4D, 0xCA67, G::Lvt),
+    (0xCA68, 0xCA68, G::Lv),
+    (0xCA69, 0xCA83, G::Lvt),
+    (0xCA84, 0xCA84, G::Lv),
+    (0xCA85, 0xCA9F, G::Lvt),
+    (0xCAA0, 0xCAA0, G::Lv),
+    (0xCAA1, 0xCABB, G::Lvt),
+    (0xCABC, 0xCABC, G::Lv),
+    (0xCABD, 0xCAD7, G::Lvt),
+    (0xCAD8, 0xCAD8, G::Lv),
+    (0xCAD9, 0xCAF3, G::Lvt),
+    (0xCAF4, 0xCAF4, G::Lv),
+    (0xCAF5, 0xCB0F, G::Lvt),
+    (0xCB10, 0xCB10, G::Lv),
+    (0xCB11, 0xCB2B, G::Lvt),
+    (0xCB2C, 0xCB2C, G::Lv),
+    (0xCB2D, 0xCB47, G::Lvt),
+    (0xCB48, 0xCB48, G::Lv),
+    (0xCB49, 0xCB63, G::Lvt),
+    (0xCB64, 0xCB64, G::Lv),
+    (0xCB65, 0xCB7F, G::Lvt),
+    (0xCB80, 0xCB80, G::Lv),
+    (0xCB81, 0xCB9B, G::Lvt),
+    (0xCB9C, 0xCB9C, G::Lv),
+    (0xCB9D, 0xCBB7, G::Lvt),
+    (0xCBB8, 0xCBB8, G::Lv),
+    (0xCBB9, 0xCBD3, G::Lvt),
+    (0xCBD4, 0xCBD4, G::Lv),
+    (0xCBD5, 0xCBEF, G::Lvt),
+    (0xCBF0, 0xCBF0, G::Lv),
+    (0xCBF1, 0xCC0B, G::Lvt),
+    (0xCC0C, 0xCC0C, G::Lv),
+    (0xCC0D, 0xCC27, G::Lvt),
+    (0xCC28, 0xCC28, G::Lv),
+    (0xCC29, 0xCC43, G::Lvt),
+    (0xCC44, 0xCC44, G::Lv),
+    (0xCC45, 0xCC5F, G::Lvt),
+    (0xCC60, 0xCC60, G::Lv),
+    (0xCC61, 0xCC7B, G::Lvt),
+    (0xCC7C, 0xCC7C, G::Lv),
+    (0xCC7D, 0xCC97, G::Lvt),
+    (0xCC98, 0xCC98, G::Lv),
+    (0xCC99, 0xCCB3, G::Lvt),
+    (0xCCB4, 0xCCB4, G::Lv),
+    (0xCCB5, 0xCCCF, G::Lvt),
+    (0xCCD0, 0xCCD0, G::Lv),
+    (0xCCD1, 0xCCEB, G::Lvt),
+    (0xCCEC, 0xCCEC, G::Lv),
+    (0xCCED, 0xCD07, G::Lvt),
+    (0xCD08, 0xCD08, G::Lv),
+    (0xCD09, 0xCD23, G::Lvt),
+    (0xCD24, 0xCD24, G::Lv),
+    (0xCD25, 0xCD3F, G::Lvt),
+    (0xCD40, 0xCD40, G::Lv),
+    (0xCD41, 0xCD5B, G::Lvt),
+    (0xCD5C, 0xCD5C, G::Lv),
+    (0xCD5D, 0xCD77, G::Lvt),
+    (0xCD78, 0xCD78, G::Lv),
+    (0xCD79, 0xCD93, G::Lvt),
+    (0xCD94, 0xCD94, G::Lv),
+    (0xCD95, 0xCDAF, G::Lvt),
+    (0xCDB0, 0xCDB0, G::Lv),
+    (0xCDB1, 0xCDCB, G::Lvt),
+    (0xCDCC, 0xCDCC, G::Lv),
+    (0xCDCD, 0xCDE7, G::Lvt),
+    (0xCDE8, 0xCDE8, G::Lv),
+    (0xCDE9, 0xCE03, G::Lvt),
+    (0xCE04, 0xCE04, G::Lv),
+    (0xCE05, 0xCE1F, G::Lvt),
+    (0xCE20, 0xCE20, G::Lv),
+    (0xCE21, 0xCE3B, G::Lvt),
+    (0xCE3C, 0xCE3C, G::Lv),
+    (0xCE3D, 0xCE57, G::Lvt),
+    (0xCE58, 0xCE58, G::Lv),
+    (0xCE59, 0xCE73, G::Lvt),
+    (0xCE74, 0xCE74, G::Lv),
+    (0xCE75, 0xCE8F, G::Lvt),
+    (0xCE90, 0xCE90, G::Lv),
+    (0xCE91, 0xCEAB, G::Lvt),
+    (0xCEAC, 0xCEAC, G::Lv),
+    (0xCEAD, 0xCEC7, G::Lvt),
+    (0xCEC8, 0xCEC8, G::Lv),
+    (0xCEC9, 0xCEE3, G::Lvt),
+    (0xCEE4, 0xCEE4, G::Lv),
+    (0xCEE5, 0xCEFF, G::Lvt),
+    (0xCF00, 0xCF00, G::Lv),
+    (0xCF01, 0xCF1B, G::Lvt),
+    (0xCF1C, 0xCF1C, G::Lv),
+    (0xCF1D, 0xCF37, G::Lvt),
+    (0xCF38, 0xCF38, G::Lv),
+    (0xCF39, 0xCF53, G::Lvt),
+    (0xCF54, 0xCF54, G::Lv),
+    (0xCF55, 0xCF6F, G::Lvt),
+    (0xCF70, 0xCF70, G::Lv),
+    (0xCF71, 0xCF8B, G::Lvt),
+    (0xCF8C, 0xCF8C, G::Lv),
+    (0xCF8D, 0xCFA7, G::Lvt),
+    (0xCFA8, 0xCFA8, G::Lv),
+    (0xCFA9, 0xCFC3, G::Lvt),
+    (0xCFC4, 0xCFC4, G::Lv),
+    (0xCFC5, 0xCFDF, G::Lvt),
+    (0xCFE0, 0xCFE0, G::Lv),
+    (0xCFE1, 0xCFFB, G::Lvt),
+    (0xCFFC, 0xCFFC, G::Lv),
+    (0xCFFD, 0xD017, G::Lvt),
+    (0xD018, 0xD018, G::Lv),
+    (0xD019, 0xD033, G::Lvt),
+    (0xD034, 0xD034, G::Lv),
+    (0xD035, 0xD04F, G::Lvt),
+    (0xD050, 0xD050, G::Lv),
+    (0xD051, 0xD06B, G::Lvt),
+    (0xD06C, 0xD06C, G::Lv),
+    (0xD06D, 0xD087, G::Lvt),
+    (0xD088, 0xD088, G::Lv),
+    (0xD089, 0xD0A3, G::Lvt),
+    (0xD0A4, 0xD0A4, G::Lv),
+    (0xD0A5, 0xD0BF, G::Lvt),
+    (0xD0C0, 0xD0C0, G::Lv),
+    (0xD0C1, 0xD0DB, G::Lvt),
+    (0xD0DC, 0xD0DC, G::Lv),
+    (0xD0DD, 0xD0F7, G::Lvt),
+    (0xD0F8, 0xD0F8, G::Lv),
+    (0xD0F9, 0xD113, G::Lvt),
+    (0xD114, 0xD114, G::Lv),
+    (0xD115, 0xD12F, G::Lvt),
+    (0xD130, 0xD130, G::Lv),
+    (0xD131, 0xD14B, G::Lvt),
+    (0xD14C, 0xD14C, G::Lv),
+    (0xD14D, 0xD167, G::Lvt),
+    (0xD168, 0xD168, G::Lv),
+    (0xD169, 0xD183, G::Lvt),
+    (0xD184, 0xD184, G::Lv),
+    (0xD185, 0xD19F, G::Lvt),
+    (0xD1A0, 0xD1A0, G::Lv),
+    (0xD1A1, 0xD1BB, G::Lvt),
+    (0xD1BC, 0xD1BC, G::Lv),
+    (0xD1BD, 0xD1D7, G::Lvt),
+    (0xD1D8, 0xD1D8, G::Lv),
+    (0xD1D9, 0xD1F3, G::Lvt),
+    (0xD1F4, 0xD1F4, G::Lv),
+    (0xD1F5, 0xD20F, G::Lvt),
+    (0xD210, 0xD210, G::Lv),
+    (0xD211, 0xD22B, G::Lvt),
+    (0xD22C, 0xD22C, G::Lv),
+    (0xD22D, 0xD247, G::Lvt),
+    (0xD248, 0xD248, G::Lv),
+    (0xD249, 0xD263, G::Lvt),
+    (0xD264, 0xD264, G::Lv),
+    (0xD265, 0xD27F, G::Lvt),
+    (0xD280, 0xD280, G::Lv),
+    (0xD281, 0xD29B, G::Lvt),
+    (0xD29C, 0xD29C, G::Lv),
+    (0xD29D, 0xD2B7, G::Lvt),
+    (0xD2B8, 0xD2B8, G::Lv),
+    (0xD2B9, 0xD2D3, G::Lvt),
+    (0xD2D4, 0xD2D4, G::Lv),
+    (0xD2D5, 0xD2EF, G::Lvt),
+    (0xD2F0, 0xD2F0, G::Lv),
+    (0xD2F1, 0xD30B, G::Lvt),
+    (0xD30C, 0xD30C, G::Lv),
+    (0xD30D, 0xD327, G::Lvt),
+    (0xD328, 0xD328, G::Lv),
+    (0xD329, 0xD343, G::Lvt),
+    (0xD344, 0xD344, G::Lv),
+    (0xD345, 0xD35F, G::Lvt),
+    (0xD360, 0xD360, G::Lv),
+    (0xD361, 0xD37B, G::Lvt),
+    (0xD37C, 0xD37C, G::Lv),
+    (0xD37D, 0xD397, G::Lvt),
+    (0xD398, 0xD398, G::Lv),
+    (0xD399, 0xD3B3, G::Lvt),
+    (0xD3B4, 0xD3B4, G::Lv),
+    (0xD3B5, 0xD3CF, G::Lvt),
+    (0xD3D0, 0xD3D0, G::Lv),
+    (0xD3D1, 0xD3EB, G::Lvt),
+    (0xD3EC, 0xD3EC, G::Lv),
+    (0xD3ED, 0xD407, G::Lvt),
+    (0xD408, 0xD408, G::Lv),
+    (0xD409, 0xD423, G::Lvt),
+    (0xD424, 0xD424, G::Lv),
+    (0xD425, 0xD43F, G::Lvt),
+    (0xD440, 0xD440, G::Lv),
+    (0xD441, 0xD45B, G::Lvt),
+    (0xD45C, 0xD45C, G::Lv),
+    (0xD45D, 0xD477, G::Lvt),
+    (0xD478, 0xD478, G::Lv),
+    (0xD479, 0xD493, G::Lvt),
+    (0xD494, 0xD494, G::Lv),
+    (0xD495, 0xD4AF, G::Lvt),
+    (0xD4B0, 0xD4B0, G::Lv),
+    (0xD4B1, 0xD4CB, G::Lvt),
+    (0xD4CC, 0xD4CC, G::Lv),
+    (0xD4CD, 0xD4E7, G::Lvt),
+    (0xD4E8, 0xD4E8, G::Lv),
+    (0xD4E9, 0xD503, G::Lvt),
+    (0xD504, 0xD504, G::Lv),
+    (0xD505, 0xD51F, G::Lvt),
+    (0xD520, 0xD520, G::Lv),
+    (0xD521, 0xD53B, G::Lvt),
+    (0xD53C, 0xD53C, G::Lv),
+    (0xD53D, 0xD557, G::Lvt),
+    (0xD558, 0xD558, G::Lv),
+    (0xD559, 0xD573, G::Lvt),
+    (0xD574, 0xD574, G::Lv),
+    (0xD575, 0xD58F, G::Lvt),
+    (0xD590, 0xD590, G::Lv),
+    (0xD591, 0xD5AB, G::Lvt),
+    (0xD5AC, 0xD5AC, G::Lv),
+    (0xD5AD, 0xD5C7, G::Lvt),
+    (0xD5C8, 0xD5C8, G::Lv),
+    (0xD5C9, 0xD5E3, G::Lvt),
+    (0xD5E4, 0xD5E4, G::Lv),
+    (0xD5E5, 0xD5FF, G::Lvt),
+    (0xD600, 0xD600, G::Lv),
+    (0xD601, 0xD61B, G::Lvt),
+    (0xD61C, 0xD61C, G::Lv),
+    (0xD61D, 0xD637, G::Lvt),
+    (0xD638, 0xD638, G::Lv),
+    (0xD639, 0xD653, G::Lvt),
+    (0xD654, 0xD654, G::Lv),
+    (0xD655, 0xD66F, G::Lvt),
+    (0xD670, 0xD670, G::Lv),
+    (0xD671, 0xD68B, G::Lvt),
+    (0xD68C, 0xD68C, G::Lv),
+    (0xD68D, 0xD6A7, G::Lvt),
+    (0xD6A8, 0xD6A8, G::Lv),
+    (0xD6A9, 0xD6C3, G::Lvt),
+    (0xD6C4, 0xD6C4, G::Lv),
+    (0xD6C5, 0xD6DF, G::Lvt),
+    (0xD6E0, 0xD6E0, G::Lv),
+    (0xD6E1, 0xD6FB, G::Lvt),
+    (0xD6FC, 0xD6FC, G::Lv),
+    (0xD6FD, 0xD717, G::Lvt),
+    (0xD718, 0xD718, G::Lv),
+    (0xD719, 0xD733, G::Lvt),
+    (0xD734, 0xD734, G::Lv),
+    (0xD735, 0xD74F, G::Lvt),
+    (0xD750, 0xD750, G::Lv),
+    (0xD751, 0xD76B, G::Lvt),
+    (0xD76C, 0xD76C, G::Lv),
+    (0xD76D, 0xD787, G::Lvt),
+    (0xD788, 0xD788, G::Lv),
+    (0xD789, 0xD7A3, G::Lvt),
+    (0xD7B0, 0xD7C6, G::V),
+    (0xD7CB, 0xD7FB, G::T),
+    (0xFB1E, 0xFB1E, G::Extend),
+    (0xFE00, 0xFE0F, G::Extend),
+    (0xFE20, 0xFE2F, G::Extend),
+    (0xFEFF, 0xFEFF, G::Control),
+    (0xFF9E, 0xFF9F, G::Extend),
+    (0xFFF0, 0xFFFB, G::Control),
+    (0x101FD, 0x101FD, G::Extend),
+    (0x102E0, 0x102E0, G::Extend),
+    (0x10376, 0x1037A, G::Extend),
+    (0x10A01, 0x10A03, G::Extend),
+    (0x10A05, 0x10A06, G::Extend),
+    (0x10A0C, 0x10A0F, G::Extend),
+    (0x10A38, 0x10A3A, G::Extend),
+    (0x10A3F, 0x10A3F, G::Extend),
+    (0x10AE5, 0x10AE6, G::Extend),
+    (0x10D24, 0x10D27, G::Extend),
+    (0x10D69, 0x10D6D, G::Extend),
+    (0x10EAB, 0x10EAC, G::Extend),
+    (0x10EFC, 0x10EFF, G::Extend),
+    (0x10F46, 0x10F50, G::Extend),
+    (0x10F82, 0x10F85, G::Extend),
+    (0x11000, 0x11000, G::SpacingMark),
+    (0x11001, 0x11001, G::Extend),
+    (0x11002, 0x11002, G::SpacingMark),
+    (0x11038, 0x11046, G::Extend),
+    (0x11070, 0x11070, G::Extend),
+    (0x11073, 0x11074, G::Extend),
+    (0x1107F, 0x11081, G::Extend),
+    (0x11082, 0x11082, G::SpacingMark),
+    (0x110B0, 0x110B2, G::SpacingMark),
+    (0x110B3, 0x110B6, G::Extend),
+    (0x110B7, 0x110B8, G::SpacingMark),
+    (0x110B9, 0x110BA, G::Extend),
+    (0x110BD, 0x110BD, G::Prepend),
+    (0x110C2, 0x110C2, G::Extend),
+    (0x110CD, 0x110CD, G::Prepend),
+    (0x11100, 0x11102, G::Extend),
+    (0x11127, 0x1112B, G::Extend),
+    (0x1112C, 0x1112C, G::SpacingMark),
+    (0x1112D, 0x11134, G::Extend),
+    (0x11145, 0x11146, G::SpacingMark),
+    (0x11173, 0x11173, G::Extend),
+    (0x11180, 0x11181, G::Extend),
+    (0x11182, 0x11182, G::SpacingMark),
+    (0x111B3, 0x111B5, G::SpacingMark),
+    (0x111B6, 0x111BE, G::Extend),
+    (0x111BF, 0x111BF, G::SpacingMark),
+    (0x111C0, 0x111C0, G::Extend),
+    (0x111C2, 0x111C3, G::Prepend),
+    (0x111C9, 0x111CC, G::Extend),
+    (0x111CE, 0x111CE, G::SpacingMark),
+    (0x111CF, 0x111CF, G::Extend),
+    (0x1122C, 0x1122E, G::SpacingMark),
+    (0x1122F, 0x11231, G::Extend),
+    (0x11232, 0x11233, G::SpacingMark),
+    (0x11234, 0x11237, G::Extend),
+    (0x1123E, 0x1123E, G::Extend),
+    (0x11241, 0x11241, G::Extend),
+    (0x112DF, 0x112DF, G::Extend),
+    (0x112E0, 0x112E2, G::SpacingMark),
+    (0x112E3, 0x112EA, G::Extend),
+    (0x11300, 0x11301, G::Extend),
+    (0x11302, 0x11303, G::SpacingMark),
+    (0x1133B, 0x1133C, G::Extend),
+    (0x1133E, 0x1133E, G::Extend),
+    (0x1133F, 0x1133F, G::SpacingMark),
+    (0x11340, 0x11340, G::Extend),
+    (0x11341, 0x11344, G::SpacingMark),
+    (0x11347, 0x11348, G::SpacingMark),
+    (0x1134B, 0x1134C, G::SpacingMark),
+    (0x1134D, 0x1134D, G::Extend),
+    (0x11357, 0x11357, G::Extend),
+    (0x11362, 0x11363, G::SpacingMark),
+    (0x11366, 0x1136C, G::Extend),
+    (0x11370, 0x11374, G::Extend),
+    (0x113B8, 0x113B8, G::Extend),
+    (0x113B9, 0x113BA, G::SpacingMark),
+    (0x113BB, 0x113C0, G::Extend),
+    (0x113C2, 0x113C2, G::Extend),
+    (0x113C5, 0x113C5, G::Extend),
+    (0x113C7, 0x113C9, G::Extend),
+    (0x113CA, 0x113CA, G::SpacingMark),
+    (0x113CC, 0x113CD, G::SpacingMark),
+    (0x113CE, 0x113D0, G::Extend),
+    (0x113D1, 0x113D1, G::Prepend),
+    (0x113D2, 0x113D2, G::Extend),
+    (0x113E1, 0x113E2, G::Extend),
+    (0x11435, 0x11437, G::SpacingMark),
+    (0x11438, 0x1143F, G::Extend),
+    (0x11440, 0x11441, G::SpacingMark),
+    (0x11442, 0x11444, G::Extend),
+    (0x11445, 0x11445, G::SpacingMark),
+    (0x11446, 0x11446, G::Extend),
+    (0x1145E, 0x1145E, G::Extend),
+    (0x114B0, 0x114B0, G::Extend),
+    (0x114B1, 0x114B2, G::SpacingMark),
+    (0x114B3, 0x114B8, G::Extend),
+    (0x114B9, 0x114B9, G::SpacingMark),
+    (0x114BA, 0x114BA, G::Extend),
+    (0x114BB, 0x114BC, G::SpacingMark),
+    (0x114BD, 0x114BD, G::Extend),
+    (0x114BE, 0x114BE, G::SpacingMark),
+    (0x114BF, 0x114C0, G::Extend),
+    (0x114C1, 0x114C1, G::SpacingMark),
+    (0x114C2, 0x114C3, G::Extend),
+    (0x115AF, 0x115AF, G::Extend),
+    (0x115B0, 0x115B1, G::SpacingMark),
+    (0x115B2, 0x115B5, G::Extend),
+    (0x115B8, 0x115BB, G::SpacingMark),
+    (0x115BC, 0x115BD, G::Extend),
+    (0x115BE, 0x115BE, G::SpacingMark),
+    (0x115BF, 0x115C0, G::Extend),
+    (0x115DC, 0x115DD, G::Extend),
+    (0x11630, 0x11632, G::SpacingMark),
+    (0x11633, 0x1163A, G::Extend),
+    (0x1163B, 0x1163C, G::SpacingMark),
+    (0x1163D, 0x1163D, G::Extend),
+    (0x1163E, 0x1163E, G::SpacingMark),
+    (0x1163F, 0x11640, G::Extend),
+    (0x116AB, 0x116AB, G::Extend),
+    (0x116AC, 0x116AC, G::SpacingMark),
+    (0x116AD, 0x116AD, G::Extend),
+    (0x116AE, 0x116AF, G::SpacingMark),
+    (0x116B0, 0x116B7, G::Extend),
+    (0x1171D, 0x1171D, G::Extend),
+    (0x1171E, 0x1171E, G::SpacingMark),
+    (0x1171F, 0x1171F, G::Extend),
+    (0x11722, 0x11725, G::Extend),
+    (0x11726, 0x11726, G::SpacingMark),
+    (0x11727, 0x1172B, G::Extend),
+    (0x1182C, 0x1182E, G::SpacingMark),
+    (0x1182F, 0x11837, G::Extend),
+    (0x11838, 0x11838, G::SpacingMark),
+    (0x11839, 0x1183A, G::Extend),
+    (0x11930, 0x11930, G::Extend),
+    (0x11931, 0x11935, G::SpacingMark),
+    (0x11937, 0x11938, G::SpacingMark),
+    (0x1193B, 0x1193E, G::Extend),
+    (0x1193F, 0x1193F, G::Prepend),
+    (0x11940, 0x11940, G::SpacingMark),
+    (0x11941, 0x11941, G::Prepend),
+    (0x11942, 0x11942, G::SpacingMark),
+    (0x11943, 0x11943, G::Extend),
+    (0x119D1, 0x119D3, G::SpacingMark),
+    (0x119D4, 0x119D7, G::Extend),
+    (0x119DA, 0x119DB, G::Extend),
+    (0x119DC, 0x119DF, G::SpacingMark),
+    (0x119E0, 0x119E0, G::Extend),
+    (0x119E4, 0x119E4, G::SpacingMark),
+    (0x11A01, 0x11A0A, G::Extend),
+    (0x11A33, 0x11A38, G::Extend),
+    (0x11A39, 0x11A39, G::SpacingMark),
+    (0x11A3A, 0x11A3A, G::Prepend),
+    (0x11A3B, 0x11A3E, G::Extend),
+    (0x11A47, 0x11A47, G::Extend),
+    (0x11A51, 0x11A56, G::Extend),
+    (0x11A57, 0x11A58, G::SpacingMark),
+    (0x11A59, 0x11A5B, G::Extend),
+    (0x11A84, 0x11A89, G::Prepend),
+    (0x11A8A, 0x11A96, G::Extend),
+    (0x11A97, 0x11A97, G::SpacingMark),
+    (0x11A98, 0x11A99, G::Extend),
+    (0x11C2F, 0x11C2F, G::SpacingMark),
+    (0x11C30, 0x11C36, G::Extend),
+    (0x11C38, 0x11C3D, G::Extend),
+    (0x11C3E, 0x11C3E, G::SpacingMark),
+    (0x11C3F, 0x11C3F, G::Extend),
+    (0x11C92, 0x11CA7, G::Extend),
+    (0x11CA9, 0x11CA9, G::SpacingMark),
+    (0x11CAA, 0x11CB0, G::Extend),
+    (0x11CB1, 0x11CB1, G::SpacingMark),
+    (0x11CB2, 0x11CB3, G::Extend),
+    (0x11CB4, 0x11CB4, G::SpacingMark),
+    (0x11CB5, 0x11CB6, G::Extend),
+    (0x11D31, 0x11D36, G::Extend),
+    (0x11D3A, 0x11D3A, G::Extend),
+    (0x11D3C, 0x11D3D, G::Extend),
+    (0x11D3F, 0x11D45, G::Extend),
+    (0x11D46, 0x11D46, G::Prepend),
+    (0x11D47, 0x11D47, G::Extend),
+    (0x11D8A, 0x11D8E, G::SpacingMark),
+    (0x11D90, 0x11D91, G::Extend),
+    (0x11D93, 0x11D94, G::SpacingMark),
+    (0x11D95, 0x11D95, G::Extend),
+    (0x11D96, 0x11D96, G::SpacingMark),
+    (0x11D97, 0x11D97, G::Extend),
+    (0x11EF3, 0x11EF4, G::Extend),
+    (0x11EF5, 0x11EF6, G::SpacingMark),
+    (0x11F00, 0x11F01, G::Extend),
+    (0x11F02, 0x11F02, G::Prepend),
+    (0x11F03, 0x11F03, G::SpacingMark),
+    (0x11F34, 0x11F35, G::SpacingMark),
+    (0x11F36, 0x11F3A, G::Extend),
+    (0x11F3E, 0x11F3F, G::SpacingMark),
+    (0x11F40, 0x11F42, G::Extend),
+    (0x11F5A, 0x11F5A, G::Extend),
+    (0x13430, 0x1343F, G::Control),
+    (0x13440, 0x13440, G::Extend),
+    (0x13447, 0x13455, G::Extend),
+    (0x1611E, 0x16129, G::Extend),
+    (0x1612A, 0x1612C, G::SpacingMark),
+    (0x1612D, 0x1612F, G::Extend),
+    (0x16AF0, 0x16AF4, G::Extend),
+    (0x16B30, 0x16B36, G::Extend),
+    (0x16D63, 0x16D63, G::V),
+    (0x16D67, 0x16D6A, G::V),
+    (0x16F4F, 0x16F4F, G::Extend),
+    (0x16F51, 0x16F87, G::SpacingMark),
+    (0x16F8F, 0x16F92, G::Extend),
+    (0x16FE4, 0x16FE4, G::Extend),
+    (0x16FF0, 0x16FF1, G::Extend),
+    (0x1BC9D, 0x1BC9E, G::Extend),
+    (0x1BCA0, 0x1BCA3, G::Control),
+    (0x1CF00, 0x1CF2D, G::Extend),
+    (0x1CF30, 0x1CF46, G::Extend),
+    (0x1D165, 0x1D169, G::Extend),
+    (0x1D16D, 0x1D172, G::Extend),
+    (0x1D173, 0x1D17A, G::Control),
+    (0x1D17B, 0x1D182, G::Extend),
+    (0x1D185, 0x1D18B, G::Extend),
+    (0x1D1AA, 0x1D1AD, G::Extend),
+    (0x1D242, 0x1D244, G::Extend),
+    (0x1DA00, 0x1DA36, G::Extend),
+    (0x1DA3B, 0x1DA6C, G::Extend),
+    (0x1DA75, 0x1DA75, G::Extend),
+    (0x1DA84, 0x1DA84, G::Extend),
+    (0x1DA9B, 0x1DA9F, G::Extend),
+    (0x1DAA1, 0x1DAAF, G::Extend),
+    (0x1E000, 0x1E006, G::Extend),
+    (0x1E008, 0x1E018, G::Extend),
+    (0x1E01B, 0x1E021, G::Extend),
+    (0x1E023, 0x1E024, G::Extend),
+    (0x1E026, 0x1E02A, G::Extend),
+    (0x1E08F, 0x1E08F, G::Extend),
+    (0x1E130, 0x1E136, G::Extend),
+    (0x1E2AE, 0x1E2AE, G::Extend),
+    (0x1E2EC, 0x1E2EF, G::Extend),
+    (0x1E4EC, 0x1E4EF, G::Extend),
+    (0x1E5EE, 0x1E5EF, G::Extend),
+    (0x1E8D0, 0x1E8D6, G::Extend),
+    (0x1E944, 0x1E94A, G::Extend),
+    (0x1F000, 0x1F0FF, G::ExtendedPictographic),
+    (0x1F10D, 0x1F10F, G::ExtendedPictographic),
+    (0x1F12F, 0x1F12F, G::ExtendedPictographic),
+    (0x1F16C, 0x1F171, G::ExtendedPictographic),
+    (0x1F17E, 0x1F17F, G::ExtendedPictographic),
+    (0x1F18E, 0x1F18E, G::ExtendedPictographic),
+    (0x1F191, 0x1F19A, G::ExtendedPictographic),
+    (0x1F1AD, 0x1F1E5, G::ExtendedPictographic),
+    (0x1F1E6, 0x1F1FF, G::RegionalIndicator),
+    (0x1F201, 0x1F20F, G::ExtendedPictographic),
+    (0x1F21A, 0x1F21A, G::ExtendedPictographic),
+    (0x1F22F, 0x1F22F, G::ExtendedPictographic),
+    (0x1F232, 0x1F23A, G::ExtendedPictographic),
+    (0x1F23C, 0x1F23F, G::ExtendedPictographic),
+    (0x1F249, 0x1F3FA, G::ExtendedPictographic),
+    (0x1F3FB, 0x1F3FF, G::Extend),
+    (0x1F400, 0x1F53D, G::ExtendedPictographic),
+    (0x1F546, 0x1F64F, G::ExtendedPictographic),
+    (0x1F680, 0x1F6FF, G::ExtendedPictographic),
+    (0x1F774, 0x1F77F, G::ExtendedPictographic),
+    (0x1F7D5, 0x1F7FF, G::ExtendedPictographic),
+    (0x1F80C, 0x1F80F, G::ExtendedPictographic),
+    (0x1F848, 0x1F84F, G::ExtendedPictographic),
+    (0x1F85A, 0x1F85F, G::ExtendedPictographic),
+    (0x1F888, 0x1F88F, G::ExtendedPictographic),
+    (0x1F8AE, 0x1F8FF, G::ExtendedPictographic),
+    (0x1F90C, 0x1F93A, G::ExtendedPictographic),
+    (0x1F93C, 0x1F945, G::ExtendedPictographic),
+    (0x1F947, 0x1FAFF, G::ExtendedPictographic),
+    (0x1FC00, 0x1FFFD, G::ExtendedPictographic),
+    (0xE0000, 0xE001F, G::Control),
+    (0xE0020, 0xE007F, G::Extend),
+    (0xE0080, 0xE00FF, G::Control),
+    (0xE0100, 0xE01EF, G::Extend),
+    (0xE01F0, 0xE0FFF, G::Control),
+];
+
+/// Indic_Conjunct_Break=Extend
+pub(super) const INCB_EXTEND: &[(u32, u32)] = &[
+    (0x300, 0x36F),
+    (0x483, 0x489),
+    (0x591, 0x5BD),
+    (0x5BF, 0x5BF),
+    (0x5C1, 0x5C2),
+    (0x5C4, 0x5C5),
+    (0x5C7, 0x5C7),
+    (0x610, 0x61A),
+    (0x64B, 0x65F),
+    (0x670, 0x670),
+    (0x6D6, 0x6DC),
+    (0x6DF, 0x6E4),
+    (0x6E7, 0x6E8),
+    (0x6EA, 0x6ED),
+    (0x711, 0x711),
+    (0x730, 0x74A),
+    (0x7A6, 0x7B0),
+    (0x7EB, 0x7F3),
+    (0x7FD, 0x7FD),
+    (0x816, 0x819),
+    (0x81B, 0x823),
+    (0x825, 0x827),
+    (0x829, 0x82D),
+    (0x859, 0x85B),
+    (0x897, 0x89F),
+    (0x8CA, 0x8E1),
+    (0x8E3, 0x902),
+    (0x93A, 0x93A),
+    (0x93C, 0x93C),
+    (0x941, 0x948),
+    (0x951, 0x957),
+    (0x962, 0x963),
+    (0x981, 0x981),
+    (0x9BC, 0x9BC),
+    (0x9BE, 0x9BE),
+    (0x9C1, 0x9C4),
+    (0x9D7, 0x9D7),
+    (0x9E2, 0x9E3),
+    (0x9FE, 0x9FE),
+    (0xA01, 0xA02),
+    (0xA3C, 0xA3C),
+    (0xA41, 0xA42),
+    (0xA47, 0xA48),
+    (0xA4B, 0xA4D),
+    (0xA51, 0xA51),
+    (0xA70, 0xA71),
+    (0xA75, 0xA75),
+    (0xA81, 0xA82),
+    (0xABC, 0xABC),
+    (0xAC1, 0xAC5),
+    (0xAC7, 0xAC8),
+    (0xAE2, 0xAE3),
+    (0xAFA, 0xAFF),
+    (0xB01, 0xB01),
+    (0xB3C, 0xB3C),
+    (0xB3E, 0xB3F),
+    (0xB41, 0xB44),
+    (0xB55, 0xB57),
+    (0xB62, 0xB63),
+    (0xB82, 0xB82),
+    (0xBBE, 0xBBE),
+    (0xBC0, 0xBC0),
+    (0xBCD, 0xBCD),
+    (0xBD7, 0xBD7),
+    (0xC00, 0xC00),
+    (0xC04, 0xC04),
+    (0xC3C, 0xC3C),
+    (0xC3E, 0xC40),
+    (0xC46, 0xC48),
+    (0xC4A, 0xC4C),
+    (0xC55, 0xC56),
+    (0xC62, 0xC63),
+    (0xC81, 0xC81),
+    (0xCBC, 0xCBC),
+    (0xCBF, 0xCC0),
+    (0xCC2, 0xCC2),
+    (0xCC6, 0xCC8),
+    (0xCCA, 0xCCD),
+    (0xCD5, 0xCD6),
+    (0xCE2, 0xCE3),
+    (0xD00, 0xD01),
+    (0xD3B, 0xD3C),
+    (0xD3E, 0xD3E),
+    (0xD41, 0xD44),
+    (0xD57, 0xD57),
+    (0xD62, 0xD63),
+    (0xD81, 0xD81),
+    (0xDCA, 0xDCA),
+    (0xDCF, 0xDCF),
+    (0xDD2, 0xDD4),
+    (0xDD6, 0xDD6),
+    (0xDDF, 0xDDF),
+    (0xE31, 0xE31),
+    (0xE34, 0xE3A),
+    (0xE47, 0xE4E),
+    (0xEB1, 0xEB1),
+    (0xEB4, 0xEBC),
+    (0xEC8, 0xECE),
+    (0xF18, 0xF19),
+    (0xF35, 0xF35),
+    (0xF37, 0xF37),
+    (0xF39, 0xF39),
+    (0xF71, 0xF7E),
+    (0xF80, 0xF84),
+    (0xF86, 0xF87),
+    (0xF8D, 0xF97),
+    (0xF99, 0xFBC),
+    (0xFC6, 0xFC6),
+    (0x102D, 0x1030),
+    (0x1032, 0x1037),
+    (0x1039, 0x103A),
+    (0x103D, 0x103E),
+    (0x1058, 0x1059),
+    (0x105E, 0x1060),
+    (0x1071, 0x1074),
+    (0x1082, 0x1082),
+    (0x1085, 0x1086),
+    (0x108D, 0x108D),
+    (0x109D, 0x109D),
+    (0x135D, 0x135F),
+    (0x1712, 0x1715),
+    (0x1732, 0x1734),
+    (0x1752, 0x1753),
+    (0x1772, 0x1773),
+    (0x17B4, 0x17B5),
+    (0x17B7, 0x17BD),
+    (0x17C6, 0x17C6),
+    (0x17C9, 0x17D3),
+    (0x17DD, 0x17DD),
+    (0x180B, 0x180D),
+    (0x180F, 0x180F),
+    (0x1885, 0x1886),
+    (0x18A9, 0x18A9),
+    (0x1920, 0x1922),
+    (0x1927, 0x1928),
+    (0x1932, 0x1932),
+    (0x1939, 0x193B),
+    (0x1A17, 0x1A18),
+    (0x1A1B, 0x1A1B),
+    (0x1A56, 0x1A56),
+    (0x1A58, 0x1A5E),
+    (0x1A60, 0x1A60),
+    (0x1A62, 0x1A62),
+    (0x1A65, 0x1A6C),
+    (0x1A73, 0x1A7C),
+    (0x1A7F, 0x1A7F),
+    (0x1AB0, 0x1ACE),
+    (0x1B00, 0x1B03),
+    (0x1B34, 0x1B3D),
+    (0x1B42, 0x1B44),
+    (0x1B6B, 0x1B73),
+    (0x1B80, 0x1B81),
+    (0x1BA2, 0x1BA5),
+    (0x1BA8, 0x1BAD),
+    (0x1BE6, 0x1BE6),
+    (0x1BE8, 0x1BE9),
+    (0x1BED, 0x1BED),
+    (0x1BEF, 0x1BF3),
+    (0x1C2C, 0x1C33),
+    (0x1C36, 0x1C37),
+    (0x1CD0, 0x1CD2),
+    (0x1CD4, 0x1CE0),
+    (0x1CE2, 0x1CE8),
+    (0x1CED, 0x1CED),
+    (0x1CF4, 0x1CF4),
+    (0x1CF8, 0x1CF9),
+    (0x1DC0, 0x1DFF),
+    (0x200D, 0x200D),
+    (0x20D0, 0x20F0),
+    (0x2CEF, 0x2CF1),
+    (0x2D7F, 0x2D7F),
+    (0x2DE0, 0x2DFF),
+    (0x302A, 0x302F),
+    (0x3099, 0x309A),
+    (0xA66F, 0xA672),
+    (0xA674, 0xA67D),
+    (0xA69E, 0xA69F),
+    (0xA6F0, 0xA6F1),
+    (0xA802, 0xA802),
+    (0xA806, 0xA806),
+    (0xA80B, 0xA80B),
+    (0xA825, 0xA826),
+    (0xA82C, 0xA82C),
+    (0xA8C4, 0xA8C5),
+    (0xA8E0, 0xA8F1),
+    (0xA8FF, 0xA8FF),
+    (0xA926, 0xA92D),
+    (0xA947, 0xA951),
+    (0xA953, 0xA953),
+    (0xA980, 0xA982),
+    (0xA9B3, 0xA9B3),
+    (0xA9B6, 0xA9B9),
+    (0xA9BC, 0xA9BD),
+    (0xA9C0, 0xA9C0),
+    (0xA9E5, 0xA9E5),
+    (0xAA29, 0xAA2E),
+    (0xAA31, 0xAA32),
+    (0xAA35, 0xAA36),
+    (0xAA43, 0xAA43),
+    (0xAA4C, 0xAA4C),
+    (0xAA7C, 0xAA7C),
+    (0xAAB0, 0xAAB0),
+    (0xAAB2, 0xAAB4),
+    (0xAAB7, 0xAAB8),
+    (0xAABE, 0xAABF),
+    (0xAAC1, 0xAAC1),
+    (0xAAEC, 0xAAED),
+    (0xAAF6, 0xAAF6),
+    (0xABE5, 0xABE5),
+    (0xABE8, 0xABE8),
+    (0xABED, 0xABED),
+    (0xFB1E, 0xFB1E),
+    (0xFE00, 0xFE0F),
+    (0xFE20, 0xFE2F),
+    (0xFF9E, 0xFF9F),
+    (0x101FD, 0x101FD),
+    (0x102E0, 0x102E0),
+    (0x10376, 0x1037A),
+    (0x10A01, 0x10A03),
+    (0x10A05, 0x10A06),
+    (0x10A0C, 0x10A0F),
+    (0x10A38, 0x10A3A),
+    (0x10A3F, 0x10A3F),
+    (0x10AE5, 0x10AE6),
+    (0x10D24, 0x10D27),
+    (0x10D69, 0x10D6D),
+    (0x10EAB, 0x10EAC),
+    (0x10EFC, 0x10EFF),
+    (0x10F46, 0x10F50),
+    (0x10F82, 0x10F85),
+    (0x11001, 0x11001),
+    (0x11038, 0x11046),
+    (0x11070, 0x11070),
+    (0x11073, 0x11074),
+    (0x1107F, 0x11081),
+    (0x110B3, 0x110B6),
+    (0x110B9, 0x110BA),
+    (0x110C2, 0x110C2),
+    (0x11100, 0x11102),
+    (0x11127, 0x1112B),
+    (0x1112D, 0x11134),
+    (0x11173, 0x11173),
+    (0x11180, 0x11181),
+    (0x111B6, 0x111BE),
+    (0x111C0, 0x111C0),
+    (0x111C9, 0x111CC),
+    (0x111CF, 0x111CF),
+    (0x1122F, 0x11231),
+    (0x11234, 0x11237),
+    (0x1123E, 0x1123E),
+    (0x11241, 0x11241),
+    (0x112DF, 0x112DF),
+    (0x112E3, 0x112EA),
+    (0x11300, 0x11301),
+    (0x1133B, 0x1133C),
+    (0x1133E, 0x1133E),
+    (0x11340, 0x11340),
+    (0x1134D, 0x1134D),
+    (0x11357, 0x11357),
+    (0x11366, 0x1136C),
+    (0x11370, 0x11374),
+    (0x113B8, 0x113B8),
+    (0x113BB, 0x113C0),
+    (0x113C2, 0x113C2),
+    (0x113C5, 0x113C5),
+    (0x113C7, 0x113C9),
+    (0x113CE, 0x113D0),
+    (0x113D2, 0x113D2),
+    (0x113E1, 0x113E2),
+    (0x11438, 0x1143F),
+    (0x11442, 0x11444),
+    (0x11446, 0x11446),
+    (0x1145E, 0x1145E),
+    (0x114B0, 0x114B0),
+    (0x114B3, 0x114B8),
+    (0x114BA, 0x114BA),
+    (0x114BD, 0x114BD),
+    (0x114BF, 0x114C0),
+    (0x114C2, 0x114C3),
+    (0x115AF, 0x115AF),
+    (0x115B2, 0x115B5),
+    (0x115BC, 0x115BD),
+    (0x115BF, 0x115C0),
+    (0x115DC, 0x115DD),
+    (0x11633, 0x1163A),
+    (0x1163D, 0x1163D),
+    (0x1163F, 0x11640),
+    (0x116AB, 0x116AB),
+    (0x116AD, 0x116AD),
+    (0x116B0, 0x116B7),
+    (0x1171D, 0x1171D),
+    (0x1171F, 0x1171F),
+    (0x11722, 0x11725),
+    (0x11727, 0x1172B),
+    (0x1182F, 0x11837),
+    (0x11839, 0x1183A),
+    (0x11930, 0x11930),
+    (0x1193B, 0x1193E),
+    (0x11943, 0x11943),
+    (0x119D4, 0x119D7),
+    (0x119DA, 0x119DB),
+    (0x119E0, 0x119E0),
+    (0x11A01, 0x11A0A),
+    (0x11A33, 0x11A38),
+    (0x11A3B, 0x11A3E),
+    (0x11A47, 0x11A47),
+    (0x11A51, 0x11A56),
+    (0x11A59, 0x11A5B),
+    (0x11A8A, 0x11A96),
+    (0x11A98, 0x11A99),
+    (0x11C30, 0x11C36),
+    (0x11C38, 0x11C3D),
+    (0x11C3F, 0x11C3F),
+    (0x11C92, 0x11CA7),
+    (0x11CAA, 0x11CB0),
+    (0x11CB2, 0x11CB3),
+    (0x11CB5, 0x11CB6),
+    (0x11D31, 0x11D36),
+    (0x11D3A, 0x11D3A),
+    (0x11D3C, 0x11D3D),
+    (0x11D3F, 0x11D45),
+    (0x11D47, 0x11D47),
+    (0x11D90, 0x11D91),
+    (0x11D95, 0x11D95),
+    (0x11D97, 0x11D97),
+    (0x11EF3, 0x11EF4),
+    (0x11F00, 0x11F01),
+    (0x11F36, 0x11F3A),
+    (0x11F40, 0x11F42),
+    (0x11F5A, 0x11F5A),
+    (0x13440, 0x13440),
+    (0x13447, 0x13455),
+    (0x1611E, 0x16129),
+    (0x1612D, 0x1612F),
+    (0x16AF0, 0x16AF4),
+    (0x16B30, 0x16B36),
+    (0x16F4F, 0x16F4F),
+    (0x16F8F, 0x16F92),
+    (0x16FE4, 0x16FE4),
+    (0x16FF0, 0x16FF1),
+    (0x1BC9D, 0x1BC9E),
+    (0x1CF00, 0x1CF2D),
+    (0x1CF30, 0x1CF46),
+    (0x1D165, 0x1D169),
+    (0x1D16D, 0x1D172),
+    (0x1D17B, 0x1D182),
+    (0x1D185, 0x1D18B),
+    (0x1D1AA, 0x1D1AD),
+    (0x1D242, 0x1D244),
+    (0x1DA00, 0x1DA36),
+    (0x1DA3B, 0x1DA6C),
+    (0x1DA75, 0x1DA75),
+    (0x1DA84, 0x1DA84),
+    (0x1DA9B, 0x1DA9F),
+    (0x1DAA1, 0x1DAAF),
+    (0x1E000, 0x1E006),
+    (0x1E008, 0x1E018),
+    (0x1E01B, 0x1E021),
+    (0x1E023, 0x1E024),
+    (0x1E026, 0x1E02A),
+    (0x1E08F, 0x1E08F),
+    (0x1E130, 0x1E136),
+    (0x1E2AE, 0x1E2AE),
+    (0x1E2EC, 0x1E2EF),
+    (0x1E4EC, 0x1E4EF),
+    (0x1E5EE, 0x1E5EF),
+    (0x1E8D0, 0x1E8D6),
+    (0x1E944, 0x1E94A),
+    (0x1F3FB, 0x1F3FF),
+    (0xE0020, 0xE007F),
+    (0xE0100, 0xE01EF),
+];
+
+/// Code points which do not take up a column, everything not listed here or in [`WIDE`] takes up one
+pub(super) const ZERO_WIDTH: &[(u32, u32)] = &[
+    (0x0, 0x1F),
+    (0x7F, 0x9F),
+    (0xAD, 0xAD),
+    (0x300, 0x36F),
+    (0x483, 0x489),
+    (0x591, 0x5BD),
+    (0x5BF, 0x5BF),
+    (0x5C1, 0x5C2),
+    (0x5C4, 0x5C5),
+    (0x5C7, 0x5C7),
+    (0x605, 0x605),
+    (0x610, 0x61A),
+    (0x61C, 0x61C),
+    (0x64B, 0x65F),
+    (0x670, 0x670),
+    (0x6D6, 0x6DC),
+    (0x6DF, 0x6E4),
+    (0x6E7, 0x6E8),
+    (0x6EA, 0x6ED),
+    (0x70F, 0x70F),
+    (0x711, 0x711),
+    (0x730, 0x74A),
+    (0x7A6, 0x7B0),
+    (0x7EB, 0x7F3),
+    (0x7FD, 0x7FD),
+    (0x816, 0x819),
+    (0x81B, 0x823),
+    (0x825, 0x827),
+    (0x829, 0x82D),
+    (0x859, 0x85B),
+    (0x890, 0x891),
+    (0x897, 0x89F),
+    (0x8CA, 0x902),
+    (0x93A, 0x93A),
+    (0x93C, 0x93C),
+    (0x941, 0x948),
+    (0x94D, 0x94D),
+    (0x951, 0x957),
+    (0x962, 0x963),
+    (0x981, 0x981),
+    (0x9BC, 0x9BC),
+    (0x9BE, 0x9BE),
+    (0x9C1, 0x9C4),
+    (0x9CD, 0x9CD),
+    (0x9D7, 0x9D7),
+    (0x9E2, 0x9E3),
+    (0x9FE, 0x9FE),
+    (0xA01, 0xA02),
+    (0xA3C, 0xA3C),
+    (0xA41, 0xA42),
+    (0xA47, 0xA48),
+    (0xA4B, 0xA4D),
+    (0xA51, 0xA51),
+    (0xA70, 0xA71),
+    (0xA75, 0xA75),
+    (0xA81, 0xA82),
+    (0xABC, 0xABC),
+    (0xAC1, 0xAC5),
+    (0xAC7, 0xAC8),
+    (0xACD, 0xACD),
+    (0xAE2, 0xAE3),
+    (0xAFA, 0xAFF),
+    (0xB01, 0xB01),
+    (0xB3C, 0xB3C),
+    (0xB3E, 0xB3F),
+    (0xB41, 0xB44),
+    (0xB4D, 0xB4D),
+    (0xB55, 0xB57),
+    (0xB62, 0xB63),
+    (0xB82, 0xB82),
+    (0xBBE, 0xBBE),
+    (0xBC0, 0xBC0),
+    (0xBCD, 0xBCD),
+    (0xBD7, 0xBD7),
+    (0xC00, 0xC00),
+    (0xC04, 0xC04),
+    (0xC3C, 0xC3C),
+    (0xC3E, 0xC40),
+    (0xC46, 0xC48),
+    (0xC4A, 0xC4D),
+    (0xC55, 0xC56),
+    (0xC62, 0xC63),
+    (0xC81, 0xC81),
+    (0xCBC, 0xCBC),
+    (0xCBF, 0xCC0),
+    (0xCC2, 0xCC2),
+    (0xCC6, 0xCC8),
+    (0xCCA, 0xCCD),
+    (0xCD5, 0xCD6),
+    (0xCE2, 0xCE3),
+    (0xD00, 0xD01),
+    (0xD3B, 0xD3C),
+    (0xD3E, 0xD3E),
+    (0xD41, 0xD44),
+    (0xD4D, 0xD4E),
+    (0xD57, 0xD57),
+    (0xD62, 0xD63),
+    (0xD81, 0xD81),
+    (0xDCA, 0xDCA),
+    (0xDCF, 0xDCF),
+    (0xDD2, 0xDD4),
+    (0xDD6, 0xDD6),
+    (0xDDF, 0xDDF),
+    (0xE31, 0xE31),
+    (0xE34, 0xE3A),
+    (0xE47, 0xE4E),
+    (0xEB1, 0xEB1),
+    (0xEB4, 0xEBC),
+    (0xEC8, 0xECE),
+    (0xF18, 0xF19),
+    (0xF35, 0xF35),
+    (0xF37, 0xF37),
+    (0xF39, 0xF39),
+    (0xF71, 0xF7E),
+    (0xF80, 0xF84),
+    (0xF86, 0xF87),
+    (0xF8D, 0xF97),
+    (0xF99, 0xFBC),
+    (0xFC6, 0xFC6),
+    (0x102D, 0x1030),
+    (0x1032, 0x1037),
+    (0x1039, 0x103A),
+    (0x103D, 0x103E),
+    (0x1058, 0x1059),
+    (0x105E, 0x1060),
+    (0x1071, 0x1074),
+    (0x1082, 0x1082),
+    (0x1085, 0x1086),
+    (0x108D, 0x108D),
+    (0x109D, 0x109D),
+    (0x1160, 0x11FF),
+    (0x135D, 0x135F),
+    (0x1712, 0x1715),
+    (0x1732, 0x1734),
+    (0x1752, 0x1753),
+    (0x1772, 0x1773),
+    (0x17B4, 0x17B5),
+    (0x17B7, 0x17BD),
+    (0x17C6, 0x17C6),
+    (0x17C9, 0x17D3),
+    (0x17DD, 0x17DD),
+    (0x180B, 0x180F),
+    (0x1885, 0x1886),
+    (0x18A9, 0x18A9),
+    (0x1920, 0x1922),
+    (0x1927, 0x1928),
+    (0x1932, 0x1932),
+    (0x1939, 0x193B),
+    (0x1A17, 0x1A18),
+    (0x1A1B, 0x1A1B),
+    (0x1A56, 0x1A56),
+    (0x1A58, 0x1A5E),
+    (0x1A60, 0x1A60),
+    (0x1A62, 0x1A62),
+    (0x1A65, 0x1A6C),
+    (0x1A73, 0x1A7C),
+    (0x1A7F, 0x1A7F),
+    (0x1AB0, 0x1ADD),
+    (0x1AE0, 0x1AEB),
+    (0x1B00, 0x1B03),
+    (0x1B34, 0x1B3D),
+    (0x1B42, 0x1B44),
+    (0x1B6B, 0x1B73),
+    (0x1B80, 0x1B81),
+    (0x1BA2, 0x1BA5),
+    (0x1BA8, 0x1BAD),
+    (0x1BE6, 0x1BE6),
+    (0x1BE8, 0x1BE9),
+    (0x1BED, 0x1BED),
+    (0x1BEF, 0x1BF3),
+    (0x1C2C, 0x1C33),
+    (0x1C36, 0x1C37),
+    (0x1CD0, 0x1CD2),
+    (0x1CD4, 0x1CE0),
+    (0x1CE2, 0x1CE8),
+    (0x1CED, 0x1CED),
+    (0x1CF4, 0x1CF4),
+    (0x1CF8, 0x1CF9),
+    (0x1DC0, 0x1DFF),
+    (0x200B, 0x200F),
+    (0x202A, 0x202E),
+    (0x2060, 0x206F),
+    (0x20D0, 0x20F0),
+    (0x2CEF, 0x2CF1),
+    (0x2DE0, 0x2DFF),
+    (0x302A, 0x302F),
+    (0x3099, 0x309A),
+    (0x3164, 0x3164),
+    (0xA66F, 0xA672),
+    (0xA674, 0xA67D),
+    (0xA69E, 0xA69F),
+    (0xA6F0, 0xA6F1),
+    (0xA802, 0xA802),
+    (0xA806, 0xA806),
+    (0xA80B, 0xA80B),
+    (0xA825, 0xA826),
+    (0xA82C, 0xA82C),
+    (0xA8C4, 0xA8C5),
+    (0xA8E0, 0xA8F1),
+    (0xA8FA, 0xA8FA),
+    (0xA8FF, 0xA8FF),
+    (0xA926, 0xA92D),
+    (0xA947, 0xA951),
+    (0xA953, 0xA953),
+    (0xA980, 0xA982),
+    (0xA9B3, 0xA9B3),
+    (0xA9B6, 0xA9B9),
+    (0xA9BC, 0xA9BD),
+    (0xA9C0, 0xA9C0),
+    (0xA9E5, 0xA9E5),
+    (0xAA29, 0xAA2E),
+    (0xAA31, 0xAA32),
+    (0xAA35, 0xAA36),
+    (0xAA43, 0xAA43),
+    (0xAA4C, 0xAA4C),
+    (0xAA7C, 0xAA7C),
+    (0xAAB0, 0xAAB0),
+    (0xAAB2, 0xAAB4),
+    (0xAAB7, 0xAAB8),
+    (0xAABE, 0xAABF),
+    (0xAAC1, 0xAAC1),
+    (0xAAEC, 0xAAED),
+    (0xAAF6, 0xAAF6),
+    (0xABE5, 0xABE5),
+    (0xABE8, 0xABE8),
+    (0xABED, 0xABED),
+    (0xD7B0, 0xD7C6),
+    (0xD7CB, 0xD7FB),
+    (0xFB1E, 0xFB1E),
+    (0xFE00, 0xFE0F),
+    (0xFE20, 0xFE2F),
+    (0xFEFF, 0xFEFF),
+    (0xFF9E, 0xFFA0),
+    (0xFFF0, 0xFFF8),
+    (0x101FD, 0x101FD),
+    (0x102E0, 0x102E0),
+    (0x10376, 0x1037A),
+    (0x10A01, 0x10A03),
+    (0x10A05, 0x10A06),
+    (0x10A0C, 0x10A0F),
+    (0x10A38, 0x10A3A),
+    (0x10A3F, 0x10A3F),
+    (0x10AE5, 0x10AE6),
+    (0x10D24, 0x10D27),
+    (0x10D69, 0x10D6D),
+    (0x10EAB, 0x10EAC),
+    (0x10EFA, 0x10EFF),
+    (0x10F46, 0x10F50),
+    (0x10F82, 0x10F85),
+    (0x11001, 0x11001),
+    (0x11038, 0x11046),
+    (0x11070, 0x11070),
+    (0x11073, 0x11074),
+    (0x1107F, 0x11081),
+    (0x110B3, 0x110B6),
+    (0x110B9, 0x110BA),
+    (0x110C2, 0x110C2),
+    (0x11100, 0x11102),
+    (0x11127, 0x1112B),
+    (0x1112D, 0x11134),
+    (0x11173, 0x11173),
+    (0x11180, 0x11181),
+    (0x111B6, 0x111BE),
+    (0x111C0, 0x111C0),
+    (0x111C2, 0x111C3),
+    (0x111C9, 0x111CC),
+    (0x111CF, 0x111CF),
+    (0x1122F, 0x11231),
+    (0x11234, 0x11237),
+    (0x1123E, 0x1123E),
+    (0x11241, 0x11241),
+    (0x112DF, 0x112DF),
+    (0x112E3, 0x112EA),
+    (0x11300, 0x11301),
+    (0x1133B, 0x1133C),
+    (0x1133E, 0x1133E),
+    (0x11340, 0x11340),
+    (0x1134D, 0x1134D),
+    (0x11357, 0x11357),
+    (0x11366, 0x1136C),
+    (0x11370, 0x11374),
+    (0x113B8, 0x113B8),
+    (0x113BB, 0x113C0),
+    (0x113C2, 0x113C2),
+    (0x113C5, 0x113C5),
+    (0x113C7, 0x113C9),
+    (0x113CE, 0x113D2),
+    (0x113E1, 0x113E2),
+    (0x11438, 0x1143F),
+    (0x11442, 0x11444),
+    (0x11446, 0x11446),
+    (0x1145E, 0x1145E),
+    (0x114B0, 0x114B0),
+    (0x114B3, 0x114B8),
+    (0x114BA, 0x114BA),
+    (0x114BD, 0x114BD),
+    (0x114BF, 0x114C0),
+    (0x114C2, 0x114C3),
+    (0x115AF, 0x115AF),
+    (0x115B2, 0x115B5),
+    (0x115BC, 0x115BD),
+    (0x115BF, 0x115C0),
+    (0x115DC, 0x115DD),
+    (0x11633, 0x1163A),
+    (0x1163D, 0x1163D),
+    (0x1163F, 0x11640),
+    (0x116AB, 0x116AB),
+    (0x116AD, 0x116AD),
+    (0x116B0, 0x116B7),
+    (0x1171D, 0x1171D),
+    (0x1171F, 0x1171F),
+    (0x11722, 0x11725),
+    (0x11727, 0x1172B),
+    (0x1182F, 0x11837),
+    (0x11839, 0x1183A),
+    (0x11930, 0x11930),
+    (0x1193B, 0x1193F),
+    (0x11941, 0x11941),
+    (0x11943, 0x11943),
+    (0x119D4, 0x119D7),
+    (0x119DA, 0x119DB),
+    (0x119E0, 0x119E0),
+    (0x11A01, 0x11A0A),
+    (0x11A33, 0x11A38),
+    (0x11A3B, 0x11A3E),
+    (0x11A47, 0x11A47),
+    (0x11A51, 0x11A56),
+    (0x11A59, 0x11A5B),
+    (0x11A84, 0x11A96),
+    (0x11A98, 0x11A99),
+    (0x11B60, 0x11B60),
+    (0x11B62, 0x11B64),
+    (0x11B66, 0x11B66),
+    (0x11C30, 0x11C36),
+    (0x11C38, 0x11C3D),
+    (0x11C3F, 0x11C3F),
+    (0x11C92, 0x11CA7),
+    (0x11CAA, 0x11CB0),
+    (0x11CB2, 0x11CB3),
+    (0x11CB5, 0x11CB6),
+    (0x11D31, 0x11D36),
+    (0x11D3A, 0x11D3A),
+    (0x11D3C, 0x11D3D),
+    (0x11D3F, 0x11D47),
+    (0x11D90, 0x11D91),
+    (0x11D95, 0x11D95),
+    (0x11D97, 0x11D97),
+    (0x11EF3, 0x11EF4),
+    (0x11F00, 0x11F02),
+    (0x11F36, 0x11F3A),
+    (0x11F40, 0x11F42),
+    (0x11F5A, 0x11F5A),
+    (0x13440, 0x13440),
+    (0x13447, 0x13455),
+    (0x1611E, 0x16129),
+    (0x1612D, 0x1612F),
+    (0x16AF0, 0x16AF4),
+    (0x16B30, 0x16B36),
+    (0x16F4F, 0x16F4F),
+    (0x16F8F, 0x16F92),
+    (0x16FE4, 0x16FE4),
+    (0x16FF0, 0x16FF1),
+    (0x1BC9D, 0x1BC9E),
+    (0x1BCA0, 0x1BCA3),
+    (0x1CF00, 0x1CF2D),
+    (0x1CF30, 0x1CF46),
+    (0x1D165, 0x1D169),
+    (0x1D16D, 0x1D182),
+    (0x1D185, 0x1D18B),
+    (0x1D1AA, 0x1D1AD),
+    (0x1D242, 0x1D244),
+    (0x1DA00, 0x1DA36),
+    (0x1DA3B, 0x1DA6C),
+    (0x1DA75, 0x1DA75),
+    (0x1DA84, 0x1DA84),
+    (0x1DA9B, 0x1DA9F),
+    (0x1DAA1, 0x1DAAF),
+    (0x1E000, 0x1E006),
+    (0x1E008, 0x1E018),
+    (0x1E01B, 0x1E021),
+    (0x1E023, 0x1E024),
+    (0x1E026, 0x1E02A),
+    (0x1E08F, 0x1E08F),
+    (0x1E130, 0x1E136),
+    (0x1E2AE, 0x1E2AE),
+    (0x1E2EC, 0x1E2EF),
+    (0x1E4EC, 0x1E4EF),
+    (0x1E5EE, 0x1E5EF),
+    (0x1E6E3, 0x1E6E3),
+    (0x1E6E6, 0x1E6E6),
+    (0x1E6EE, 0x1E6EF),
+    (0x1E6F5, 0x1E6F5),
+    (0x1E8D0, 0x1E8D6),
+    (0x1E944, 0x1E94A),
+    (0xE0000, 0xE0FFF),
+];
+
+/// Code points which take up two columns
+pub(super) const WIDE: &[(u32, u32)] = &[
+    (0x1100, 0x115F),
+    (0x17A4, 0x17A4),
+    (0x17D8, 0x17D8),
+    (0x231A, 0x231B),
+    (0x2329, 0x232A),
+    (0x23E9, 0x23EC),
+    (0x23F0, 0x23F0),
+    (0x23F3, 0x23F3),
+    (0x25FD, 0x25FE),
+    (0x2614, 0x2615),
+    (0x2630, 0x2637),
+    (0x2648, 0x2653),
+    (0x267F, 0x267F),
+    (0x268A, 0x268F),
+    (0x2693, 0x2693),
+    (0x26A1, 0x26A1),
+    (0x26AA, 0x26AB),
+    (0x26BD, 0x26BE),
+    (0x26C4, 0x26C5),
+    (0x26CE, 0x26CE),
+    (0x26D4, 0x26D4),
+    (0x26EA, 0x26EA),
+    (0x26F2, 0x26F3),
+    (0x26F5, 0x26F5),
+    (0x26FA, 0x26FA),
+    (0x26FD, 0x26FD),
+    (0x2705, 0x2705),
+    (0x270A, 0x270B),
+    (0x2728, 0x2728),
+    (0x274C, 0x274C),
+    (0x274E, 0x274E),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2795, 0x2797),
+    (0x27B0, 0x27B0),
+    (0x27BF, 0x27BF),
+    (0x2B1B, 0x2B1C),
+    (0x2B50, 0x2B50),
+    (0x2B55, 0x2B55),
+    (0x2E80, 0x2E99),
+    (0x2E9B, 0x2EF3),
+    (0x2F00, 0x2FD5),
+    (0x2FF0, 0x3029),
+    (0x3030, 0x303E),
+    (0x3041, 0x3096),
+    (0x309B, 0x30FF),
+    (0x3105, 0x312F),
+    (0x3131, 0x3163),
+    (0x3165, 0x318E),
+    (0x3190, 0x31E5),
+    (0x31EF, 0x321E),
+    (0x3220, 0x3247),
+    (0x3250, 0xA48C),
+    (0xA490, 0xA4C6),
+    (0xA960, 0xA97C),
+    (0xAC00, 0xD7A3),
+    (0xF900, 0xFAFF),
+    (0xFE10, 0xFE19),
+    (0xFE30, 0xFE52),
+    (0xFE54, 0xFE66),
+    (0xFE68, 0xFE6B),
+    (0xFF01, 0xFF60),
+    (0xFFE0, 0xFFE6),
+    (0x16FE0, 0x16FE3),
+    (0x16FF2, 0x16FF6),
+    (0x17000, 0x18CD5),
+    (0x18CFF, 0x18D1E),
+    (0x18D80, 0x18DF2),
+    (0x1AFF0, 0x1AFF3),
+    (0x1AFF5, 0x1AFFB),
+    (0x1AFFD, 0x1AFFE),
+    (0x1B000, 0x1B122),
+    (0x1B132, 0x1B132),
+    (0x1B150, 0x1B152),
+    (0x1B155, 0x1B155),
+    (0x1B164, 0x1B167),
+    (0x1B170, 0x1B2FB),
+    (0x1D300, 0x1D356),
+    (0x1D360, 0x1D376),
+    (0x1F004, 0x1F004),
+    (0x1F0CF, 0x1F0CF),
+    (0x1F18E, 0x1F18E),
+    (0x1F191, 0x1F19A),
+    (0x1F200, 0x1F202),
+    (0x1F210, 0x1F23B),
+    (0x1F240, 0x1F248),
+    (0x1F250, 0x1F251),
+    (0x1F260, 0x1F265),
+    (0x1F300, 0x1F320),
+    (0x1F32D, 0x1F335),
+    (0x1F337, 0x1F37C),
+    (0x1F37E, 0x1F393),
+    (0x1F3A0, 0x1F3CA),
+    (0x1F3CF, 0x1F3D3),
+    (0x1F3E0, 0x1F3F0),
+    (0x1F3F4, 0x1F3F4),
+    (0x1F3F8, 0x1F43E),
+    (0x1F440, 0x1F440),
+    (0x1F442, 0x1F4FC),
+    (0x1F4FF, 0x1F53D),
+    (0x1F54B, 0x1F54E),
+    (0x1F550, 0x1F567),
+    (0x1F57A, 0x1F57A),
+    (0x1F595, 0x1F596),
+    (0x1F5A4, 0x1F5A4),
+    (0x1F5FB, 0x1F64F),
+    (0x1F680, 0x1F6C5),
+    (0x1F6CC, 0x1F6CC),
+    (0x1F6D0, 0x1F6D2),
+    (0x1F6D5, 0x1F6D8),
+    (0x1F6DC, 0x1F6DF),
+    (0x1F6EB, 0x1F6EC),
+    (0x1F6F4, 0x1F6FC),
+    (0x1F7E0, 0x1F7EB),
+    (0x1F7F0, 0x1F7F0),
+    (0x1F90C, 0x1F93A),
+    (0x1F93C, 0x1F945),
+    (0x1F947, 0x1F9FF),
+    (0x1FA70, 0x1FA7C),
+    (0x1FA80, 0x1FA8A),
+    (0x1FA8E, 0x1FAC6),
+    (0x1FAC8, 0x1FAC8),
+    (0x1FACD, 0x1FADC),
+    (0x1FADF, 0x1FAEA),
+    (0x1FAEF, 0x1FAF8),
+    (0x20000, 0x2FFFD),
+    (0x30000, 0x3FFFD),
+];
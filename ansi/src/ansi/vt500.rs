@@ -0,0 +1,395 @@
+use super::parser::Step;
+use crate::ansi::*;
+
+/// The states of the DEC/xterm state machine as documented by Paul Williams
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum Vt500State {
+    #[default]
+    Ground,
+    Escape,
+    EscapeIntermediate,
+    CsiEntry,
+    CsiParam,
+    CsiIntermediate,
+    CsiIgnore,
+    DcsEntry,
+    DcsParam,
+    DcsIntermediate,
+    DcsPassthrough,
+    DcsIgnore,
+    OscString,
+    SosPmApcString,
+}
+
+pub type SizedVt500Parser<const BUF_CAP: usize> = Vt500Parser<[u8; BUF_CAP]>;
+
+pub type UnsizedVt500Parser = Vt500Parser<[u8]>;
+
+/// A parser which agrees byte for byte with the VT500 state machine.
+///
+/// There is no configuration, input is never decoded as UTF-8, 0x80..=0x9F are always
+/// C1 controls and 0xA0..=0xFF behave like 0x20..=0x7F. Printed bytes are reported as
+/// [`Out::Data`], DCS headers and payloads as [`Out::DCSData`] and OSC payloads as
+/// [`Out::OSData`] while SOS, PM and APC payloads are ignored.
+#[derive(Debug)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub struct Vt500Parser<T: ?Sized> {
+    state: Vt500State,
+    /// The control string being parsed, kept through an ESC which might terminate it
+    string: Option<StringKind>,
+    overflow: bool,
+    buffer_count: usize,
+    buffer: T,
+}
+
+impl<const BYTE_BUF_SIZE: usize> core::default::Default for SizedVt500Parser<BYTE_BUF_SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const BYTE_BUF_SIZE: usize> SizedVt500Parser<BYTE_BUF_SIZE> {
+    pub const fn new() -> Self {
+        Self {
+            state: Vt500State::Ground,
+            string: None,
+            overflow: false,
+            buffer_count: 0,
+            buffer: [0; BYTE_BUF_SIZE],
+        }
+    }
+
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        let tc: &mut UnsizedVt500Parser = self;
+        tc.reset();
+    }
+
+    #[inline(always)]
+    pub fn state(&self) -> Vt500State {
+        self.state
+    }
+
+    #[inline(always)]
+    pub fn next(&mut self, input: u8) -> Out<'_> {
+        let tc: &mut UnsizedVt500Parser = self;
+        tc.next(input)
+    }
+
+    #[inline(always)]
+    pub fn feed<'a>(&'a mut self, input: &'a [u8]) -> Feed<'a> {
+        let tc: &mut UnsizedVt500Parser = self;
+        tc.feed(input)
+    }
+}
+
+impl UnsizedVt500Parser {
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn reset(&mut self) {
+        self.state = Vt500State::Ground;
+        self.string = None;
+        self.clear();
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn state(&self) -> Vt500State {
+        self.state
+    }
+
+    /// Feeds a single byte into the parser, see [`AnsiParser::next`]
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn next(&mut self, input: u8) -> Out<'_> {
+        let step = match self.step(input) {
+            Step::Reprocess(out) => match self.step(input) {
+                Step::Out(Out::None) => Step::Out(out),
+                step => step,
+            },
+            step => step,
+        };
+        self.resolve(step)
+    }
+
+    /// Feeds bytes from `input` until an event is produced, see [`AnsiParser::feed`]
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn feed<'a>(&'a mut self, input: &'a [u8]) -> Feed<'a> {
+        for (i, byte) in input.iter().copied().enumerate() {
+            match self.step(byte) {
+                Step::Out(Out::None) => {}
+                Step::Reprocess(out) => return Feed { consumed: i, out },
+                step => {
+                    return Feed {
+                        consumed: i.wrapping_add(1),
+                        out: self.resolve(step),
+                    };
+                }
+            }
+        }
+        Feed {
+            consumed: input.len(),
+            out: Out::None,
+        }
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    #[allow(clippy::useless_conversion)]
+    fn resolve(&self, step: Step) -> Out<'_> {
+        let buffer = self.buffer.get(..self.buffer_count).unwrap_or(&[]);
+        match step {
+            Step::Out(out) | Step::Reprocess(out) => out,
+            Step::Csi => Out::CSI(crate::CSI::new(buffer)),
+            Step::Nf => Out::nF(buffer.into()),
+            // control strings are never buffered
+            Step::String(..) => Out::None,
+        }
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn clear(&mut self) {
+        self.buffer_count = 0;
+        self.overflow = false;
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn collect(&mut self, input: u8) {
+        if let Some(e) = self.buffer.get_mut(self.buffer_count) {
+            *e = input;
+            self.buffer_count = self.buffer_count.wrapping_add(1);
+        } else {
+            self.overflow = true;
+        }
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn execute(&self, input: u8) -> Out<'static> {
+        match input {
+            0x80..=0x9F => C1::try_from(input - 0x40).map_or(Out::None, Out::C1),
+            _ => C0::try_from(input).map_or(Out::None, Out::C0),
+        }
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn enter_string(&mut self, state: Vt500State, kind: StringKind) -> Out<'static> {
+        self.clear();
+        self.state = state;
+        self.string = Some(kind);
+        match kind {
+            StringKind::DeviceControl => Out::C1(C1::DCS),
+            StringKind::Regular => Out::C1(C1::SOS),
+            StringKind::Privacy => Out::C1(C1::PM),
+            StringKind::ApplicationProgramCommand => Out::C1(C1::APC),
+            StringKind::Os => Out::C1(C1::OSC),
+        }
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn esc_dispatch(&mut self, input: u8) -> Step {
+        self.state = Vt500State::Ground;
+        Step::Out(match input {
+            0x30..=0x3F => Fp::try_from(input).map_or(Out::InvalidEscapeByte(input), Out::Fp),
+            0x40..=0x5F => C1::try_from(input).map_or(Out::InvalidEscapeByte(input), Out::C1),
+            _ => Fs::try_from(input).map_or(Out::InvalidEscapeByte(input), Out::Fs),
+        })
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn csi_dispatch(&mut self, input: u8) -> Step {
+        self.state = Vt500State::Ground;
+        self.collect(input);
+        if self.overflow {
+            Step::Out(Out::CSISequenceTooLarge)
+        } else {
+            Step::Csi
+        }
+    }
+
+    /// Handles the bytes which leave a control string
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn step_string_end(&mut self, kind: StringKind, input: u8) -> Option<Step> {
+        match (self.state, input) {
+            (Vt500State::Escape, b'\\') => {
+                self.string = None;
+                self.state = Vt500State::Ground;
+                Some(Step::Out(Out::StringEnd {
+                    kind,
+                    terminator: StringTerminator::ST,
+                }))
+            }
+            (Vt500State::Escape, _) => {
+                self.string = None;
+                Some(Step::Reprocess(Out::StringAborted {
+                    kind,
+                    cause: StringAbort::Interrupted,
+                }))
+            }
+            (_, 0x9C) => {
+                self.string = None;
+                self.state = Vt500State::Ground;
+                Some(Step::Out(Out::StringEnd {
+                    kind,
+                    terminator: StringTerminator::ST,
+                }))
+            }
+            (_, 0x18 | 0x1A | 0x80..=0x9F) => {
+                self.string = None;
+                self.state = Vt500State::Ground;
+                Some(Step::Reprocess(Out::StringAborted {
+                    kind,
+                    cause: StringAbort::Interrupted,
+                }))
+            }
+            _ => None,
+        }
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn step(&mut self, input: u8) -> Step {
+        use Vt500State as S;
+
+        if let Some(step) = self
+            .string
+            .and_then(|kind| self.step_string_end(kind, input))
+        {
+            return step;
+        }
+
+        // transitions which happen from anywhere
+        match input {
+            0x18 | 0x1A | 0x80..=0x8F | 0x91..=0x97 | 0x99 | 0x9A => {
+                self.state = S::Ground;
+                return Step::Out(self.execute(input));
+            }
+            0x9C => {
+                self.state = S::Ground;
+                return Step::Out(Out::None);
+            }
+            0x1B => {
+                self.clear();
+                self.state = S::Escape;
+                return Step::Out(Out::None);
+            }
+            0x9B => {
+                self.clear();
+                self.state = S::CsiEntry;
+                return Step::Out(Out::None);
+            }
+            0x90 => return Step::Out(self.enter_string(S::DcsEntry, StringKind::DeviceControl)),
+            0x9D => return Step::Out(self.enter_string(S::OscString, StringKind::Os)),
+            0x98 => return Step::Out(self.enter_string(S::SosPmApcString, StringKind::Regular)),
+            0x9E => return Step::Out(self.enter_string(S::SosPmApcString, StringKind::Privacy)),
+            0x9F => {
+                return Step::Out(
+                    self.enter_string(S::SosPmApcString, StringKind::ApplicationProgramCommand),
+                );
+            }
+            _ => {}
+        }
+
+        // GR bytes behave like their GL counterparts
+        let byte = if input >= 0xA0 { input - 0x80 } else { input };
+        let c0 = matches!(byte, 0x00..=0x17 | 0x19 | 0x1C..=0x1F);
+
+        Step::Out(match self.state {
+            S::Ground if c0 => self.execute(byte),
+            S::Ground => Out::Data(input as crate::FfiChar),
+
+            S::Escape => match byte {
+                _ if c0 => self.execute(byte),
+                0x20..=0x2F => {
+                    self.collect(byte);
+                    self.state = S::EscapeIntermediate;
+                    Out::None
+                }
+                b'P' => self.enter_string(S::DcsEntry, StringKind::DeviceControl),
+                b'X' => self.enter_string(S::SosPmApcString, StringKind::Regular),
+                b'[' => {
+                    self.clear();
+                    self.state = S::CsiEntry;
+                    Out::None
+                }
+                b']' => self.enter_string(S::OscString, StringKind::Os),
+                b'^' => self.enter_string(S::SosPmApcString, StringKind::Privacy),
+                b'_' => self.enter_string(S::SosPmApcString, StringKind::ApplicationProgramCommand),
+                0x30..=0x7E => return self.esc_dispatch(byte),
+                _ => Out::None,
+            },
+            S::EscapeIntermediate => match byte {
+                _ if c0 => self.execute(byte),
+                0x20..=0x2F => {
+                    self.collect(byte);
+                    Out::None
+                }
+                0x30..=0x7E => {
+                    self.state = S::Ground;
+                    self.collect(byte);
+                    if self.overflow {
+                        Out::nFSequenceTooLarge
+                    } else {
+                        return Step::Nf;
+                    }
+                }
+                _ => Out::None,
+            },
+
+            S::CsiEntry | S::CsiParam | S::CsiIntermediate => match (self.state, byte) {
+                _ if c0 => self.execute(byte),
+                (_, 0x20..=0x2F) => {
+                    self.collect(byte);
+                    self.state = S::CsiIntermediate;
+                    Out::None
+                }
+                (S::CsiEntry, 0x30..=0x39 | 0x3B | 0x3C..=0x3F)
+                | (S::CsiParam, 0x30..=0x39 | 0x3B) => {
+                    self.collect(byte);
+                    self.state = S::CsiParam;
+                    Out::None
+                }
+                (_, 0x30..=0x3F) => {
+                    self.state = S::CsiIgnore;
+                    Out::None
+                }
+                (_, 0x40..=0x7E) => return self.csi_dispatch(byte),
+                _ => Out::None,
+            },
+            S::CsiIgnore => match byte {
+                _ if c0 => self.execute(byte),
+                0x40..=0x7E => {
+                    self.state = S::Ground;
+                    Out::None
+                }
+                _ => Out::None,
+            },
+
+            S::DcsEntry | S::DcsParam | S::DcsIntermediate => match (self.state, byte) {
+                _ if c0 => Out::None,
+                (_, 0x20..=0x2F) => {
+                    self.state = S::DcsIntermediate;
+                    Out::DCSData(input as crate::FfiChar)
+                }
+                (S::DcsEntry, 0x30..=0x39 | 0x3B | 0x3C..=0x3F)
+                | (S::DcsParam, 0x30..=0x39 | 0x3B) => {
+                    self.state = S::DcsParam;
+                    Out::DCSData(input as crate::FfiChar)
+                }
+                (_, 0x30..=0x3F) => {
+                    self.state = S::DcsIgnore;
+                    Out::None
+                }
+                (_, 0x40..=0x7E) => {
+                    self.state = S::DcsPassthrough;
+                    Out::DCSData(input as crate::FfiChar)
+                }
+                _ => Out::None,
+            },
+            S::DcsPassthrough => match byte {
+                0x7F => Out::None,
+                _ => Out::DCSData(input as crate::FfiChar),
+            },
+            S::DcsIgnore => Out::None,
+
+            S::OscString if c0 => Out::None,
+            S::OscString => Out::OSData(input as crate::FfiChar),
+            S::SosPmApcString => Out::None,
+        })
+    }
+}
@@ -0,0 +1,128 @@
+use super::*;
+
+/// A feature listed in a primary device attributes report
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub enum DeviceFeature {
+    Columns132,
+    Printer,
+    ReGIS,
+    Sixel,
+    SelectiveErase,
+    /// DRCS
+    SoftCharacterSets,
+    UserDefinedKeys,
+    NationalReplacementCharacterSets,
+    TechnicalCharacters,
+    LocatorPort,
+    TerminalStateInterrogation,
+    UserWindows,
+    HorizontalScrolling,
+    AnsiColor,
+    RectangularEditing,
+    AnsiTextLocator,
+
+    Unknown(u16),
+}
+
+impl From<u16> for DeviceFeature {
+    fn from(code: u16) -> Self {
+        match code {
+            1 => Self::Columns132,
+            2 => Self::Printer,
+            3 => Self::ReGIS,
+            4 => Self::Sixel,
+            6 => Self::SelectiveErase,
+            7 => Self::SoftCharacterSets,
+            8 => Self::UserDefinedKeys,
+            9 => Self::NationalReplacementCharacterSets,
+            15 => Self::TechnicalCharacters,
+            16 => Self::LocatorPort,
+            17 => Self::TerminalStateInterrogation,
+            18 => Self::UserWindows,
+            21 => Self::HorizontalScrolling,
+            22 => Self::AnsiColor,
+            28 => Self::RectangularEditing,
+            29 => Self::AnsiTextLocator,
+            code => Self::Unknown(code),
+        }
+    }
+}
+
+/// The features of a primary device attributes report
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub struct DeviceFeatures<'a>(pub CSIParser<'a>);
+
+impl<'a> core::fmt::Debug for DeviceFeatures<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(*self).finish()
+    }
+}
+
+impl<'a> Iterator for DeviceFeatures<'a> {
+    type Item = DeviceFeature;
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let CSIPart::Param(crate::FfiOption::Some(p)) = self.0.next()? {
+                return Some(p.into());
+            }
+        }
+    }
+}
+
+/// DA1 report, `CSI ? Ps ; Ps... c`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub struct DeviceAttributes<'a> {
+    /// 6x for a device conforming to level x, VT100 class devices report other values
+    pub class: u16,
+    pub features: DeviceFeatures<'a>,
+}
+
+impl DeviceAttributes<'_> {
+    /// The VT level the device conforms to, 1 for VT100 class devices
+    pub fn conformance_level(&self) -> u16 {
+        match self.class {
+            61..=69 => self.class - 60,
+            _ => 1,
+        }
+    }
+}
+
+/// DA2 report, `CSI > Pp ; Pv ; Pc c`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub struct SecondaryDeviceAttributes {
+    pub terminal_type: u16,
+    pub firmware_version: u16,
+    pub rom_cartridge: u16,
+}
+
+/// DA3 report, `DCS ! | XXXXXXXX ST`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub struct TertiaryDeviceAttributes {
+    pub unit_id: u32,
+}
+
+impl TertiaryDeviceAttributes {
+    /// Parses the payload of a [`crate::Out::DCS`]
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn from_dcs(payload: &[u8]) -> Option<Self> {
+        let [b'!', b'|', digits @ ..] = payload else {
+            return None;
+        };
+        if digits.len() != 8 {
+            return None;
+        }
+        let mut unit_id = 0u32;
+        for digit in digits {
+            let value = (*digit as char).to_digit(16)?;
+            unit_id = unit_id << 4 | value;
+        }
+        Some(Self { unit_id })
+    }
+}
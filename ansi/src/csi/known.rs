@@ -42,20 +42,34 @@ pub enum KnownCSI<'a> {
 
     SaveCurrentCursorPosition,
     RestoreCurrentCursorPosition,
-    ShowCursor,
-    HideCursor,
-
-    EnableFocusReporting,
-    DisableFocusReporting,
-
-    EnableBracketPastingMode,
-    DisableBracketPastingMode,
 
     RestoreScreen,
     SaveScreen,
 
-    EnableAlternativeBuffer,
-    DisableAlternativeBuffer,
+    SetModes(AnsiModes<'a>),
+    ResetModes(AnsiModes<'a>),
+    /// DECRQM, CSI ? Ps $ p for a private mode or CSI Ps $ p for an ANSI mode
+    RequestMode {
+        private: bool,
+        mode: u16,
+    },
+    /// DECRPM, CSI ? Ps ; Pm $ y for a private mode or CSI Ps ; Pm $ y for an ANSI mode
+    ModeReport {
+        private: bool,
+        mode: u16,
+        state: ModeState,
+    },
+    /// DA1, CSI c
+    RequestDeviceAttributes,
+    /// DA2, CSI > c
+    RequestSecondaryDeviceAttributes,
+    /// DA3, CSI = c
+    RequestTertiaryDeviceAttributes,
+    DeviceAttributes(DeviceAttributes<'a>),
+    SecondaryDeviceAttributes(SecondaryDeviceAttributes),
+    SetPrivateModes(PrivateModes<'a>),
+    ResetPrivateModes(PrivateModes<'a>),
+    /// CSI = Ps h
     ScreenMode(ScreenMode),
     ResetScreenMode(ScreenMode),
     SetScrollingRegion {
@@ -145,7 +159,67 @@ impl<'a> CSIParser<'a> {
                 let [row, col] = self.parse_params([1, 1])?;
                 KnownCSI::HorizontalVerticalPosition { row, col }
             }
-            (Some(b'?'), Some(b'h')) => match self.parse_params([0])?[0] {
+            (private @ (None | Some(b'?')), Some(b'p'))
+                if self.last_intermediate() == Some(b'$') =>
+            {
+                KnownCSI::RequestMode {
+                    private: private.is_some(),
+                    mode: self.parse_params([0])?[0],
+                }
+            }
+            (private @ (None | Some(b'?')), Some(b'y'))
+                if self.last_intermediate() == Some(b'$') =>
+            {
+                let [mode, state] = self.parse_params([0, 0])?;
+                KnownCSI::ModeReport {
+                    private: private.is_some(),
+                    mode,
+                    state: ModeState::try_from(state).ok()?,
+                }
+            }
+            (None, Some(b'c')) => match self.parse_params([0])?[0] {
+                0 => KnownCSI::RequestDeviceAttributes,
+                _ => None?,
+            },
+            (Some(b'='), Some(b'c')) => match self.parse_params([0])?[0] {
+                0 => KnownCSI::RequestTertiaryDeviceAttributes,
+                _ => None?,
+            },
+            (Some(b'>'), Some(b'c')) => {
+                let params = *self;
+                let count = params.filter(|p| matches!(p, CSIPart::Param(_))).count();
+                let [terminal_type, firmware_version, rom_cartridge] =
+                    self.parse_params([0, 0, 0])?;
+                if count == 1 && terminal_type == 0 {
+                    KnownCSI::RequestSecondaryDeviceAttributes
+                } else {
+                    KnownCSI::SecondaryDeviceAttributes(SecondaryDeviceAttributes {
+                        terminal_type,
+                        firmware_version,
+                        rom_cartridge,
+                    })
+                }
+            }
+            (Some(b'?'), Some(b'c')) if self.only_params() => {
+                let [class] = self.parse_params([0])?;
+                return Some(KnownCSI::DeviceAttributes(DeviceAttributes {
+                    class,
+                    features: DeviceFeatures(*self),
+                }));
+            }
+            (None, Some(b'h')) if self.only_params() => {
+                return Some(KnownCSI::SetModes(AnsiModes(*self)));
+            }
+            (None, Some(b'l')) if self.only_params() => {
+                return Some(KnownCSI::ResetModes(AnsiModes(*self)));
+            }
+            (Some(b'?'), Some(b'h')) if self.only_params() => {
+                return Some(KnownCSI::SetPrivateModes(PrivateModes(*self)));
+            }
+            (Some(b'?'), Some(b'l')) if self.only_params() => {
+                return Some(KnownCSI::ResetPrivateModes(PrivateModes(*self)));
+            }
+            (Some(b'='), Some(b'h')) => match self.parse_params([0])?[0] {
                 0 => KnownCSI::ScreenMode(ScreenMode::Monochrome40x25),
                 1 => KnownCSI::ScreenMode(ScreenMode::Color40x25),
                 2 => KnownCSI::ScreenMode(ScreenMode::Monochrome80x25),
@@ -162,10 +236,6 @@ impl<'a> CSIParser<'a> {
                 17 => KnownCSI::ScreenMode(ScreenMode::GraphicsMonochrome640x480),
                 18 => KnownCSI::ScreenMode(ScreenMode::Graphics16Color640x480),
                 19 => KnownCSI::ScreenMode(ScreenMode::Graphics256Color320x200),
-                25 => KnownCSI::ShowCursor,
-                1004 => KnownCSI::EnableFocusReporting,
-                1049 => KnownCSI::EnableAlternativeBuffer,
-                2004 => KnownCSI::EnableBracketPastingMode,
                 _ => None?,
             },
             (None, Some(b'i')) => match self.parse_params([0])?[0] {
@@ -173,7 +243,7 @@ impl<'a> CSIParser<'a> {
                 5 => KnownCSI::AuxPortOn,
                 _ => None?,
             },
-            (Some(b'?'), Some(b'l')) => match self.parse_params([0])?[0] {
+            (Some(b'='), Some(b'l')) => match self.parse_params([0])?[0] {
                 0 => KnownCSI::ResetScreenMode(ScreenMode::Monochrome40x25),
                 1 => KnownCSI::ResetScreenMode(ScreenMode::Color40x25),
                 2 => KnownCSI::ResetScreenMode(ScreenMode::Monochrome80x25),
@@ -190,10 +260,6 @@ impl<'a> CSIParser<'a> {
                 17 => KnownCSI::ResetScreenMode(ScreenMode::GraphicsMonochrome640x480),
                 18 => KnownCSI::ResetScreenMode(ScreenMode::Graphics16Color640x480),
                 19 => KnownCSI::ResetScreenMode(ScreenMode::Graphics256Color320x200),
-                25 => KnownCSI::HideCursor,
-                1004 => KnownCSI::DisableFocusReporting,
-                1049 => KnownCSI::DisableAlternativeBuffer,
-                2004 => KnownCSI::DisableBracketPastingMode,
                 _ => None?,
             },
             (None, Some(b'm')) => {
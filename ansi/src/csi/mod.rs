@@ -1,20 +1,84 @@
+pub mod attributes;
 pub mod gr;
 pub mod known;
+pub mod modes;
+pub mod params;
 pub mod parser;
 #[cfg(test)]
 mod test;
 
+pub use attributes::*;
 pub use gr::*;
 pub use known::*;
+pub use modes::*;
+pub use params::*;
 pub use parser::*;
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-#[repr(transparent)]
-pub struct CSI<'a>(pub crate::FfiSlice<'a, u8>);
+/// The bytes of a control sequence after the introducer and its parameters if
+/// they were decoded by the parser, see [`crate::Config::csi_params`]
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub struct CSI<'a> {
+    bytes: crate::FfiSlice<'a, u8>,
+    params: crate::FfiOption<&'a CsiParams>,
+}
+
+impl PartialEq for CSI<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl Eq for CSI<'_> {}
 
 impl<'a> CSI<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self::with_params(bytes, None)
+    }
+
+    #[allow(clippy::useless_conversion)]
+    pub(crate) fn with_params(bytes: &'a [u8], params: Option<&'a CsiParams>) -> Self {
+        Self {
+            bytes: bytes.into(),
+            params: params.into(),
+        }
+    }
+
+    #[allow(clippy::useless_conversion)]
+    pub fn bytes(self) -> &'a [u8] {
+        self.bytes.into()
+    }
+
+    /// The decoded parameters, none unless the parser was configured to decode them
+    #[allow(clippy::useless_conversion)]
+    pub fn params(self) -> Option<&'a CsiParams> {
+        self.params.into()
+    }
+
+    /// One of `?`, `<`, `=` or `>` in front of the parameters
+    pub fn private_marker(self) -> Option<u8> {
+        self.bytes()
+            .first()
+            .copied()
+            .filter(|b| matches!(b, b'<'..=b'?'))
+    }
+
+    pub fn intermediates(self) -> &'a [u8] {
+        let bytes = self.bytes();
+        let end = bytes.len().saturating_sub(1);
+        let start = bytes[..end]
+            .iter()
+            .rposition(|b| !matches!(b, 0x20..=0x2F))
+            .map_or(0, |i| i + 1);
+        &bytes[start..end]
+    }
+
+    pub fn final_byte(self) -> Option<u8> {
+        self.bytes().last().copied()
+    }
+
     pub fn parser(self) -> CSIParser<'a> {
-        CSIParser::new(self.0.into())
+        CSIParser::new(self.bytes())
     }
 
     pub fn parse(self) -> KnownCSI<'a> {
@@ -0,0 +1,292 @@
+use super::*;
+
+/// A mode set with `CSI ? Pm h` and reset with `CSI ? Pm l`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub enum DecPrivateMode {
+    /// DECCKM, cursor keys send application sequences
+    CursorKeys,
+    /// DECCOLM, 132 columns
+    Columns132,
+    /// DECSCNM, light background
+    ReverseVideo,
+    /// DECOM, cursor addressing is relative to the scrolling region
+    Origin,
+    /// DECAWM
+    AutoWrap,
+    /// DECARM
+    AutoRepeat,
+    /// Report button presses only
+    X10Mouse,
+    /// DECTCEM
+    ShowCursor,
+    AlternateScreen,
+    /// Report button presses and releases
+    NormalMouse,
+    /// Report motion while a button is held
+    ButtonEventMouse,
+    /// Report all motion
+    AnyEventMouse,
+    FocusReporting,
+    Utf8Mouse,
+    SgrMouse,
+    /// Scroll wheel sends cursor keys on the alternate screen
+    AlternateScroll,
+    UrxvtMouse,
+    /// [`Self::SgrMouse`] reporting pixels instead of cells
+    SgrPixelMouse,
+    MetaSendsEscape,
+    AltSendsEscape,
+    /// Switch to the alternate screen, clearing it when leaving
+    ClearAlternateScreen,
+    /// Save the cursor as DECSC does, restore it when reset
+    SaveCursor,
+    /// [`Self::SaveCursor`] followed by [`Self::ClearAlternateScreen`]
+    SaveCursorAlternateScreen,
+    BracketedPaste,
+    SynchronizedOutput,
+    GraphemeClustering,
+
+    Unknown(u16),
+}
+
+impl DecPrivateMode {
+    pub const fn code(self) -> u16 {
+        match self {
+            Self::CursorKeys => 1,
+            Self::Columns132 => 3,
+            Self::ReverseVideo => 5,
+            Self::Origin => 6,
+            Self::AutoWrap => 7,
+            Self::AutoRepeat => 8,
+            Self::X10Mouse => 9,
+            Self::ShowCursor => 25,
+            Self::AlternateScreen => 47,
+            Self::NormalMouse => 1000,
+            Self::ButtonEventMouse => 1002,
+            Self::AnyEventMouse => 1003,
+            Self::FocusReporting => 1004,
+            Self::Utf8Mouse => 1005,
+            Self::SgrMouse => 1006,
+            Self::AlternateScroll => 1007,
+            Self::UrxvtMouse => 1015,
+            Self::SgrPixelMouse => 1016,
+            Self::MetaSendsEscape => 1036,
+            Self::AltSendsEscape => 1039,
+            Self::ClearAlternateScreen => 1047,
+            Self::SaveCursor => 1048,
+            Self::SaveCursorAlternateScreen => 1049,
+            Self::BracketedPaste => 2004,
+            Self::SynchronizedOutput => 2026,
+            Self::GraphemeClustering => 2027,
+            Self::Unknown(code) => code,
+        }
+    }
+}
+
+impl From<u16> for DecPrivateMode {
+    fn from(code: u16) -> Self {
+        match code {
+            1 => Self::CursorKeys,
+            3 => Self::Columns132,
+            5 => Self::ReverseVideo,
+            6 => Self::Origin,
+            7 => Self::AutoWrap,
+            8 => Self::AutoRepeat,
+            9 => Self::X10Mouse,
+            25 => Self::ShowCursor,
+            47 => Self::AlternateScreen,
+            1000 => Self::NormalMouse,
+            1002 => Self::ButtonEventMouse,
+            1003 => Self::AnyEventMouse,
+            1004 => Self::FocusReporting,
+            1005 => Self::Utf8Mouse,
+            1006 => Self::SgrMouse,
+            1007 => Self::AlternateScroll,
+            1015 => Self::UrxvtMouse,
+            1016 => Self::SgrPixelMouse,
+            1036 => Self::MetaSendsEscape,
+            1039 => Self::AltSendsEscape,
+            1047 => Self::ClearAlternateScreen,
+            1048 => Self::SaveCursor,
+            1049 => Self::SaveCursorAlternateScreen,
+            2004 => Self::BracketedPaste,
+            2026 => Self::SynchronizedOutput,
+            2027 => Self::GraphemeClustering,
+            code => Self::Unknown(code),
+        }
+    }
+}
+
+/// The modes of a `CSI ? Pm h` or `CSI ? Pm l` sequence, an empty parameter is mode 0
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub struct PrivateModes<'a>(pub CSIParser<'a>);
+
+impl<'a> core::fmt::Debug for PrivateModes<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(*self).finish()
+    }
+}
+
+impl<'a> Iterator for PrivateModes<'a> {
+    type Item = DecPrivateMode;
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let CSIPart::Param(p) = self.0.next()? {
+                return Some(p.unwrap_or(0).into());
+            }
+        }
+    }
+}
+
+/// A mode set with `CSI Pm h` and reset with `CSI Pm l`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub enum AnsiMode {
+    /// KAM, the keyboard is locked
+    KeyboardAction,
+    /// IRM, characters are inserted instead of replacing the ones on screen
+    Insert,
+    /// SRM, local echo is off when set
+    SendReceive,
+    /// LNM, line feeds also return the cursor
+    LineFeedNewLine,
+
+    Unknown(u16),
+}
+
+impl AnsiMode {
+    pub const fn code(self) -> u16 {
+        match self {
+            Self::KeyboardAction => 2,
+            Self::Insert => 4,
+            Self::SendReceive => 12,
+            Self::LineFeedNewLine => 20,
+            Self::Unknown(code) => code,
+        }
+    }
+}
+
+impl From<u16> for AnsiMode {
+    fn from(code: u16) -> Self {
+        match code {
+            2 => Self::KeyboardAction,
+            4 => Self::Insert,
+            12 => Self::SendReceive,
+            20 => Self::LineFeedNewLine,
+            code => Self::Unknown(code),
+        }
+    }
+}
+
+/// The modes of a `CSI Pm h` or `CSI Pm l` sequence, an empty parameter is mode 0
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub struct AnsiModes<'a>(pub CSIParser<'a>);
+
+impl<'a> core::fmt::Debug for AnsiModes<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(*self).finish()
+    }
+}
+
+impl<'a> Iterator for AnsiModes<'a> {
+    type Item = AnsiMode;
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let CSIPart::Param(p) = self.0.next()? {
+                return Some(p.unwrap_or(0).into());
+            }
+        }
+    }
+}
+
+/// The state of a mode in a DECRPM report
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum ModeState {
+    NotRecognized = 0,
+    Set = 1,
+    Reset = 2,
+    PermanentlySet = 3,
+    PermanentlyReset = 4,
+}
+
+impl TryFrom<u16> for ModeState {
+    type Error = ();
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::NotRecognized,
+            1 => Self::Set,
+            2 => Self::Reset,
+            3 => Self::PermanentlySet,
+            4 => Self::PermanentlyReset,
+            _ => return Err(()),
+        })
+    }
+}
+
+/// Writes a DECRQM query, `CSI ? Ps $ p` for a private mode and `CSI Ps $ p` otherwise
+pub fn write_request_mode<S: crate::ByteSink + ?Sized>(
+    sink: &mut S,
+    form: crate::ControlForm,
+    private: bool,
+    mode: u16,
+) -> Result<(), S::Error> {
+    crate::C1::CSI.write_bytes(sink, form)?;
+    if private {
+        sink.write_bytes(b"?")?;
+    }
+    crate::ansi::write_param(sink, mode)?;
+    sink.write_bytes(b"$p")
+}
+
+pub fn write_request_mode_to(
+    w: &mut impl core::fmt::Write,
+    form: crate::ControlForm,
+    private: bool,
+    mode: u16,
+) -> core::fmt::Result {
+    write_request_mode(&mut crate::ansi::FmtSink(w), form, private, mode)
+}
+
+/// Writes a DECRPM report, `CSI ? Ps ; Pm $ y` for a private mode and `CSI Ps ; Pm $ y` otherwise
+pub fn write_mode_report<S: crate::ByteSink + ?Sized>(
+    sink: &mut S,
+    form: crate::ControlForm,
+    private: bool,
+    mode: u16,
+    state: ModeState,
+) -> Result<(), S::Error> {
+    crate::C1::CSI.write_bytes(sink, form)?;
+    if private {
+        sink.write_bytes(b"?")?;
+    }
+    crate::ansi::write_param(sink, mode)?;
+    sink.write_bytes(&[b';', b'0' + state as u8, b'$', b'y'])
+}
+
+pub fn write_mode_report_to(
+    w: &mut impl core::fmt::Write,
+    form: crate::ControlForm,
+    private: bool,
+    mode: u16,
+    state: ModeState,
+) -> core::fmt::Result {
+    write_mode_report(&mut crate::ansi::FmtSink(w), form, private, mode, state)
+}
+
+impl<'a> CSIParser<'a> {
+    /// The remaining parts are all plain parameters
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub(super) fn only_params(&self) -> bool {
+        let mut copy = *self;
+        copy.all(|part| matches!(part, CSIPart::Param(_)))
+    }
+}
@@ -0,0 +1,161 @@
+/// The number of parameters and subparameters [`CsiParams`] can hold
+pub const CSI_MAX_PARAMS: usize = 32;
+
+/// The parameters of a control sequence, decoded while the sequence is
+/// collected when [`crate::Config::csi_params`] is enabled.
+///
+/// Values are decoded the same way as by [`crate::CSIParser`] with
+/// [`crate::ParamOverflow::Saturate`], a private marker in front of the
+/// parameters is not part of them.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub struct CsiParams {
+    /// Parameters and their subparameters in order
+    values: [crate::FfiOption<u16>; CSI_MAX_PARAMS],
+    /// The index into `values` each parameter is at
+    starts: [u8; CSI_MAX_PARAMS],
+    entries: u8,
+    params: u8,
+    overflow: bool,
+    /// A digit was seen, private markers after it start a new parameter
+    started: bool,
+    marker: bool,
+    decoded: usize,
+}
+
+impl Default for CsiParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CsiParams {
+    pub const fn new() -> Self {
+        Self {
+            values: [crate::FfiOption::None; CSI_MAX_PARAMS],
+            starts: [0; CSI_MAX_PARAMS],
+            entries: 1,
+            params: 1,
+            overflow: false,
+            started: false,
+            marker: false,
+            decoded: 0,
+        }
+    }
+
+    /// The number of parameters, at least one as an empty sequence has a single default parameter
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn param_count(&self) -> usize {
+        self.params as usize
+    }
+
+    /// The value of parameter `i`, none if it is empty or out of range
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    #[allow(clippy::useless_conversion)]
+    pub fn param(&self, i: usize) -> crate::FfiOption<u16> {
+        if i >= self.param_count() {
+            return crate::FfiOption::None;
+        }
+        self.starts
+            .get(i)
+            .and_then(|start| self.values.get(*start as usize))
+            .copied()
+            .unwrap_or(crate::FfiOption::None)
+    }
+
+    /// The subparameters following parameter `i`
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn subparams(&self, i: usize) -> &[crate::FfiOption<u16>] {
+        if i >= self.param_count() {
+            return &[];
+        }
+        let start = self.starts.get(i).map_or(0, |s| *s as usize + 1);
+        let end = match self.starts.get(i + 1) {
+            Some(end) if i + 1 < self.param_count() => *end as usize,
+            _ => self.entries as usize,
+        };
+        self.values.get(start..end).unwrap_or(&[])
+    }
+
+    /// More than [`CSI_MAX_PARAMS`] parameters and subparameters were present,
+    /// the ones past the limit are missing
+    pub fn overflowed(&self) -> bool {
+        self.overflow
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub(crate) fn clear(&mut self) {
+        self.values[0] = crate::FfiOption::None;
+        self.entries = 1;
+        self.params = 1;
+        self.overflow = false;
+        self.started = false;
+        self.marker = false;
+        self.decoded = 0;
+    }
+
+    /// The number of bytes passed to [`Self::push`] since the last clear
+    pub(crate) fn decoded(&self) -> usize {
+        self.decoded
+    }
+
+    /// Decodes the parameter bytes at the front of a collected sequence
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub(crate) fn decode(&mut self, raw: &[u8]) {
+        self.clear();
+        for byte in raw {
+            if !matches!(byte, 0x30..=0x3F) {
+                break;
+            }
+            self.push(*byte);
+        }
+    }
+
+    /// Decodes the next parameter byte
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub(crate) fn push(&mut self, byte: u8) {
+        self.decoded = self.decoded.wrapping_add(1);
+        let marker = core::mem::take(&mut self.marker);
+        match byte {
+            b'0'..=b'9' => {
+                if marker {
+                    self.start_entry(true);
+                }
+                self.started = true;
+                let d = (byte - b'0') as u16;
+                if let Some(value) = self.values.get_mut((self.entries as usize).wrapping_sub(1))
+                    && !self.overflow
+                {
+                    *value = crate::FfiOption::Some(match *value {
+                        crate::FfiOption::Some(v) => v.saturating_mul(10).saturating_add(d),
+                        crate::FfiOption::None => d,
+                    });
+                }
+            }
+            b';' | b':' => {
+                self.started = true;
+                self.start_entry(byte == b';');
+            }
+            _ => self.marker = self.started,
+        }
+    }
+
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    fn start_entry(&mut self, param: bool) {
+        let index = self.entries as usize;
+        if self.overflow || index >= CSI_MAX_PARAMS {
+            self.overflow = true;
+            return;
+        }
+        if param {
+            if let Some(start) = self.starts.get_mut(self.params as usize) {
+                *start = self.entries;
+            }
+            self.params += 1;
+        }
+        if let Some(value) = self.values.get_mut(index) {
+            *value = crate::FfiOption::None;
+        }
+        self.entries += 1;
+    }
+}
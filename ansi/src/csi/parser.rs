@@ -12,6 +12,41 @@ pub enum CSIPart {
 
     Intermediate(u8),
     Final(u8),
+
+    /// A parameter which does not fit, see [`ParamOverflow::Report`]
+    ParamOverflow {
+        sub: bool,
+    },
+}
+
+/// What [`CSIParser`] does with parameters larger than the type they are parsed into
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub enum ParamOverflow {
+    /// Clamp the value to the largest one representable
+    #[default]
+    Saturate,
+    /// Produce [`CSIPart::ParamOverflow`] in place of the parameter
+    Report,
+}
+
+/// A [`CSIPart`] with parameters parsed as `u32`, see [`CSIParser::next_wide`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "crepr", repr(C))]
+pub enum WideCSIPart {
+    Param(crate::FfiOption<u32>),
+    SubParam(crate::FfiOption<u32>),
+    Other(CSIPart),
+}
+
+/// A number or a part which is not one
+enum Token {
+    Part(CSIPart),
+    Value {
+        sub: bool,
+        value: crate::FfiOption<u32>,
+        overflow: bool,
+    },
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -23,13 +58,18 @@ enum CSIParserState {
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 #[cfg_attr(feature = "crepr", repr(C))]
-pub struct CSIParser<'a>(crate::FfiSlice<'a, u8>, CSIParserState);
+pub struct CSIParser<'a>(crate::FfiSlice<'a, u8>, CSIParserState, ParamOverflow);
 
 impl<'a> CSIParser<'a> {
     #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
     pub fn new(input: &'a [u8]) -> Self {
         #[allow(clippy::useless_conversion)]
-        Self(input.into(), CSIParserState::Start)
+        Self(input.into(), CSIParserState::Start, ParamOverflow::Saturate)
+    }
+
+    pub fn with_overflow(mut self, overflow: ParamOverflow) -> Self {
+        self.2 = overflow;
+        self
     }
 
     #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
@@ -78,6 +118,15 @@ impl<'a> CSIParser<'a> {
         None
     }
 
+    /// Takes the last intermediate byte, to be called after [`Self::final_identifier`]
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn last_intermediate(&mut self) -> Option<u8> {
+        if matches!(self.peek_last()?, 0x20..=0x2F) {
+            return self.pop_back();
+        }
+        None
+    }
+
     #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
     pub fn parse_params<const N: usize>(&mut self, default: [u16; N]) -> Option<[u16; N]> {
         let mut result = [0; N];
@@ -120,6 +169,91 @@ impl<'a> CSIParser<'a> {
         Some(result)
     }
 
+    /// Like [`Self::parse_params`] for parameters which can be larger than `u16`
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn parse_params_wide<const N: usize>(&mut self, default: [u32; N]) -> Option<[u32; N]> {
+        let mut result = [0; N];
+        for i in 0..N {
+            let mut copy = *self;
+            match copy.next_wide() {
+                Some(WideCSIPart::Param(p)) => result[i] = p.unwrap_or(default[i]),
+                Some(WideCSIPart::Other(CSIPart::Intermediate(_) | CSIPart::Final(_))) | None => {
+                    result[i] = default[i];
+                    continue;
+                }
+                _ => return None,
+            }
+            *self = copy;
+        }
+        Some(result)
+    }
+
+    /// Like [`Iterator::next`] with parameters parsed as `u32`
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    pub fn next_wide(&mut self) -> Option<WideCSIPart> {
+        Some(match self.token(u32::MAX)? {
+            Token::Part(part) => WideCSIPart::Other(part),
+            Token::Value { sub, overflow, .. } if overflow && self.2 == ParamOverflow::Report => {
+                WideCSIPart::Other(CSIPart::ParamOverflow { sub })
+            }
+            Token::Value {
+                sub: true, value, ..
+            } => WideCSIPart::SubParam(value),
+            Token::Value { value, .. } => WideCSIPart::Param(value),
+        })
+    }
+
+    /// Takes the next part, numbers larger than `max` are clamped to it
+    #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
+    #[allow(clippy::useless_conversion)]
+    fn token(&mut self, max: u32) -> Option<Token> {
+        if self.1 == CSIParserState::Start {
+            if matches!(self.peek_first(), None | Some(0x20..=0x2F|0x40..=0x7E|b':'|b';')) {
+                self.1 = CSIParserState::Middle;
+                return Some(Token::Value {
+                    sub: false,
+                    value: crate::FfiOption::None,
+                    overflow: false,
+                });
+            } else if matches!(self.peek_first(), Some(b'0'..=b'9')) {
+                self.1 = CSIParserState::Middle;
+            }
+        }
+        let (sub, mut value) = match self.pop_front() {
+            Some(b'?') => return Some(Token::Part(CSIPart::Question)),
+            Some(b'=') => return Some(Token::Part(CSIPart::Eq)),
+            Some(b'>') => return Some(Token::Part(CSIPart::Gt)),
+            Some(b'<') => return Some(Token::Part(CSIPart::Lt)),
+            Some(v @ 0x20..=0x2F) => return Some(Token::Part(CSIPart::Intermediate(v))),
+            Some(v @ 0x40..=0x7E) => return Some(Token::Part(CSIPart::Final(v))),
+            Some(b':') => (true, None),
+            Some(b';') => (false, None),
+            Some(v @ b'0'..=b'9') => (false, Some((v - b'0') as u32)),
+            _ => return None,
+        };
+        let mut overflow = false;
+        while let Some(v @ b'0'..=b'9') = self.peek_first() {
+            self.pop_front();
+            let d = (v - b'0') as u32;
+            let next = value
+                .unwrap_or(0)
+                .checked_mul(10)
+                .and_then(|v| v.checked_add(d));
+            value = match next.filter(|v| *v <= max) {
+                Some(v) => Some(v),
+                None => {
+                    overflow = true;
+                    Some(max)
+                }
+            };
+        }
+        Some(Token::Value {
+            sub,
+            value: value.into(),
+            overflow,
+        })
+    }
+
     #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
     pub fn peek(&self) -> Option<CSIPart> {
         let mut copy = *self;
@@ -136,48 +270,19 @@ impl<'a> Iterator for CSIParser<'a> {
 
     #[cfg_attr(feature = "no_panic", no_panic::no_panic)]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.1 == CSIParserState::Start {
-            if matches!(self.peek_first(), None | Some(0x20..=0x2F|0x40..=0x7E|b':'|b';')) {
-                self.1 = CSIParserState::Middle;
-                return Some(CSIPart::Param(crate::FfiOption::None));
-            } else if matches!(self.peek_first(), Some(b'0'..=b'9')) {
-                self.1 = CSIParserState::Middle;
-            }
-        }
-        let mut value = crate::FfiOption::None;
-        let sub;
-        match self.pop_front() {
-            Some(b'?') => return Some(CSIPart::Question),
-            Some(b'=') => return Some(CSIPart::Eq),
-            Some(b'>') => return Some(CSIPart::Gt),
-            Some(b'<') => return Some(CSIPart::Lt),
-            Some(v @ 0x20..=0x2F) => return Some(CSIPart::Intermediate(v)),
-            Some(v @ 0x40..=0x7E) => return Some(CSIPart::Final(v)),
-            Some(b':') => {
-                sub = true;
-            }
-            Some(b';') => {
-                sub = false;
+        Some(match self.token(u16::MAX as u32)? {
+            Token::Part(part) => part,
+            Token::Value { sub, overflow, .. } if overflow && self.2 == ParamOverflow::Report => {
+                CSIPart::ParamOverflow { sub }
             }
-            Some(v @ b'0'..=b'9') => {
-                sub = false;
-                value = crate::FfiOption::Some((v - b'0') as u16);
-            }
-            _ => return None,
-        }
-        while let Some(v @ b'0'..=b'9') = self.peek_first() {
-            self.pop_front();
-            let d = (v - b'0') as u16;
-            if let crate::FfiOption::Some(v) = value {
-                value = crate::FfiOption::Some(v.wrapping_mul(10).wrapping_add(d))
-            } else {
-                value = crate::FfiOption::Some(d);
+            Token::Value { sub, value, .. } => {
+                let value = value.map(|v| v as u16);
+                if sub {
+                    CSIPart::SubParam(value)
+                } else {
+                    CSIPart::Param(value)
+                }
             }
-        }
-        if sub {
-            Some(CSIPart::SubParam(value))
-        } else {
-            Some(CSIPart::Param(value))
-        }
+        })
     }
 }
@@ -243,7 +243,7 @@ fn expect_csi_params<const N: usize>(
 fn private_sequences() {
     let modes = [0, 1, 2, 3, 4, 5, 6, 7, 12, 13, 14, 15, 16, 17, 18, 19];
     for mode in modes {
-        let bytes = format!("?{mode}h");
+        let bytes = format!("={mode}h");
         let mut parser = crate::CSIParser::new(bytes.as_bytes());
         match parser.parse() {
             crate::KnownCSI::ScreenMode(sm) if sm as i32 == mode => {}
@@ -252,7 +252,7 @@ fn private_sequences() {
     }
 
     for mode in modes {
-        let bytes = format!("?{mode}l");
+        let bytes = format!("={mode}l");
         let mut parser = crate::CSIParser::new(bytes.as_bytes());
         match parser.parse() {
             crate::KnownCSI::ResetScreenMode(sm) if sm as i32 == mode => {}
@@ -260,17 +260,252 @@ fn private_sequences() {
         }
     }
 
-    expect_csi(b"?25h", crate::KnownCSI::ShowCursor);
-    expect_csi(b"?25l", crate::KnownCSI::HideCursor);
+    let modes = |bytes: &[u8]| match crate::CSIParser::new(bytes).parse() {
+        crate::KnownCSI::SetPrivateModes(modes) => (true, modes.collect::<std::vec::Vec<_>>()),
+        crate::KnownCSI::ResetPrivateModes(modes) => (false, modes.collect()),
+        wrong => panic!("{wrong:?}"),
+    };
+    use crate::DecPrivateMode::*;
+    assert_eq!(modes(b"?25h"), (true, [ShowCursor].into()));
+    assert_eq!(modes(b"?25l"), (false, [ShowCursor].into()));
+    assert_eq!(
+        modes(b"?1049;25h"),
+        (true, [SaveCursorAlternateScreen, ShowCursor].into())
+    );
+    assert_eq!(
+        modes(b"?1000;1002;1003;1005;1006;1015;1016l"),
+        (
+            false,
+            [
+                NormalMouse,
+                ButtonEventMouse,
+                AnyEventMouse,
+                Utf8Mouse,
+                SgrMouse,
+                UrxvtMouse,
+                SgrPixelMouse
+            ]
+            .into()
+        )
+    );
+    assert_eq!(
+        modes(b"?1;3;5;6;7;8;9;47;1047;1048;1007;1036;1039;1004;2004;2026;2027h"),
+        (
+            true,
+            [
+                CursorKeys,
+                Columns132,
+                ReverseVideo,
+                Origin,
+                AutoWrap,
+                AutoRepeat,
+                X10Mouse,
+                AlternateScreen,
+                ClearAlternateScreen,
+                SaveCursor,
+                AlternateScroll,
+                MetaSendsEscape,
+                AltSendsEscape,
+                FocusReporting,
+                BracketedPaste,
+                SynchronizedOutput,
+                GraphemeClustering
+            ]
+            .into()
+        )
+    );
+    assert_eq!(
+        modes(b"?12;;h"),
+        (true, [Unknown(12), Unknown(0), Unknown(0)].into())
+    );
+    for code in 0..=u16::MAX {
+        assert_eq!(crate::DecPrivateMode::from(code).code(), code);
+    }
 
-    expect_csi(b"?1004h", crate::KnownCSI::EnableFocusReporting);
-    expect_csi(b"?1004l", crate::KnownCSI::DisableFocusReporting);
+    expect_csi(
+        b"?1:2h",
+        crate::KnownCSI::Unknown(crate::CSIParser::new(b"?1:2h")),
+    );
+    expect_csi(
+        b"?25$h",
+        crate::KnownCSI::Unknown(crate::CSIParser::new(b"?25$h")),
+    );
+}
+
+#[test]
+fn ansi_modes() {
+    use crate::AnsiMode::*;
+    let modes = |bytes: &[u8]| match crate::CSIParser::new(bytes).parse() {
+        crate::KnownCSI::SetModes(modes) => (true, modes.collect::<std::vec::Vec<_>>()),
+        crate::KnownCSI::ResetModes(modes) => (false, modes.collect()),
+        wrong => panic!("{wrong:?}"),
+    };
+    assert_eq!(modes(b"4h"), (true, [Insert].into()));
+    assert_eq!(modes(b"20l"), (false, [LineFeedNewLine].into()));
+    assert_eq!(
+        modes(b"2;4;12;20;3h"),
+        (
+            true,
+            [
+                KeyboardAction,
+                Insert,
+                SendReceive,
+                LineFeedNewLine,
+                Unknown(3)
+            ]
+            .into()
+        )
+    );
+    for code in [0, 2, 4, 12, 20, 2027] {
+        assert_eq!(crate::AnsiMode::from(code).code(), code);
+    }
+    expect_csi(
+        b"4:1h",
+        crate::KnownCSI::Unknown(crate::CSIParser::new(b"4:1h")),
+    );
+}
 
-    expect_csi(b"?1049h", crate::KnownCSI::EnableAlternativeBuffer);
-    expect_csi(b"?1049l", crate::KnownCSI::DisableAlternativeBuffer);
+#[test]
+fn mode_queries() {
+    use crate::{ControlForm, KnownCSI, ModeState, write_mode_report, write_mode_report_to};
+
+    expect_csi(
+        b"?2026$p",
+        KnownCSI::RequestMode {
+            private: true,
+            mode: 2026,
+        },
+    );
+    expect_csi(
+        b"4$p",
+        KnownCSI::RequestMode {
+            private: false,
+            mode: 4,
+        },
+    );
+    expect_csi(
+        b"?2027;3$y",
+        KnownCSI::ModeReport {
+            private: true,
+            mode: 2027,
+            state: ModeState::PermanentlySet,
+        },
+    );
+    expect_csi(
+        b"20;0$y",
+        KnownCSI::ModeReport {
+            private: false,
+            mode: 20,
+            state: ModeState::NotRecognized,
+        },
+    );
+    expect_csi(
+        b"?1;5$y",
+        KnownCSI::Unknown(crate::CSIParser::new(b"?1;5$y")),
+    );
+    expect_csi(b"?1!p", KnownCSI::Unknown(crate::CSIParser::new(b"?1!p")));
+    expect_csi(b"?1p", KnownCSI::Unknown(crate::CSIParser::new(b"?1p")));
 
-    expect_csi(b"?2004h", crate::KnownCSI::EnableBracketPastingMode);
-    expect_csi(b"?2004l", crate::KnownCSI::DisableBracketPastingMode);
+    let mut string = std::string::String::new();
+    crate::write_request_mode_to(&mut string, ControlForm::SevenBit, true, 2026).unwrap();
+    assert_eq!(string, "\x1b[?2026$p");
+
+    let mut buffer = [0; 16];
+    let mut sink = &mut buffer[..];
+    write_mode_report(&mut sink, ControlForm::SevenBit, false, 0, ModeState::Reset).unwrap();
+    let len = 16 - sink.len();
+    assert_eq!(&buffer[..len], b"\x1b[0;2$y");
+
+    for (private, mode, state) in [
+        (true, 65535, ModeState::Set),
+        (false, 12, ModeState::PermanentlyReset),
+    ] {
+        let mut string = std::string::String::new();
+        write_mode_report_to(&mut string, ControlForm::SevenBit, private, mode, state).unwrap();
+        expect_csi(
+            &string.as_bytes()[2..],
+            KnownCSI::ModeReport {
+                private,
+                mode,
+                state,
+            },
+        );
+    }
+}
+
+#[test]
+fn device_attributes() {
+    use crate::{DeviceFeature::*, KnownCSI, SecondaryDeviceAttributes, TertiaryDeviceAttributes};
+
+    expect_csi(b"c", KnownCSI::RequestDeviceAttributes);
+    expect_csi(b"0c", KnownCSI::RequestDeviceAttributes);
+    expect_csi(b">c", KnownCSI::RequestSecondaryDeviceAttributes);
+    expect_csi(b">0c", KnownCSI::RequestSecondaryDeviceAttributes);
+    expect_csi(b"=c", KnownCSI::RequestTertiaryDeviceAttributes);
+
+    let KnownCSI::DeviceAttributes(da) =
+        crate::CSIParser::new(b"?64;1;2;4;6;9;15;22;28;42c").parse()
+    else {
+        panic!()
+    };
+    assert_eq!(da.class, 64);
+    assert_eq!(da.conformance_level(), 4);
+    assert_eq!(
+        da.features.collect::<std::vec::Vec<_>>(),
+        [
+            Columns132,
+            Printer,
+            Sixel,
+            SelectiveErase,
+            NationalReplacementCharacterSets,
+            TechnicalCharacters,
+            AnsiColor,
+            RectangularEditing,
+            Unknown(42)
+        ]
+    );
+    let KnownCSI::DeviceAttributes(da) = crate::CSIParser::new(b"?1;2c").parse() else {
+        panic!()
+    };
+    assert_eq!(da.conformance_level(), 1);
+
+    expect_csi(
+        b">41;380;0c",
+        KnownCSI::SecondaryDeviceAttributes(SecondaryDeviceAttributes {
+            terminal_type: 41,
+            firmware_version: 380,
+            rom_cartridge: 0,
+        }),
+    );
+    expect_csi(
+        b">0;10;1c",
+        KnownCSI::SecondaryDeviceAttributes(SecondaryDeviceAttributes {
+            terminal_type: 0,
+            firmware_version: 10,
+            rom_cartridge: 1,
+        }),
+    );
+
+    assert_eq!(
+        TertiaryDeviceAttributes::from_dcs(b"!|7E565445"),
+        Some(TertiaryDeviceAttributes {
+            unit_id: 0x7E565445
+        })
+    );
+    assert_eq!(TertiaryDeviceAttributes::from_dcs(b"!|0000000"), None);
+    assert_eq!(TertiaryDeviceAttributes::from_dcs(b"!|0000000g"), None);
+    assert_eq!(TertiaryDeviceAttributes::from_dcs(b"$|00000000"), None);
+
+    // the report as it arrives from the parser
+    let mut parser = crate::SizedAnsiParser::<16>::new();
+    parser.cfg.buffer_strings = true;
+    let mut unit = None;
+    for b in b"\x1bP!|00000000\x1b\\" {
+        if let crate::Out::DCS { payload, .. } = parser.next(*b) {
+            unit = TertiaryDeviceAttributes::from_dcs(&payload);
+        }
+    }
+    assert_eq!(unit, Some(TertiaryDeviceAttributes { unit_id: 0 }));
 }
 
 #[test]
@@ -359,3 +594,61 @@ fn param_idx_sequences() {
         crate::KnownCSI::ReportCursorPosition
     );
 }
+
+#[test]
+fn csi_param_overflow() {
+    use crate::{CSIParser, CSIPart, FfiOption, KnownCSI, ParamOverflow, WideCSIPart};
+
+    assert_eq!(
+        CSIParser::new(b"65537A").parse(),
+        KnownCSI::CursorUp(u16::MAX)
+    );
+    assert_eq!(
+        CSIParser::new(b"65535;99999999999:7m").collect::<std::vec::Vec<_>>(),
+        [
+            CSIPart::Param(FfiOption::Some(u16::MAX)),
+            CSIPart::Param(FfiOption::Some(u16::MAX)),
+            CSIPart::SubParam(FfiOption::Some(7)),
+            CSIPart::Final(b'm')
+        ]
+    );
+
+    let mut parser = CSIParser::new(b"65536;1:65536A").with_overflow(ParamOverflow::Report);
+    assert_eq!(
+        parser.collect::<std::vec::Vec<_>>(),
+        [
+            CSIPart::ParamOverflow { sub: false },
+            CSIPart::Param(FfiOption::Some(1)),
+            CSIPart::ParamOverflow { sub: true },
+            CSIPart::Final(b'A')
+        ]
+    );
+    assert!(matches!(parser.parse(), KnownCSI::Unknown(_)));
+
+    let mut parser = CSIParser::new(b"4;1080;1920:5t");
+    assert_eq!(
+        parser.next_wide(),
+        Some(WideCSIPart::Param(FfiOption::Some(4)))
+    );
+    assert_eq!(parser.parse_params_wide([0, 0]), Some([1080, 1920]));
+    assert_eq!(
+        parser.next_wide(),
+        Some(WideCSIPart::SubParam(FfiOption::Some(5)))
+    );
+    assert_eq!(
+        parser.next_wide(),
+        Some(WideCSIPart::Other(CSIPart::Final(b't')))
+    );
+    assert_eq!(parser.next_wide(), None);
+
+    assert_eq!(
+        CSIParser::new(b"4294967295;4294967296t").parse_params_wide([0, 0, 7]),
+        Some([u32::MAX, u32::MAX, 7])
+    );
+    assert_eq!(
+        CSIParser::new(b"4294967296t")
+            .with_overflow(ParamOverflow::Report)
+            .next_wide(),
+        Some(WideCSIPart::Other(CSIPart::ParamOverflow { sub: false }))
+    );
+}
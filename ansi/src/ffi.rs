@@ -58,6 +58,43 @@ impl<'a, T> Deref for FfiSlice<'a, T> {
     }
 }
 
+/// A [`FfiSlice`] which is guaranteed to contain valid UTF-8
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct FfiStr<'a>(FfiSlice<'a, u8>);
+
+impl<'a> From<&'a str> for FfiStr<'a> {
+    fn from(value: &'a str) -> Self {
+        Self(value.as_bytes().into())
+    }
+}
+
+impl<'a> From<FfiStr<'a>> for &'a str {
+    fn from(value: FfiStr<'a>) -> Self {
+        value.as_str()
+    }
+}
+
+impl<'a> Debug for FfiStr<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl<'a> FfiStr<'a> {
+    pub fn as_str(self) -> &'a str {
+        unsafe { core::str::from_utf8_unchecked(self.0.as_slice()) }
+    }
+}
+
+impl<'a> Deref for FfiStr<'a> {
+    type Target = str;
+
+    fn deref(self: &'_ FfiStr<'a>) -> &'a str {
+        (*self).as_str()
+    }
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
 pub enum FfiOption<T> {
@@ -73,6 +110,13 @@ impl<T> FfiOption<T> {
             Self::None => default,
         }
     }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> FfiOption<U> {
+        match self {
+            Self::Some(v) => FfiOption::Some(f(v)),
+            Self::None => FfiOption::None,
+        }
+    }
 }
 
 impl<T> From<Option<T>> for FfiOption<T> {
@@ -1,8 +1,10 @@
 #![no_std]
 #![cfg_attr(not(feature = "crepr"), forbid(unsafe_code))]
 
-#[cfg(test)]
-#[macro_use]
+#[cfg(any(test, feature = "alloc"))]
+extern crate alloc;
+#[cfg(any(test, feature = "std"))]
+#[cfg_attr(test, macro_use)]
 extern crate std;
 
 pub mod ansi;
@@ -19,6 +21,8 @@ pub use ffi::*;
 #[cfg(not(feature = "crepr"))]
 pub type FfiSlice<'a, T> = &'a [T];
 #[cfg(not(feature = "crepr"))]
+pub type FfiStr<'a> = &'a str;
+#[cfg(not(feature = "crepr"))]
 pub use Option as FfiOption;
 #[cfg(not(feature = "crepr"))]
 pub use char as FfiChar;
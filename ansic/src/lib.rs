@@ -39,14 +39,41 @@ pub unsafe extern "C" fn ansic_next<'a>(
     parser.next(input)
 }
 
+#[unsafe(no_mangle)]
+/// # Safety
+/// The parser pointer must be valid and initialized.
+///
+/// The parser must have at least buffer_size bytes allocated after the structure which are initialized to some value.
+///
+/// The input pointer must be valid for reads of len bytes.
+///
+/// The return value is only valid until any modification is made to the parser or the input.
+pub unsafe extern "C" fn ansic_feed<'a>(
+    parser: *mut AnsiParser,
+    buffer_size: usize,
+    input: *const u8,
+    len: usize,
+) -> ansi::Feed<'a> {
+    let (parser, input) = unsafe {
+        (
+            (core::ptr::slice_from_raw_parts_mut(parser, buffer_size)
+                as *mut ansi::AnsiParser<[u8]>)
+                .as_mut()
+                .unwrap_unchecked(),
+            core::slice::from_raw_parts(input, len),
+        )
+    };
+    parser.feed(input)
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn ansic_parse_csi<'a>(csi: ansi::CSI<'a>) -> ansi::KnownCSI<'a> {
-    ansi::CSIParser::new(csi.0.into()).parse()
+    csi.parse()
 }
 
 #[unsafe(no_mangle)]
 pub extern "C" fn ansic_csi_into_parser<'a>(csi: ansi::CSI<'a>) -> ansi::CSIParser<'a> {
-    ansi::CSIParser::new(csi.0.into())
+    csi.parser()
 }
 
 #[unsafe(no_mangle)]